@@ -622,6 +622,7 @@ mod bridge {
                 legal_description: String::from("Test"),
                 valuation: 100000,
                 documents_url: String::from("ipfs://test"),
+                jurisdiction: None,
             };
 
             let result = bridge.initiate_bridge_multisig(1, 2, accounts.bob, 2, Some(50), metadata);
@@ -641,6 +642,7 @@ mod bridge {
                 legal_description: String::from("Test"),
                 valuation: 100000,
                 documents_url: String::from("ipfs://test"),
+                jurisdiction: None,
             };
 
             let request_id = bridge
@@ -23,20 +23,8 @@ mod compliance_registry {
         Expired,
     }
 
-    /// Supported jurisdictions
-    #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
-    #[cfg_attr(
-        feature = "std",
-        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub enum Jurisdiction {
-        US,
-        EU,
-        UK,
-        Singapore,
-        UAE,
-        Other,
-    }
+    /// Supported jurisdictions (shared with property metadata)
+    pub use propchain_traits::Jurisdiction;
 
     /// Risk level assessment
     #[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
@@ -176,7 +164,7 @@ mod compliance_registry {
     )]
     pub struct AuditLog {
         pub account: AccountId,
-        pub action: u8, // 0=verification, 1=aml_check, 2=sanctions_check, 3=consent_update, etc.
+        pub action: u8, // 0=verification, 1=aml_check, 2=sanctions_check, 3=consent_update, 4=revocation, etc.
         pub timestamp: Timestamp,
         pub verifier: AccountId,
     }
@@ -238,6 +226,8 @@ mod compliance_registry {
         account_requests: Mapping<AccountId, u64>,
         /// ZK compliance contract address (optional)
         zk_compliance_contract: Option<AccountId>,
+        /// How long a submitted verification stays valid, in milliseconds
+        verification_validity: u64,
     }
 
     /// Errors
@@ -255,6 +245,7 @@ mod compliance_registry {
         InvalidRiskScore,
         InvalidDocumentType,
         JurisdictionNotSupported,
+        InvalidValidityPeriod,
     }
 
     pub type Result<T> = core::result::Result<T, Error>;
@@ -268,6 +259,20 @@ mod compliance_registry {
         timestamp: Timestamp,
     }
 
+    #[ink(event)]
+    pub struct VerifierUpdated {
+        #[ink(topic)]
+        verifier: AccountId,
+        authorized: bool,
+        timestamp: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct VerificationValidityUpdated {
+        validity: u64,
+        timestamp: Timestamp,
+    }
+
     #[ink(event)]
     pub struct ComplianceCheckPerformed {
         #[ink(topic)]
@@ -402,6 +407,7 @@ mod compliance_registry {
                 service_providers: Mapping::default(),
                 account_requests: Mapping::default(),
                 zk_compliance_contract: None,
+                verification_validity: 365 * 24 * 60 * 60 * 1000, // 1 year
             };
 
             // Initialize default jurisdiction rules
@@ -482,9 +488,54 @@ mod compliance_registry {
         pub fn add_verifier(&mut self, verifier: AccountId) -> Result<()> {
             self.ensure_owner()?;
             self.verifiers.insert(verifier, &true);
+            self.env().emit_event(VerifierUpdated {
+                verifier,
+                authorized: true,
+                timestamp: self.env().block_timestamp(),
+            });
             Ok(())
         }
 
+        /// Remove authorized verifier; verifications it already submitted stay in force
+        #[ink(message)]
+        pub fn remove_verifier(&mut self, verifier: AccountId) -> Result<()> {
+            self.ensure_owner()?;
+            self.verifiers.remove(verifier);
+            self.env().emit_event(VerifierUpdated {
+                verifier,
+                authorized: false,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Check if an account is an authorized verifier
+        #[ink(message)]
+        pub fn is_verifier(&self, account: AccountId) -> bool {
+            self.verifiers.get(account).unwrap_or(false)
+        }
+
+        /// Set how long new verifications stay valid (milliseconds); existing ones keep their expiry
+        #[ink(message)]
+        pub fn set_verification_validity(&mut self, validity: u64) -> Result<()> {
+            self.ensure_owner()?;
+            if validity == 0 {
+                return Err(Error::InvalidValidityPeriod);
+            }
+            self.verification_validity = validity;
+            self.env().emit_event(VerificationValidityUpdated {
+                validity,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Get how long new verifications stay valid (milliseconds)
+        #[ink(message)]
+        pub fn get_verification_validity(&self) -> u64 {
+            self.verification_validity
+        }
+
         /// Submit KYC verification with enhanced document and biometric info
         #[ink(message)]
         pub fn submit_verification(
@@ -517,7 +568,7 @@ mod compliance_registry {
             }
 
             let now = self.env().block_timestamp();
-            let expiry = now + (365 * 24 * 60 * 60 * 1000); // 1 year validity
+            let expiry = now.saturating_add(self.verification_validity);
             let retention_days = rules.data_retention_days as u64;
             let retention_until = now + (retention_days * 24 * 60 * 60 * 1000);
 
@@ -618,6 +669,41 @@ mod compliance_registry {
             }
         }
 
+        /// Check if account is compliant and was verified in the given jurisdiction
+        #[ink(message)]
+        pub fn is_compliant_in(&self, account: AccountId, jurisdiction: Jurisdiction) -> bool {
+            self.is_compliant(account)
+                && self
+                    .compliance_data
+                    .get(account)
+                    .is_some_and(|data| data.jurisdiction == jurisdiction)
+        }
+
+        /// Get the KYC tier of a compliant account (1-5), or 0 if it is not compliant
+        /// The tier is the verification level earned by its documents, biometrics and risk score.
+        #[ink(message)]
+        pub fn get_compliance_tier(
+            &self,
+            account: AccountId,
+            jurisdiction: Option<Jurisdiction>,
+        ) -> u8 {
+            let data = match self.compliance_data.get(account) {
+                Some(data) => data,
+                None => return 0,
+            };
+            if !self.is_compliant(account)
+                || jurisdiction.is_some_and(|jurisdiction| jurisdiction != data.jurisdiction)
+            {
+                return 0;
+            }
+            self.calculate_verification_level(
+                data.document_type,
+                data.biometric_method,
+                data.risk_score,
+            )
+            .max(1)
+        }
+
         /// Require compliance (use this in property transfer functions)
         #[ink(message)]
         pub fn require_compliance(&self, account: AccountId) -> Result<()> {
@@ -717,6 +803,9 @@ mod compliance_registry {
                 data.status = VerificationStatus::Rejected;
                 self.compliance_data.insert(account, &data);
 
+                // Log audit event
+                self.log_audit_event(account, 4); // 4 = revocation
+
                 self.env().emit_event(VerificationUpdated {
                     account,
                     status: VerificationStatus::Rejected,
@@ -1051,6 +1140,15 @@ mod compliance_registry {
             Ok(())
         }
 
+        /// Check compliance for many accounts at once; results follow the input order
+        #[ink(message)]
+        pub fn are_compliant(&self, accounts: Vec<AccountId>) -> Vec<bool> {
+            accounts
+                .iter()
+                .map(|account| self.is_compliant(*account))
+                .collect()
+        }
+
         /// Get compliance summary for reporting
         #[ink(message)]
         pub fn get_compliance_summary(&self, accounts: Vec<AccountId>) -> Vec<(AccountId, bool)> {
@@ -1111,7 +1209,8 @@ mod compliance_registry {
                 | ComplianceOperation::TransferProperty
                 | ComplianceOperation::UpdateMetadata
                 | ComplianceOperation::CreateEscrow
-                | ComplianceOperation::ReleaseEscrow => {
+                | ComplianceOperation::ReleaseEscrow
+                | ComplianceOperation::Lease => {
                     if !rules.requires_kyc || !rules.requires_aml || !rules.requires_sanctions_check
                     {
                         return Ok(());
@@ -1298,6 +1397,21 @@ mod compliance_registry {
         fn is_compliant(&self, account: AccountId) -> bool {
             ComplianceRegistry::is_compliant(self, account)
         }
+
+        #[ink(message)]
+        fn is_compliant_in(&self, account: AccountId, jurisdiction: Jurisdiction) -> bool {
+            ComplianceRegistry::is_compliant_in(self, account, jurisdiction)
+        }
+
+        #[ink(message)]
+        fn compliance_tier(&self, account: AccountId, jurisdiction: Option<Jurisdiction>) -> u8 {
+            ComplianceRegistry::get_compliance_tier(self, account, jurisdiction)
+        }
+
+        #[ink(message)]
+        fn are_compliant(&self, accounts: Vec<AccountId>) -> Vec<bool> {
+            ComplianceRegistry::are_compliant(self, accounts)
+        }
     }
 
     #[cfg(test)]
@@ -1352,6 +1466,21 @@ mod compliance_registry {
 
             // Check compliance
             assert!(contract.is_compliant(user));
+            assert!(contract.is_compliant_in(user, Jurisdiction::US));
+            assert_eq!(
+                contract.are_compliant(vec![user, AccountId::from([0x09; 32])]),
+                vec![true, false]
+            );
+            assert!(!contract.is_compliant_in(user, Jurisdiction::EU));
+            // Passport + face recognition + low risk score
+            assert_eq!(
+                contract.get_compliance_tier(user, Some(Jurisdiction::US)),
+                5
+            );
+            assert_eq!(
+                contract.get_compliance_tier(user, Some(Jurisdiction::EU)),
+                0
+            );
 
             // Require compliance should pass
             assert!(contract.require_compliance(user).is_ok());
@@ -1511,5 +1640,67 @@ mod compliance_registry {
             let summary = contract.get_sanctions_screening_summary();
             assert!(!summary.lists_checked.is_empty());
         }
+
+        #[ink::test]
+        fn remove_verifier_revokes_verifier_rights() {
+            let mut contract = ComplianceRegistry::new();
+            let verifier = AccountId::from([0x03; 32]);
+            contract.add_verifier(verifier).expect("add verifier");
+            assert!(contract.is_verifier(verifier));
+
+            contract.remove_verifier(verifier).expect("remove verifier");
+            assert!(!contract.is_verifier(verifier));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(verifier);
+            assert_eq!(
+                contract.revoke_verification(AccountId::from([0x02; 32])),
+                Err(Error::NotAuthorized)
+            );
+            assert_eq!(contract.add_verifier(verifier), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn verification_validity_is_admin_managed() {
+            let mut contract = ComplianceRegistry::new();
+            let user = AccountId::from([0x02; 32]);
+            let day = 24 * 60 * 60 * 1000;
+
+            assert_eq!(
+                contract.set_verification_validity(0),
+                Err(Error::InvalidValidityPeriod)
+            );
+            contract
+                .set_verification_validity(30 * day)
+                .expect("owner sets validity");
+            assert_eq!(contract.get_verification_validity(), 30 * day);
+
+            contract
+                .submit_verification(
+                    user,
+                    Jurisdiction::US,
+                    [0u8; 32],
+                    RiskLevel::Low,
+                    DocumentType::Passport,
+                    BiometricMethod::FaceRecognition,
+                    15,
+                )
+                .expect("verification should succeed in test");
+            let data = contract.get_compliance_data(user).expect("stored");
+            assert_eq!(
+                data.expiry_timestamp,
+                data.verification_timestamp + 30 * day
+            );
+
+            // Revocation is recorded in the audit trail
+            contract.revoke_verification(user).expect("revoke");
+            let logs = contract.get_audit_logs(user, 10);
+            assert_eq!(logs.last().map(|log| log.action), Some(4));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+            assert_eq!(
+                contract.set_verification_validity(day),
+                Err(Error::NotAuthorized)
+            );
+        }
     }
 }
@@ -45,8 +45,159 @@ mod propchain_contracts {
         InsufficientApprovals,
         AlreadyApproved,
         NotAuthorizedToPause,
+        EscrowNotFunded,
+        EscrowAlreadyFunded,
+        InvalidEscrowDeposit,
+        EscrowTransferFailed,
+        CannotBuyOwnProperty,
+        EscrowNotAccepted,
+        EscrowAlreadyAccepted,
+        InvalidEscrowDeadline,
+        EscrowNotExpired,
+        EscrowDisputed,
+        EscrowNotDisputed,
+        NotArbitrator,
+        CancellationAlreadyRequested,
+        InvalidEscrowFee,
+        NoFeesToWithdraw,
+        EscrowClosed,
+        InvalidEscrowTransition,
+        EscrowSellerNotOwner,
+        EscrowConditionNotFound,
+        EscrowConditionAlreadySatisfied,
+        EscrowConditionsNotMet,
+        TooManyEscrowConditions,
+        InvalidSignerThreshold,
+        YieldSourceNotSet,
+        YieldSourceFailed,
+        EscrowNotApproved,
+        CoolingOffActive,
+        InvalidBasisPoints,
+        PropertyFractionalized,
+        NoExcessToWithdraw,
+        NotaryApprovalRequired,
+        InvalidDelegation,
+        DelegationNotFound,
+        EscrowAcceptanceExpired,
+        InvalidComplianceOperation,
+        UnsupportedEscrowAsset,
+        EscrowAmountBelowMinimum,
+        BuyerBarred,
+        EscrowTermsMismatch,
+        EscrowTermsNotAcknowledged,
+        EscrowTermsLocked,
+        ListingNotFound,
+        ListingNotActive,
+        ListingAlreadyExists,
+        InvalidListingPrice,
+        IncorrectPayment,
+        AuctionNotFound,
+        AuctionNotActive,
+        AuctionAlreadyExists,
+        AuctionEnded,
+        AuctionNotEnded,
+        InvalidAuctionDuration,
+        BidTooLow,
+        InvalidAuctionPrice,
+        RevealNotOpen,
+        InvalidBidReveal,
+        BidAlreadyCommitted,
+        BidNotFound,
+        OfferNotFound,
+        OfferNotPending,
+        TooManyOffers,
+        CounterOfferNotFound,
+        InvalidCounterOffer,
+        InvalidExpiry,
+        ListingExpired,
+        OfferExpired,
+        InvalidBundle,
+        BundleNotFound,
+        FinancingNotAvailable,
+        PropertyEncumbered,
+        LienNotFound,
+        OperatorNotApproved,
+        PropertyNotVerified,
+        AuctionHasBids,
+        InsufficientShares,
+        ShareTokenCodeHashNotSet,
+        ShareTokenAlreadyDeployed,
+        ShareTokenNotDeployed,
+        ShareTokenDeployFailed,
+        NoIncomeToClaim,
+        BelowBuyoutThreshold,
+        BuyoutNotFound,
+        BuyoutInProgress,
+        ProposalNotFound,
+        ProposalNotActive,
+        AlreadyVoted,
+        ProposalNotPassed,
+        RenovationNotApproved,
+        SharesLocked,
+        InvalidVestingSchedule,
+        VestingScheduleExists,
+        ShareBalanceBelowMinimum,
+        InsufficientShareAllowance,
+        PropertyNotFractionalized,
+        SnapshotNotFound,
+        IssuanceNotFound,
+        IssuanceInProgress,
+        PreemptionWindowActive,
+        ExceedsPreemptionRight,
+        InvalidPledge,
+        PledgeNotFound,
+        PledgeNotMatured,
+        PledgeNotDefaulted,
+        LeaseNotFound,
+        InvalidLeaseTerms,
+        LeaseNotPending,
+        LeaseNotActive,
+        PropertyAlreadyLeased,
+        LeaseTermNotOver,
+        NoRentDue,
+        DepositNotFound,
+        InvalidDepositDeduction,
+        InvalidDepositTransition,
+        DepositClaimPeriodActive,
+        NoticeAlreadyGiven,
+        SubleaseNotApproved,
+        SubleaseExists,
+        NoPurchaseOption,
+        PurchaseOptionNotExercisable,
+        RenewalNotFound,
+        AssignmentNotFound,
+        AssignmentNotApproved,
+        MaintenanceRequestNotFound,
+        MaintenanceAlreadyResolved,
+        NotEvictionEligible,
+        ArbitratorNotSet,
+        RentDisputeExists,
+        NoRentDispute,
+        ExceedsHeldRent,
+        DocumentHashMismatch,
+        InsufficientKycTier,
+        InvalidKycTier,
+        AccountSanctioned,
+        ComplianceBatchTooLarge,
+        ComplianceStale,
+        TooManyComplianceRegistries,
+        ComplianceSignerNotSet,
+        InvalidAttestation,
+        AttestationExpired,
+        AttestationNonceUsed,
+        InvalidAttestationSignature,
+        EscrowBelowListingPrice,
     }
 
+    /// A cached compliance result: (generation, expires at, jurisdiction, tier)
+    type CachedCompliance = (u32, u64, Option<Jurisdiction>, u8);
+
+    /// Share allowance key: (property ID, holder, spender)
+    type ShareAllowanceKey = (u64, AccountId, AccountId);
+
+    /// A holder's balances as of past snapshots, as (snapshot ID, balance) pairs
+    type ShareBalanceHistory = Vec<(u64, u128)>;
+
     /// Property Registry contract
     #[ink(storage)]
     pub struct PropertyRegistry {
@@ -68,10 +219,104 @@ mod propchain_contracts {
         escrows: Mapping<u64, EscrowInfo>,
         /// Escrow counter
         escrow_count: u64,
+        /// Closing conditions attached to each escrow
+        escrow_conditions: Mapping<u64, Vec<EscrowCondition>>,
+        /// N-of-M release signers configured for each escrow
+        escrow_signers: Mapping<u64, EscrowSigners>,
+        /// Escrow agents: (escrow ID, agent) -> delegation from the buyer or seller
+        escrow_delegations: Mapping<(u64, AccountId), EscrowDelegation>,
+        /// Share blocks sold through escrows, by escrow ID (absent for whole-title escrows)
+        escrow_shares: Mapping<u64, u128>,
+        /// Index: number of escrows opened against each property
+        property_escrow_count: Mapping<u64, u32>,
+        /// Index: (property ID, position) to the escrows opened against it
+        property_escrows: Mapping<(u64, u32), u64>,
+        /// Index: number of escrows where each account is the buyer
+        buyer_escrow_count: Mapping<AccountId, u32>,
+        /// Index: (account, position) to the escrows where it is the buyer
+        buyer_escrows: Mapping<(AccountId, u32), u64>,
+        /// Index: number of escrows where each account is the seller
+        seller_escrow_count: Mapping<AccountId, u32>,
+        /// Index: (account, position) to the escrows where it is the seller
+        seller_escrows: Mapping<(AccountId, u32), u64>,
+        /// Sale receipts recorded whenever a sale settles, by sale ID
+        sale_records: Mapping<u64, SaleRecord>,
+        /// Sale receipt counter
+        sale_count: u64,
+        /// Index: property ID to its most recent sale receipts, capped at `MAX_SALE_HISTORY`
+        property_sales: Mapping<u64, Vec<u64>>,
+        /// Total native balance currently locked across all escrows
+        total_escrowed: u128,
+        /// Yield source contract escrowed native funds may be routed into (optional)
+        yield_source: Option<AccountId>,
+        /// Pending interest-split proposals: escrow ID -> (proposer, buyer share in basis points)
+        escrow_yield_proposals: Mapping<u64, (AccountId, u32)>,
+        /// Time a seller has to accept a funded escrow, in milliseconds (0 = no limit)
+        escrow_acceptance_window: u64,
+        /// Delay between release approval and settlement, in milliseconds (0 = settle immediately)
+        escrow_cooling_off_period: u64,
+        /// Account allowed to resolve escrow disputes (optional)
+        escrow_arbitrator: Option<AccountId>,
+        /// Settlement assets escrows may be opened in
+        accepted_escrow_assets: Vec<EscrowAsset>,
+        /// Defaults recorded against each buyer since they were last barred
+        buyer_defaults: Mapping<AccountId, u32>,
+        /// Buyers barred from opening escrows, mapped to the end of their cooldown
+        barred_buyers: Mapping<AccountId, u64>,
+        /// Defaults after which a buyer is barred automatically (0 = manual only)
+        buyer_default_threshold: u32,
+        /// Cooldown applied when a buyer is barred automatically, in milliseconds
+        buyer_bar_period: u64,
+        /// Minimum escrow amount as a share of the property's valuation, in basis points
+        escrow_min_valuation_bps: u32,
+        /// Platform fee taken from escrow releases, in basis points
+        escrow_fee_bps: u32,
+        /// Bounty paid from platform fees per expired escrow swept by a keeper
+        escrow_sweep_bounty: u128,
+        /// Last escrow ID examined by `sweep_expired_escrows`
+        escrow_sweep_cursor: u64,
+        /// Account that receives withdrawn platform fees
+        treasury: AccountId,
+        /// Platform fees accumulated and not yet withdrawn
+        treasury_balance: u128,
         /// Gas usage tracking
         gas_tracker: GasTracker,
         /// Compliance registry contract address (optional)
         compliance_registry: Option<AccountId>,
+        /// Further registries consulted alongside the primary one (e.g. a sanctions screener)
+        additional_compliance_registries: Vec<AccountId>,
+        /// Whether all registries or any one of them must pass an account
+        compliance_policy: CompliancePolicy,
+        /// Whether compliance checks pass when the registry call fails (fail-open)
+        compliance_fail_open: bool,
+        /// How long a positive compliance result is trusted without re-asking the registry (0 disables)
+        compliance_cache_ttl: u64,
+        /// Generation of the compliance cache; bumping it drops every cached result
+        compliance_cache_epoch: u32,
+        /// Cached positive compliance results: account -> (cache epoch, expires at, jurisdiction, tier)
+        compliance_cache: Mapping<AccountId, CachedCompliance>,
+        /// KYC tier required from a property valuation upwards: (minimum valuation, tier)
+        kyc_tier_thresholds: Vec<(u128, u8)>,
+        /// Account that may maintain the sanctions blocklist alongside the admin
+        compliance_officer: Option<AccountId>,
+        /// Sanctioned accounts; blocked regardless of the compliance registry's answer
+        sanctioned_accounts: Mapping<AccountId, bool>,
+        /// Accounts exempt from compliance checks (e.g. settlement contracts, registrars)
+        compliance_exempt_accounts: Mapping<AccountId, bool>,
+        /// When the registry last confirmed each account as compliant
+        compliance_confirmed_at: Mapping<AccountId, u64>,
+        /// Age (ms) after which a confirmation is stale and must be renewed (0 disables)
+        compliance_max_age: u64,
+        /// Operations at which accounts must pass the compliance check
+        compliance_operations: Vec<ComplianceOperation>,
+        /// Key that signs off-chain compliance attestations
+        compliance_signer: Option<ComplianceSigner>,
+        /// Highest attestation nonce accepted per account
+        attestation_nonces: Mapping<AccountId, u64>,
+        /// Generation of the attestation signer; changing the signer drops every attestation
+        compliance_signer_epoch: u32,
+        /// Accepted attestations: account -> (signer epoch, expires at, jurisdiction, tier)
+        compliance_attestations: Mapping<AccountId, CachedCompliance>,
         /// Badge storage: (property_id, badge_type) -> Badge
         property_badges: Mapping<(u64, BadgeType), Badge>,
         /// Authorized badge verifiers
@@ -94,62 +339,440 @@ mod propchain_contracts {
         fee_manager: Option<AccountId>,
         /// Fractional properties info
         fractional: Mapping<u64, FractionalInfo>,
+        /// Fractional share balances by (property ID, holder)
+        share_balances: Mapping<(u64, AccountId), u128>,
+        /// Cap-table snapshots by snapshot ID
+        share_snapshots: Mapping<u64, ShareSnapshot>,
+        /// Snapshot counter, shared by all properties
+        share_snapshot_count: u64,
+        /// Latest snapshot taken of each property's cap table
+        latest_share_snapshots: Mapping<u64, u64>,
+        /// Balances as they stood at each snapshot, recorded when a holder's balance first
+        /// changes after it, by (property ID, holder) as (snapshot ID, balance) pairs
+        share_balance_history: Mapping<(u64, AccountId), ShareBalanceHistory>,
+        /// Offerings of newly issued shares by issuance ID
+        share_issuances: Mapping<u64, ShareIssuance>,
+        /// Share issuance counter
+        share_issuance_count: u64,
+        /// Index: property ID to its open share issuance
+        property_issuances: Mapping<u64, u64>,
+        /// Shares each holder bought under their preemption right, by (issuance ID, holder)
+        preemption_purchases: Mapping<(u64, AccountId), u128>,
+        /// Number of accounts holding shares in each property
+        shareholder_count: Mapping<u64, u32>,
+        /// Enumerable holder set: (property ID, position) -> holder
+        shareholders: Mapping<(u64, u32), AccountId>,
+        /// Position of each holder in the enumerable holder set
+        shareholder_positions: Mapping<(u64, AccountId), u32>,
+        /// Code hash PSP22 share tokens are instantiated from (optional)
+        share_token_code_hash: Option<Hash>,
+        /// PSP22 share token deployed for a fractionalized property, by property ID
+        share_tokens: Mapping<u64, AccountId>,
+        /// Share transfers below this amount skip the recipient compliance check (None = check all)
+        share_compliance_exempt_below: Option<u128>,
+        /// Cumulative income per share for each property, scaled by `INCOME_PRECISION`
+        income_per_share: Mapping<u64, u128>,
+        /// Income-per-share value each holder was last settled at, by (property ID, holder)
+        income_checkpoints: Mapping<(u64, AccountId), u128>,
+        /// Settled income awaiting claim, by (property ID, holder)
+        unclaimed_income: Mapping<(u64, AccountId), u128>,
+        /// Share of a property's shares a holder needs to force a buyout, in basis points
+        buyout_threshold_bps: u32,
+        /// Majority buyouts with minority payouts still to be claimed, by property ID
+        buyouts: Mapping<u64, Buyout>,
+        /// Shareholder proposals by proposal ID
+        proposals: Mapping<u64, Proposal>,
+        /// Proposal counter
+        proposal_count: u64,
+        /// Accounts that have voted on a proposal, by (proposal ID, voter)
+        proposal_votes: Mapping<(u64, AccountId), bool>,
+        /// How long shareholders can vote on a proposal, in milliseconds
+        proposal_voting_period: u64,
+        /// Renovations approved by shareholders, allowing one metadata update per approval
+        approved_renovations: Mapping<u64, Hash>,
+        /// Lockup or vesting schedules on holders' shares, by (property ID, holder)
+        share_vesting: Mapping<(u64, AccountId), ShareVesting>,
+        /// Smallest non-zero share balance a holder may be left with, by property ID
+        min_share_holdings: Mapping<u64, u128>,
+        /// Shares a spender may move on a holder's behalf, by (property ID, holder, spender)
+        share_allowances: Mapping<ShareAllowanceKey, u128>,
+        /// Share pledges by pledge ID
+        share_pledges: Mapping<u64, SharePledge>,
+        /// Share pledge counter
+        share_pledge_count: u64,
+        /// Shares a holder has pledged and cannot transfer, by (property ID, holder)
+        pledged_shares: Mapping<(u64, AccountId), u128>,
+        /// Marketplace listings by listing ID
+        listings: Mapping<u64, Listing>,
+        /// Listing counter
+        listing_count: u64,
+        /// Index: property ID to its active listing
+        property_listings: Mapping<u64, u64>,
+        /// Property auctions by auction ID
+        auctions: Mapping<u64, Auction>,
+        /// Auction counter
+        auction_count: u64,
+        /// Index: property ID to its running auction
+        property_auctions: Mapping<u64, u64>,
+        /// Outbid or failed auction bids awaiting withdrawal, by (auction ID, bidder)
+        auction_refunds: Mapping<(u64, AccountId), u128>,
+        /// Declining-price auctions by auction ID
+        dutch_auctions: Mapping<u64, DutchAuction>,
+        /// Declining-price auction counter
+        dutch_auction_count: u64,
+        /// Index: property ID to its running declining-price auction
+        property_dutch_auctions: Mapping<u64, u64>,
+        /// Sealed-bid auctions by auction ID
+        sealed_auctions: Mapping<u64, SealedAuction>,
+        /// Sealed-bid auction counter
+        sealed_auction_count: u64,
+        /// Index: property ID to its running sealed-bid auction
+        property_sealed_auctions: Mapping<u64, u64>,
+        /// Sealed bids: (auction ID, bidder) -> committed bid and deposit
+        sealed_bids: Mapping<(u64, AccountId), SealedBid>,
+        /// Purchase offers by offer ID
+        offers: Mapping<u64, Offer>,
+        /// Offer counter
+        offer_count: u64,
+        /// Index: property ID to its pending offer IDs
+        property_offers: Mapping<u64, Vec<u64>>,
+        /// Bundle listings by bundle ID
+        bundles: Mapping<u64, BundleListing>,
+        /// Bundle listing counter
+        bundle_count: u64,
+        /// Index: property ID to the active bundle it is listed in
+        property_bundles: Mapping<u64, u64>,
+        /// Resale royalties fixed at registration, by property ID
+        property_royalties: Mapping<u64, RoyaltyInfo>,
+        /// Platform fee per marketplace sale type, in basis points (escrows use `escrow_fee_bps`)
+        marketplace_fee_bps: Mapping<SaleType, u32>,
+        /// Number of fixed-price listings currently open for purchase
+        active_listing_count: u64,
+        /// Total native value of all settled sales
+        total_sales_volume: u128,
+        /// Number of settled sales per seller
+        seller_sale_counts: Mapping<AccountId, u64>,
+        /// Liens securing unpaid purchase balances, by property ID
+        property_liens: Mapping<u64, Lien>,
+        /// Marketplace contracts allowed to transfer properties under owner approvals
+        marketplace_operators: Mapping<AccountId, bool>,
+        /// Badge a property must hold before it can be offered through a sale type
+        sale_verification_requirements: Mapping<SaleType, BadgeType>,
+        /// Leases by lease ID
+        leases: Mapping<u64, Lease>,
+        /// Lease counter
+        lease_count: u64,
+        /// Index: property ID to its pending or active lease
+        property_active_leases: Mapping<u64, u64>,
+        /// Number of leases ever created on each property
+        property_lease_count: Mapping<u64, u32>,
+        /// Index: (property ID, position) to lease ID, oldest first
+        property_leases: Mapping<(u64, u32), u64>,
+        /// Number of leases each account has been tenant of
+        tenant_lease_count: Mapping<AccountId, u32>,
+        /// Index: (tenant, position) to lease ID, oldest first
+        tenant_leases: Mapping<(AccountId, u32), u64>,
+        /// Index: lease ID to its pending or active sublease
+        lease_subleases: Mapping<u64, u64>,
+        /// Renewal terms offered by owners and awaiting the tenant, by lease ID
+        lease_renewals: Mapping<u64, LeaseRenewal>,
+        /// Proposed transfers of a tenant's rights, by lease ID
+        lease_assignments: Mapping<u64, LeaseAssignment>,
+        /// Open habitability disputes with the rent held back, by lease ID
+        rent_disputes: Mapping<u64, RentDispute>,
+        /// Account that releases rent held over habitability disputes
+        rent_arbitrator: Option<AccountId>,
+        /// Maintenance requests by request ID
+        maintenance_requests: Mapping<u64, MaintenanceRequest>,
+        /// Maintenance request counter
+        maintenance_request_count: u64,
+        /// Index: lease ID to its maintenance request IDs, oldest first
+        lease_maintenance_requests: Mapping<u64, Vec<u64>>,
+        /// Surcharge on rent paid after its due date, in basis points of the rent
+        rent_late_fee_bps: u32,
+        /// Consecutive unpaid rent periods after which an owner may terminate a lease
+        eviction_threshold_periods: u64,
+        /// Security deposits held for leases, by lease ID
+        lease_deposits: Mapping<u64, SecurityDeposit>,
+        /// Time the owner has after a lease ends to claim deductions, and the tenant has to
+        /// dispute them, in milliseconds
+        deposit_claim_period: u64,
     }
 
-    /// Escrow information
+    /// Asset an escrow is denominated in
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum EscrowAsset {
+        /// Native chain balance
+        Native,
+        /// PSP22 token at the given contract address
+        Psp22(AccountId),
+    }
+
+    /// Lifecycle state of an escrow
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum EscrowState {
+        /// Opened, awaiting the full deposit
+        Created,
+        /// The agreed amount is held by the contract
+        Funded,
+        /// Funds paid to the seller
+        Released,
+        /// Funds returned to the buyer
+        Refunded,
+        /// Frozen pending arbitration
+        Disputed,
+        /// Deadline passed and the buyer reclaimed the deposit
+        Expired,
+    }
+
+    impl EscrowState {
+        /// Returns true if the escrow can move from this state to `next`
+        pub fn can_transition_to(&self, next: EscrowState) -> bool {
+            use EscrowState::*;
+            matches!(
+                (self, next),
+                (Created, Funded)
+                    | (Created, Refunded)
+                    | (Created, Expired)
+                    | (Created, Disputed)
+                    | (Funded, Released)
+                    | (Funded, Refunded)
+                    | (Funded, Expired)
+                    | (Funded, Disputed)
+                    | (Disputed, Released)
+                    | (Disputed, Refunded)
+            )
+        }
+
+        /// Returns true once the escrow has settled and can no longer change
+        pub fn is_final(&self) -> bool {
+            matches!(
+                self,
+                EscrowState::Released | EscrowState::Refunded | EscrowState::Expired
+            )
+        }
+    }
+
+    /// Maximum number of escrows returned by a single paginated query
+    pub const MAX_ESCROW_PAGE_SIZE: u32 = 100;
+
+    /// Number of most recent sales kept in each property's price history
+    pub const MAX_SALE_HISTORY: u32 = 100;
+
+    /// Maximum number of closing conditions attached to a single escrow
+    pub const MAX_ESCROW_CONDITIONS: u32 = 20;
+
+    /// Named closing condition that must be satisfied before an escrow can be released
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct EscrowInfo {
-        pub id: u64,
-        pub property_id: u64,
-        pub buyer: AccountId,
-        pub seller: AccountId,
-        pub amount: u128,
-        pub released: bool,
+    pub struct EscrowCondition {
+        pub name: String,
+        pub satisfied: bool,
+        pub satisfied_by: Option<AccountId>,
     }
 
-    /// Portfolio summary statistics
+    /// Maximum number of release signers designated for a single escrow
+    pub const MAX_ESCROW_SIGNERS: u32 = 10;
+
+    /// Designated signers whose approvals are required before an escrow can be released
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct PortfolioSummary {
-        pub property_count: u64,
-        pub total_valuation: u128,
-        pub average_valuation: u128,
-        pub total_size: u64,
-        pub average_size: u64,
+    pub struct EscrowSigners {
+        pub signers: Vec<AccountId>,
+        /// Number of distinct signer approvals required
+        pub threshold: u32,
+        pub approvals: Vec<AccountId>,
     }
 
-    /// Detailed portfolio information
+    /// Power of attorney granted by an escrow party to an agent
+    /// The agent may fund, accept, approve, release and refund the escrow on the principal's
+    /// behalf until `expires_at`.
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct PortfolioDetails {
-        pub owner: AccountId,
-        pub properties: Vec<PortfolioProperty>,
-        pub total_count: u64,
+    pub struct EscrowDelegation {
+        pub principal: AccountId,
+        pub expires_at: u64,
     }
 
-    /// Individual property in portfolio
+    /// Escrow information
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct PortfolioProperty {
+    pub struct EscrowInfo {
         pub id: u64,
-        pub location: String,
-        pub size: u64,
-        pub valuation: u128,
-        pub registered_at: u64,
+        pub property_id: u64,
+        pub buyer: AccountId,
+        pub seller: AccountId,
+        pub amount: u128,
+        /// Asset the escrow is denominated and paid out in
+        pub asset: EscrowAsset,
+        /// Active listing the amount was checked against; closed when the escrow settles
+        pub listing_id: Option<u64>,
+        /// Balance currently held by the contract for this escrow
+        pub funded_amount: u128,
+        /// Part of the funded amount locked as earnest money, forfeited if the buyer walks away
+        pub earnest_amount: u128,
+        /// Whether the seller has agreed to the escrow terms
+        pub seller_accepted: bool,
+        /// When the seller accepted the escrow terms
+        pub accepted_at: Option<u64>,
+        /// Timestamp by which the seller must accept once the buyer has deposited funds
+        pub acceptance_deadline: Option<u64>,
+        /// Optional timestamp after which the buyer may reclaim the deposit
+        pub deadline: Option<u64>,
+        /// Notary attached by either party; manages closing conditions and must co-sign release
+        pub notary: Option<AccountId>,
+        /// Whether the attached notary has signed off on the release
+        pub notary_approved: bool,
+        /// Hash of the off-chain purchase agreement, if one is attached
+        pub terms_hash: Option<Hash>,
+        /// Whether the buyer has acknowledged the attached terms
+        pub buyer_acknowledged_terms: bool,
+        /// Whether the seller has acknowledged the attached terms
+        pub seller_acknowledged_terms: bool,
+        /// Agreed buyer share of accrued interest in basis points, when yield routing is enabled
+        pub yield_buyer_share_bps: Option<u32>,
+        /// Principal currently deposited in the yield source
+        pub yield_principal: u128,
+        /// When release was approved, starting the cooling-off window before settlement
+        pub approved_at: Option<u64>,
+        /// Party that has asked to cancel the escrow, awaiting the counterparty's consent
+        pub cancel_requested_by: Option<AccountId>,
+        /// Current lifecycle state
+        pub state: EscrowState,
+    }
+
+    impl EscrowInfo {
+        /// Creates an unfunded, unaccepted escrow with no deadline, notary, terms or yield
+        /// The ID is assigned when the escrow is opened.
+        pub fn new(
+            property_id: u64,
+            buyer: AccountId,
+            seller: AccountId,
+            amount: u128,
+            asset: EscrowAsset,
+        ) -> Self {
+            Self {
+                id: 0,
+                property_id,
+                buyer,
+                seller,
+                amount,
+                asset,
+                listing_id: None,
+                funded_amount: 0,
+                earnest_amount: 0,
+                seller_accepted: false,
+                accepted_at: None,
+                acceptance_deadline: None,
+                deadline: None,
+                notary: None,
+                notary_approved: false,
+                terms_hash: None,
+                buyer_acknowledged_terms: false,
+                seller_acknowledged_terms: false,
+                yield_buyer_share_bps: None,
+                yield_principal: 0,
+                approved_at: None,
+                cancel_requested_by: None,
+                state: EscrowState::Created,
+            }
+        }
+    }
+
+    /// Shortfall of an escrow's yield position, apportioned between buyer and seller
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct YieldLoss {
+        pub buyer: u128,
+        pub seller: u128,
+    }
+
+    impl YieldLoss {
+        /// Splits a shortfall in the same proportion the parties agreed to share interest
+        pub fn split(loss: u128, buyer_share_bps: u32) -> Self {
+            let buyer = loss
+                .saturating_mul(buyer_share_bps as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            Self {
+                buyer,
+                seller: loss.saturating_sub(buyer),
+            }
+        }
+
+        /// Returns the whole shortfall
+        pub fn total(&self) -> u128 {
+            self.buyer.saturating_add(self.seller)
+        }
+
+        /// Takes each party's share out of its payout, returning the `(buyer, seller)` payouts
+        /// A share larger than the party's payout falls on the other party.
+        pub fn charge(&self, buyer_amount: u128, seller_amount: u128) -> (u128, u128) {
+            let buyer_uncovered = self.buyer.saturating_sub(buyer_amount);
+            let seller_uncovered = self.seller.saturating_sub(seller_amount);
+            (
+                buyer_amount
+                    .saturating_sub(self.buyer)
+                    .saturating_sub(seller_uncovered),
+                seller_amount
+                    .saturating_sub(self.seller)
+                    .saturating_sub(buyer_uncovered),
+            )
+        }
+    }
+
+    /// Receipt of a completed sale, kept after the escrow or listing behind it is pruned
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SaleRecord {
+        pub id: u64,
+        pub sale_type: SaleType,
+        /// ID of the escrow, listing, bundle, auction or offer the sale settled through
+        pub source_id: u64,
+        pub property_id: u64,
+        pub buyer: AccountId,
+        pub seller: AccountId,
+        pub price: u128,
+        pub asset: EscrowAsset,
+        /// Hash of the purchase agreement both parties acknowledged, if any
+        pub terms_hash: Option<Hash>,
+        pub timestamp: u64,
     }
 
+    /// Lifecycle status of a marketplace listing
     #[derive(
         Debug,
         Clone,
+        Copy,
         PartialEq,
         Eq,
         scale::Encode,
@@ -157,53 +780,74 @@ mod propchain_contracts {
         ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct FractionalInfo {
-        pub total_shares: u128,
-        pub enabled: bool,
-        pub created_at: u64,
+    pub enum ListingStatus {
+        Active,
+        Sold,
+        Cancelled,
+        Expired,
     }
 
-    /// Global analytics data
+    /// Fixed-price marketplace listing
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct GlobalAnalytics {
-        pub total_properties: u64,
-        pub total_valuation: u128,
-        pub average_valuation: u128,
-        pub total_size: u64,
-        pub average_size: u64,
-        pub unique_owners: u64,
+    pub struct Listing {
+        pub id: u64,
+        pub property_id: u64,
+        pub seller: AccountId,
+        pub price: u128,
+        pub created_at: u64,
+        /// Timestamp after which the listing can no longer be bought
+        pub expires_at: Option<u64>,
+        pub status: ListingStatus,
+        pub buyer: Option<AccountId>,
+        /// Only this account may buy a private listing
+        pub private_buyer: Option<AccountId>,
+        /// Smallest down payment accepted for a financed purchase, if the seller offers financing
+        pub min_down_payment: Option<u128>,
+        /// Asset the price must be paid in
+        pub asset: EscrowAsset,
     }
 
-    /// Gas metrics for monitoring
+    /// Lien recorded against a property for the unpaid balance of a financed purchase
+    /// While a lien exists the property cannot be transferred or put up for sale.
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct GasMetrics {
-        pub last_operation_gas: u64,
-        pub average_operation_gas: u64,
-        pub total_operations: u64,
-        pub min_gas_used: u64,
-        pub max_gas_used: u64,
+    pub struct Lien {
+        pub property_id: u64,
+        /// Account owed the balance, normally the seller who financed the sale
+        pub creditor: AccountId,
+        pub debtor: AccountId,
+        pub outstanding: u128,
+        pub created_at: u64,
     }
 
-    /// Gas tracker for monitoring usage
+    /// How the answers of several compliance registries combine
     #[derive(
-        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct GasTracker {
-        pub total_gas_used: u64,
-        pub operation_count: u64,
-        pub last_operation_gas: u64,
-        pub min_gas_used: u64,
-        pub max_gas_used: u64,
+    pub enum CompliancePolicy {
+        /// Every registry must pass the account
+        All,
+        /// Any one registry passing is enough
+        Any,
     }
 
-    /// Badge types for property verification
+    /// Maximum number of registries consulted besides the primary compliance registry
+    pub const MAX_ADDITIONAL_COMPLIANCE_REGISTRIES: u32 = 5;
+
+    /// Public key of the off-chain service that signs compliance attestations
     #[derive(
         Debug,
         Clone,
@@ -215,47 +859,92 @@ mod propchain_contracts {
         ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum BadgeType {
-        OwnerVerification,    // KYC/Identity verified
-        DocumentVerification, // Legal documents verified
-        LegalCompliance,      // Regulatory compliance verified
-        PremiumListing,       // Premium tier property
+    pub enum ComplianceSigner {
+        Sr25519([u8; 32]),
+        /// Compressed secp256k1 public key
+        Ecdsa([u8; 33]),
     }
 
-    /// Badge information
+    /// Signature over a compliance attestation, matching the configured signer's scheme
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum AttestationSignature {
+        Sr25519([u8; 64]),
+        Ecdsa([u8; 65]),
+    }
+
+    /// Off-chain statement that an account passed compliance screening
+    /// The signer signs the Blake2x256 hash of the SCALE-encoded `(registry contract, attestation)`
+    /// pair, so an attestation cannot be replayed against another deployment.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ComplianceAttestation {
+        pub account: AccountId,
+        /// Jurisdiction the account was verified in, if the attestation is scoped to one
+        pub jurisdiction: Option<Jurisdiction>,
+        /// KYC tier granted; must be at least 1
+        pub tier: u8,
+        /// Must exceed the last nonce accepted for the account
+        pub nonce: u64,
+        /// Timestamp after which the attestation is no longer accepted or honoured
+        pub expires_at: u64,
+    }
+
+    /// Way a property changed hands, used to select the platform fee
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum SaleType {
+        /// Fixed-price and bundle listings
+        Listing,
+        /// English, declining-price and sealed-bid auctions
+        Auction,
+        /// Accepted purchase offers and counter-offers
+        Offer,
+        /// Escrow settlements
+        Escrow,
+    }
+
+    /// Maximum royalty on resales, in basis points
+    pub const MAX_ROYALTY_BPS: u32 = 1_000;
+
+    /// Royalty paid out of the proceeds whenever a property is resold
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct Badge {
-        pub badge_type: BadgeType,
-        pub issued_at: u64,
-        pub issued_by: AccountId,
-        pub expires_at: Option<u64>,
-        pub metadata_url: String,
-        pub revoked: bool,
-        pub revoked_at: Option<u64>,
-        pub revocation_reason: String,
+    pub struct RoyaltyInfo {
+        pub receiver: AccountId,
+        pub bps: u32,
     }
 
-    /// Verification request for badge
+    /// Maximum number of properties sold together in one bundle listing
+    pub const MAX_BUNDLE_SIZE: u32 = 10;
+
+    /// Several properties listed together for a single price
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct VerificationRequest {
+    pub struct BundleListing {
         pub id: u64,
-        pub property_id: u64,
-        pub badge_type: BadgeType,
-        pub requester: AccountId,
-        pub requested_at: u64,
-        pub evidence_url: String,
-        pub status: VerificationStatus,
-        pub reviewed_by: Option<AccountId>,
-        pub reviewed_at: Option<u64>,
+        pub seller: AccountId,
+        pub property_ids: Vec<u64>,
+        pub price: u128,
+        pub created_at: u64,
+        pub status: ListingStatus,
+        pub buyer: Option<AccountId>,
     }
 
-    /// Verification status
+    /// Lifecycle status of a property auction
     #[derive(
         Debug,
         Clone,
@@ -267,31 +956,63 @@ mod propchain_contracts {
         ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum VerificationStatus {
-        Pending,
-        Approved,
-        Rejected,
+    pub enum AuctionStatus {
+        Active,
+        Finalized,
+        Cancelled,
+        /// Ended without a bid at or above the reserve; the seller keeps the property
+        ReserveNotMet,
     }
 
-    /// Appeal for badge revocation
+    /// Ascending-price (English) property auction
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct Appeal {
+    pub struct Auction {
         pub id: u64,
         pub property_id: u64,
-        pub badge_type: BadgeType,
-        pub appellant: AccountId,
-        pub reason: String,
-        pub submitted_at: u64,
-        pub status: AppealStatus,
-        pub resolved_by: Option<AccountId>,
-        pub resolved_at: Option<u64>,
-        pub resolution: String,
+        pub seller: AccountId,
+        /// Lowest winning bid; the auction fails if the highest bid stays below it
+        pub reserve_price: u128,
+        /// Smallest acceptable first bid, at most the reserve price
+        pub opening_bid: u128,
+        pub highest_bid: u128,
+        pub highest_bidder: Option<AccountId>,
+        pub started_at: u64,
+        pub ends_at: u64,
+        pub status: AuctionStatus,
+        /// Amount each new bid must exceed the current highest bid by
+        pub min_bid_increment: u128,
+        /// A bid placed within this many milliseconds of the end pushes the end back to
+        /// this long after the bid; zero disables extensions
+        pub extension_window: u64,
     }
 
-    /// Appeal status
+    /// Declining-price (Dutch) property auction
+    /// The price falls linearly from `start_price` to `floor_price` between `started_at` and
+    /// `ends_at`, then stays at the floor until the auction is bought or cancelled.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct DutchAuction {
+        pub id: u64,
+        pub property_id: u64,
+        pub seller: AccountId,
+        pub start_price: u128,
+        pub floor_price: u128,
+        pub started_at: u64,
+        pub ends_at: u64,
+        pub status: AuctionStatus,
+        pub buyer: Option<AccountId>,
+        pub sold_price: u128,
+    }
+
+    /// Maximum number of pending offers held against a single property
+    pub const MAX_PENDING_OFFERS: u32 = 20;
+
+    /// Lifecycle status of a purchase offer
     #[derive(
         Debug,
         Clone,
@@ -303,2277 +1024,13510 @@ mod propchain_contracts {
         ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum AppealStatus {
+    pub enum OfferStatus {
         Pending,
-        Approved,
+        Accepted,
         Rejected,
+        Withdrawn,
+        Expired,
     }
 
-    /// Pause information
+    /// Purchase offer on a property, backed by a deposit of the full offered amount
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct PauseInfo {
-        pub paused: bool,
-        pub paused_at: Option<u64>,
-        pub paused_by: Option<AccountId>,
-        pub reason: Option<String>,
-        pub auto_resume_at: Option<u64>,
-
-        // For Resume Process
-        pub resume_request_active: bool,
-        pub resume_requester: Option<AccountId>,
-        pub resume_approvals: Vec<AccountId>,
-        pub required_approvals: u32,
+    pub struct Offer {
+        pub id: u64,
+        pub property_id: u64,
+        pub offeror: AccountId,
+        /// Offered price, held by the contract until the offer is resolved
+        pub amount: u128,
+        /// Asset the offered price is held in
+        pub asset: EscrowAsset,
+        pub created_at: u64,
+        /// Timestamp after which the offer can no longer be accepted
+        pub expires_at: Option<u64>,
+        pub status: OfferStatus,
+        /// Latest counter-offer from the owner, if any
+        pub counter: Option<CounterOffer>,
     }
 
-    // ============================================================================
-    // STRUCTURED EVENT SYSTEM - Version 1.0
-    // ============================================================================
-    // All events follow a standardized format with:
-    // - Indexed fields (topics) for efficient querying
-    // - Timestamps and block numbers for historical tracking
-    // - Event versioning for future compatibility
-    // - Detailed metadata for off-chain indexing
-    // ============================================================================
+    /// Owner's counter-offer on a pending purchase offer
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CounterOffer {
+        pub price: u128,
+        /// Owner who made the counter-offer; it lapses if the property changes hands
+        pub seller: AccountId,
+        pub countered_at: u64,
+    }
 
-    /// Event emitted when the contract is initialized
-    #[ink(event)]
-    pub struct ContractInitialized {
-        #[ink(topic)]
-        admin: AccountId,
-        #[ink(topic)]
-        contract_version: u32,
-        timestamp: u64,
-        block_number: u32,
+    /// Sealed-bid property auction with a commit window followed by a reveal window
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SealedAuction {
+        pub id: u64,
+        pub property_id: u64,
+        pub seller: AccountId,
+        pub reserve_price: u128,
+        /// Bids can be committed until this timestamp
+        pub commit_ends_at: u64,
+        /// Committed bids can be revealed until this timestamp
+        pub reveal_ends_at: u64,
+        pub highest_bid: u128,
+        pub highest_bidder: Option<AccountId>,
+        pub status: AuctionStatus,
     }
 
-    /// Event emitted when a property is registered
-    /// Indexed fields: property_id, owner for efficient filtering
-    #[ink(event)]
-    pub struct PropertyRegistered {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        owner: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        location: String,
-        size: u64,
-        valuation: u128,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Bid committed to a sealed-bid auction
+    /// The deposit must cover the hidden bid; whatever is not spent on a winning bid is
+    /// withdrawn after the auction is finalized.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SealedBid {
+        /// Blake2x256 hash of the SCALE-encoded `(amount, salt)` pair
+        pub commitment: Hash,
+        pub deposit: u128,
+        /// Revealed bid amount, once revealed
+        pub revealed_amount: Option<u128>,
     }
 
-    /// Event emitted when property ownership is transferred
-    /// Indexed fields: property_id, from, to for efficient querying
-    #[ink(event)]
-    pub struct PropertyTransferred {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        from: AccountId,
-        #[ink(topic)]
-        to: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-        transferred_by: AccountId, // The account that initiated the transfer
+    /// Portfolio summary statistics
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PortfolioSummary {
+        pub property_count: u64,
+        pub total_valuation: u128,
+        pub average_valuation: u128,
+        pub total_size: u64,
+        pub average_size: u64,
     }
 
-    /// Event emitted when property metadata is updated
-    /// Indexed fields: property_id, owner for efficient filtering
-    #[ink(event)]
-    pub struct PropertyMetadataUpdated {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        owner: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        old_location: String,
-        new_location: String,
-        old_valuation: u128,
-        new_valuation: u128,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Detailed portfolio information
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PortfolioDetails {
+        pub owner: AccountId,
+        pub properties: Vec<PortfolioProperty>,
+        pub total_count: u64,
     }
 
-    /// Event emitted when an account is approved to transfer a property
-    /// Indexed fields: property_id, owner, approved for efficient querying
-    #[ink(event)]
-    pub struct ApprovalGranted {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        owner: AccountId,
-        #[ink(topic)]
-        approved: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Individual property in portfolio
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PortfolioProperty {
+        pub id: u64,
+        pub location: String,
+        pub size: u64,
+        pub valuation: u128,
+        pub registered_at: u64,
     }
 
-    /// Event emitted when an approval is cleared/revoked
-    /// Indexed fields: property_id, owner for efficient querying
-    #[ink(event)]
-    pub struct ApprovalCleared {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        owner: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Majority buyout of a fractionalized property's remaining shares
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Buyout {
+        pub buyer: AccountId,
+        pub price_per_share: u128,
+        /// Minority shares whose payout has not been claimed yet
+        pub outstanding_shares: u128,
+        pub started_at: u64,
     }
 
-    /// Event emitted when an escrow is created
-    /// Indexed fields: escrow_id, property_id, buyer, seller for efficient querying
-    #[ink(event)]
-    pub struct EscrowCreated {
-        #[ink(topic)]
-        escrow_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        buyer: AccountId,
-        #[ink(topic)]
-        seller: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        amount: u128,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Shares that unlock linearly between `start` and `end`, with nothing released
+    /// before `cliff`; a plain lockup uses the same time for `cliff` and `end`
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ShareVesting {
+        pub total: u128,
+        pub start: u64,
+        pub cliff: u64,
+        pub end: u64,
     }
 
-    /// Event emitted when escrow is released and property transferred
-    /// Indexed fields: escrow_id, property_id, buyer for efficient querying
-    #[ink(event)]
-    pub struct EscrowReleased {
-        #[ink(topic)]
-        escrow_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        buyer: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        amount: u128,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-        released_by: AccountId,
+    /// Shares a holder has put up as collateral with a lender
+    /// Once `maturity` has passed the lender may record a default and then seize the shares.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SharePledge {
+        pub id: u64,
+        pub property_id: u64,
+        pub holder: AccountId,
+        pub lender: AccountId,
+        pub shares: u128,
+        pub maturity: u64,
+        pub defaulted: bool,
     }
 
-    /// Event emitted when escrow is refunded
-    /// Indexed fields: escrow_id, property_id, seller for efficient querying
-    #[ink(event)]
-    pub struct EscrowRefunded {
-        #[ink(topic)]
-        escrow_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        seller: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        amount: u128,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-        refunded_by: AccountId,
+    /// Point in time a property's share balances were frozen at
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ShareSnapshot {
+        pub id: u64,
+        pub property_id: u64,
+        pub total_shares: u128,
+        pub taken_at: u64,
     }
 
-    /// Event emitted when admin is changed
-    /// Indexed fields: old_admin, new_admin for efficient querying
-    #[ink(event)]
-    pub struct AdminChanged {
-        #[ink(topic)]
-        old_admin: AccountId,
-        #[ink(topic)]
-        new_admin: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-        changed_by: AccountId,
+    /// Offering of new shares in a fractionalized property
+    /// Until `preemption_ends_at` only existing holders may buy, each up to their pro-rata
+    /// portion of `new_shares` as of the snapshot; the remainder is then open to anyone.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ShareIssuance {
+        pub id: u64,
+        pub property_id: u64,
+        /// Property owner receiving the proceeds
+        pub issuer: AccountId,
+        pub new_shares: u128,
+        pub sold: u128,
+        pub price_per_share: u128,
+        /// Snapshot preemption rights are sized from
+        pub snapshot_id: u64,
+        /// Total shares before the issuance
+        pub base_shares: u128,
+        pub preemption_ends_at: u64,
+        pub open: bool,
     }
 
-    /// Batch event for multiple property registrations
-    /// Indexed fields: owner for efficient filtering
-    #[ink(event)]
-    pub struct BatchPropertyRegistered {
-        #[ink(topic)]
-        owner: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        property_ids: Vec<u64>,
-        count: u64,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Restricted action on a fractionalized property that shareholders vote on
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ProposalAction {
+        /// List the whole property at a fixed price; proceeds are split across shareholders
+        Sell { price: u128 },
+        /// Reprice the property's active listing
+        SetListingPrice { price: u128 },
+        /// Approve a major renovation described off-chain, unlocking one metadata update
+        MajorRenovation { description_hash: Hash },
     }
 
-    /// Batch event for multiple property transfers to the same recipient
-    /// Indexed fields: from, to for efficient querying
-    #[ink(event)]
-    pub struct BatchPropertyTransferred {
-        #[ink(topic)]
-        from: AccountId,
-        #[ink(topic)]
-        to: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        property_ids: Vec<u64>,
-        count: u64,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-        transferred_by: AccountId,
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ProposalStatus {
+        Active,
+        Executed,
     }
 
-    /// Batch event for multiple metadata updates
-    /// Indexed fields: owner for efficient filtering
-    #[ink(event)]
-    pub struct BatchMetadataUpdated {
-        #[ink(topic)]
-        owner: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        property_ids: Vec<u64>,
-        count: u64,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Shareholder vote on a restricted action for a fractionalized property
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Proposal {
+        pub id: u64,
+        pub property_id: u64,
+        pub proposer: AccountId,
+        pub action: ProposalAction,
+        pub votes_for: u128,
+        pub votes_against: u128,
+        /// Cap-table snapshot voting power is read from
+        pub snapshot_id: u64,
+        pub created_at: u64,
+        pub voting_ends_at: u64,
+        pub status: ProposalStatus,
     }
 
-    /// Batch event for multiple property transfers to different recipients
-    /// Indexed fields: from for efficient querying
-    #[ink(event)]
-    pub struct BatchPropertyTransferredToMultiple {
-        #[ink(topic)]
-        from: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        transfers: Vec<(u64, AccountId)>, // (property_id, to)
-        count: u64,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-        transferred_by: AccountId,
-    }
+    /// Maximum number of shareholders returned by one cap-table query
+    pub const MAX_SHAREHOLDER_PAGE_SIZE: u32 = 100;
 
-    /// Event emitted when a badge is issued to a property
-    #[ink(event)]
-    pub struct BadgeIssued {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        badge_type: BadgeType,
-        #[ink(topic)]
-        issued_by: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        expires_at: Option<u64>,
-        metadata_url: String,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-    }
+    /// Maximum number of leases returned by one lease query
+    pub const MAX_LEASE_PAGE_SIZE: u32 = 100;
 
-    /// Event emitted when a badge is revoked
-    #[ink(event)]
-    pub struct BadgeRevoked {
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        badge_type: BadgeType,
-        #[ink(topic)]
-        revoked_by: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        reason: String,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-    }
+    /// Maximum number of accounts screened by one batch compliance query
+    pub const MAX_COMPLIANCE_BATCH_SIZE: u32 = 100;
 
-    /// Event emitted when a verification is requested
-    #[ink(event)]
-    pub struct VerificationRequested {
-        #[ink(topic)]
-        request_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        badge_type: BadgeType,
-        #[ink(topic)]
-        requester: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        evidence_url: String,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
-    }
+    /// Scaling factor for the per-share income accumulator
+    pub const INCOME_PRECISION: u128 = 1_000_000_000_000;
 
-    /// Event emitted when a verification is reviewed
-    #[ink(event)]
-    pub struct VerificationReviewed {
-        #[ink(topic)]
-        request_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        reviewer: AccountId,
-        #[ink(topic)]
-        approved: bool,
-        #[ink(topic)]
-        event_version: u8,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct FractionalInfo {
+        pub total_shares: u128,
+        pub enabled: bool,
+        pub created_at: u64,
     }
 
-    /// Event emitted when an appeal is submitted
-    #[ink(event)]
-    pub struct AppealSubmitted {
-        #[ink(topic)]
-        appeal_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        badge_type: BadgeType,
-        #[ink(topic)]
-        appellant: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        reason: String,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Global analytics data
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct GlobalAnalytics {
+        pub total_properties: u64,
+        pub total_valuation: u128,
+        pub average_valuation: u128,
+        pub total_size: u64,
+        pub average_size: u64,
+        pub unique_owners: u64,
     }
 
-    /// Event emitted when an appeal is resolved
-    #[ink(event)]
-    pub struct AppealResolved {
-        #[ink(topic)]
-        appeal_id: u64,
-        #[ink(topic)]
-        property_id: u64,
-        #[ink(topic)]
-        resolved_by: AccountId,
-        #[ink(topic)]
-        approved: bool,
-        #[ink(topic)]
-        event_version: u8,
-        resolution: String,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Gas metrics for monitoring
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct GasMetrics {
+        pub last_operation_gas: u64,
+        pub average_operation_gas: u64,
+        pub total_operations: u64,
+        pub min_gas_used: u64,
+        pub max_gas_used: u64,
     }
 
-    /// Event emitted when a verifier is added or removed
-    #[ink(event)]
-    pub struct VerifierUpdated {
-        #[ink(topic)]
-        verifier: AccountId,
-        #[ink(topic)]
-        authorized: bool,
-        #[ink(topic)]
-        updated_by: AccountId,
-        #[ink(topic)]
-        event_version: u8,
-        timestamp: u64,
-        block_number: u32,
-        transaction_hash: Hash,
+    /// Gas tracker for monitoring usage
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct GasTracker {
+        pub total_gas_used: u64,
+        pub operation_count: u64,
+        pub last_operation_gas: u64,
+        pub min_gas_used: u64,
+        pub max_gas_used: u64,
     }
 
-    /// Event emitted when contract is paused
-    #[ink(event)]
-    pub struct ContractPaused {
-        #[ink(topic)]
-        by: AccountId,
-        #[ink(topic)]
-        reason: String,
-        timestamp: u64,
-        auto_resume_at: Option<u64>,
+    /// Badge types for property verification
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum BadgeType {
+        OwnerVerification,    // KYC/Identity verified
+        DocumentVerification, // Legal documents verified
+        LegalCompliance,      // Regulatory compliance verified
+        PremiumListing,       // Premium tier property
     }
 
-    /// Event emitted when a resume is requested
-    #[ink(event)]
-    pub struct ResumeRequested {
-        #[ink(topic)]
-        requester: AccountId,
-        timestamp: u64,
+    /// Badge information
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Badge {
+        pub badge_type: BadgeType,
+        pub issued_at: u64,
+        pub issued_by: AccountId,
+        pub expires_at: Option<u64>,
+        pub metadata_url: String,
+        pub revoked: bool,
+        pub revoked_at: Option<u64>,
+        pub revocation_reason: String,
     }
 
-    /// Event emitted when a resume request is approved
-    #[ink(event)]
-    pub struct ResumeApproved {
-        #[ink(topic)]
-        approver: AccountId,
-        current_approvals: u32,
-        required_approvals: u32,
-        timestamp: u64,
+    /// Verification request for badge
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct VerificationRequest {
+        pub id: u64,
+        pub property_id: u64,
+        pub badge_type: BadgeType,
+        pub requester: AccountId,
+        pub requested_at: u64,
+        pub evidence_url: String,
+        pub status: VerificationStatus,
+        pub reviewed_by: Option<AccountId>,
+        pub reviewed_at: Option<u64>,
     }
 
-    /// Event emitted when contract is resumed
-    #[ink(event)]
-    pub struct ContractResumed {
-        #[ink(topic)]
-        by: AccountId,
-        timestamp: u64,
+    /// Verification status
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum VerificationStatus {
+        Pending,
+        Approved,
+        Rejected,
     }
 
-    /// Event emitted when a pause guardian is updated
-    #[ink(event)]
-    pub struct PauseGuardianUpdated {
-        #[ink(topic)]
-        guardian: AccountId,
-        #[ink(topic)]
-        is_guardian: bool,
-        updated_by: AccountId,
+    /// Appeal for badge revocation
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Appeal {
+        pub id: u64,
+        pub property_id: u64,
+        pub badge_type: BadgeType,
+        pub appellant: AccountId,
+        pub reason: String,
+        pub submitted_at: u64,
+        pub status: AppealStatus,
+        pub resolved_by: Option<AccountId>,
+        pub resolved_at: Option<u64>,
+        pub resolution: String,
     }
 
-    impl PropertyRegistry {
-        /// Creates a new PropertyRegistry contract
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            let caller = Self::env().caller();
-            let timestamp = Self::env().block_timestamp();
-            let block_number = Self::env().block_number();
+    /// Appeal status
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum AppealStatus {
+        Pending,
+        Approved,
+        Rejected,
+    }
 
-            let contract = Self {
-                properties: Mapping::default(),
-                owner_properties: Mapping::default(),
-                property_owners: Mapping::default(),
-                approvals: Mapping::default(),
-                property_count: 0,
-                version: 1,
-                admin: caller,
-                escrows: Mapping::default(),
-                escrow_count: 0,
-                gas_tracker: GasTracker {
-                    total_gas_used: 0,
-                    operation_count: 0,
-                    last_operation_gas: 0,
-                    min_gas_used: u64::MAX,
-                    max_gas_used: 0,
-                },
-                compliance_registry: None,
-                property_badges: Mapping::default(),
-                badge_verifiers: Mapping::default(),
-                verification_requests: Mapping::default(),
-                verification_count: 0,
-                appeals: Mapping::default(),
-                appeal_count: 0,
-                pause_info: PauseInfo {
-                    paused: false,
-                    paused_at: None,
-                    paused_by: None,
-                    reason: None,
-                    auto_resume_at: None,
-                    resume_request_active: false,
-                    resume_requester: None,
-                    resume_approvals: Vec::new(),
-                    required_approvals: 2, // Default requirement
-                },
-                pause_guardians: Mapping::default(),
-                oracle: None,
-                fee_manager: None,
-                fractional: Mapping::default(),
-            };
+    /// Pause information
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PauseInfo {
+        pub paused: bool,
+        pub paused_at: Option<u64>,
+        pub paused_by: Option<AccountId>,
+        pub reason: Option<String>,
+        pub auto_resume_at: Option<u64>,
 
-            // Emit contract initialization event
-            Self::env().emit_event(ContractInitialized {
-                admin: caller,
-                contract_version: 1,
-                timestamp,
-                block_number,
+        // For Resume Process
+        pub resume_request_active: bool,
+        pub resume_requester: Option<AccountId>,
+        pub resume_approvals: Vec<AccountId>,
+        pub required_approvals: u32,
+    }
+
+    /// Lifecycle status of a lease
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum LeaseStatus {
+        /// Offered by the owner, awaiting the tenant's acceptance
+        Pending,
+        Active,
+        Ended,
+    }
+
+    /// Rental agreement between a property's owner and a tenant
+    /// Owner-side actions follow the property's current owner, so a lease survives a sale.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Lease {
+        pub id: u64,
+        pub property_id: u64,
+        pub tenant: AccountId,
+        /// Rent due each period
+        pub rent: u128,
+        /// Length of a rent period, in milliseconds
+        pub period: u64,
+        /// Timestamp the lease runs until
+        pub term_end: u64,
+        pub status: LeaseStatus,
+        pub created_at: u64,
+        /// Timestamp the tenant accepted the lease
+        pub started_at: Option<u64>,
+        /// Timestamp rent has been paid up to; the next period's rent falls due at this time
+        /// and is late once that period has run its course
+        pub paid_through: u64,
+        /// Late fees the tenant has paid over the life of the lease
+        pub late_fees_paid: u128,
+        /// Security deposit the tenant locks when accepting
+        pub deposit: u128,
+        pub ended_at: Option<u64>,
+        /// Notice either party must give to end the lease early, in milliseconds
+        pub notice_period: u64,
+        /// Paid by whichever party ends the lease before its agreed term
+        pub early_termination_penalty: u128,
+        pub notice_given_by: Option<AccountId>,
+        /// Lease this one sublets; the parent's tenant then acts as owner
+        pub parent_lease: Option<u64>,
+        /// Whether the property owner has approved this sublease
+        pub sublease_approved: bool,
+        /// Rent received from subtenants, passed through to this lease's tenant
+        pub sublease_rent_received: u128,
+        /// Rent-to-own terms, if the tenant may buy the property
+        pub purchase_option: Option<PurchaseOption>,
+        /// Rent accrued toward the purchase price
+        pub purchase_credit: u128,
+        /// Number of times the lease has been renewed
+        pub renewals: u32,
+        /// Earlier tenants who assigned the lease on, oldest first
+        pub previous_tenants: Vec<AccountId>,
+        /// Hash of the signed off-chain lease document both parties agreed to
+        pub document_hash: Option<Hash>,
+    }
+
+    /// Maintenance ticket a tenant files against a lease
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct MaintenanceRequest {
+        pub id: u64,
+        pub lease_id: u64,
+        pub filed_by: AccountId,
+        /// Hash of the off-chain description and photos
+        pub description_hash: Hash,
+        pub filed_at: u64,
+        pub resolved_at: Option<u64>,
+        /// Hash of the owner's off-chain account of the fix
+        pub resolution_hash: Option<Hash>,
+    }
+
+    /// Habitability dispute during which the tenant's rent is held by the contract
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct RentDispute {
+        pub lease_id: u64,
+        /// Hash of the tenant's off-chain evidence
+        pub evidence: Hash,
+        pub opened_at: u64,
+        /// Rent paid in since the dispute opened
+        pub held: u128,
+    }
+
+    /// Proposed transfer of a lease to a new tenant
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LeaseAssignment {
+        pub assignee: AccountId,
+        /// Whether the owner has approved the assignment
+        pub approved: bool,
+        pub proposed_at: u64,
+    }
+
+    /// Updated terms an owner offers to extend a lease with
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LeaseRenewal {
+        pub rent: u128,
+        pub term_end: u64,
+        pub proposed_at: u64,
+    }
+
+    /// Rent-to-own terms of a lease
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PurchaseOption {
+        /// Price the tenant may buy the property at
+        pub price: u128,
+        /// Portion of each rent payment credited toward the price, in basis points
+        pub credit_bps: u32,
+    }
+
+    /// Lifecycle status of a security deposit
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum DepositStatus {
+        Held,
+        /// The owner has claimed deductions the tenant may still dispute
+        Claimed,
+        Disputed,
+        Settled,
+    }
+
+    /// Security deposit held by the contract for a lease
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SecurityDeposit {
+        pub lease_id: u64,
+        /// Amount currently held
+        pub amount: u128,
+        /// Deduction claimed by the owner
+        pub deduction: u128,
+        /// Hash of the documentation backing the deduction
+        pub evidence: Option<Hash>,
+        pub claimed_at: Option<u64>,
+        pub status: DepositStatus,
+    }
+
+    // ============================================================================
+    // STRUCTURED EVENT SYSTEM - Version 1.0
+    // ============================================================================
+    // All events follow a standardized format with:
+    // - Indexed fields (topics) for efficient querying
+    // - Timestamps and block numbers for historical tracking
+    // - Event versioning for future compatibility
+    // - Detailed metadata for off-chain indexing
+    // ============================================================================
+
+    /// Event emitted when the contract is initialized
+    #[ink(event)]
+    pub struct ContractInitialized {
+        #[ink(topic)]
+        admin: AccountId,
+        #[ink(topic)]
+        contract_version: u32,
+        timestamp: u64,
+        block_number: u32,
+    }
+
+    /// Event emitted when a property is registered
+    /// Indexed fields: property_id, owner for efficient filtering
+    #[ink(event)]
+    pub struct PropertyRegistered {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        location: String,
+        size: u64,
+        valuation: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when property ownership is transferred
+    /// Indexed fields: property_id, from, to for efficient querying
+    #[ink(event)]
+    pub struct PropertyTransferred {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        transferred_by: AccountId, // The account that initiated the transfer
+    }
+
+    /// Event emitted when property metadata is updated
+    /// Indexed fields: property_id, owner for efficient filtering
+    #[ink(event)]
+    pub struct PropertyMetadataUpdated {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        old_location: String,
+        new_location: String,
+        old_valuation: u128,
+        new_valuation: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an account is approved to transfer a property
+    /// Indexed fields: property_id, owner, approved for efficient querying
+    #[ink(event)]
+    pub struct ApprovalGranted {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        approved: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an approval is cleared/revoked
+    /// Indexed fields: property_id, owner for efficient querying
+    #[ink(event)]
+    pub struct ApprovalCleared {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an escrow is created
+    /// Indexed fields: escrow_id, property_id, buyer, seller for efficient querying
+    #[ink(event)]
+    pub struct EscrowCreated {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when the seller accepts a buyer-initiated escrow
+    /// Indexed fields: escrow_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct EscrowAccepted {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when the buyer deposits funds into an escrow
+    /// Indexed fields: escrow_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct EscrowFunded {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        funded_amount: u128,
+        fully_funded: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when escrow is released and property transferred
+    /// Indexed fields: escrow_id, property_id, buyer, seller for efficient querying
+    #[ink(event)]
+    pub struct EscrowReleased {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        fee: u128,
+        /// Resale royalty paid out of the price
+        royalty: u128,
+        /// Amount paid to the seller after the platform fee and royalty
+        seller_payout: u128,
+        /// Surplus deposit returned to the buyer
+        excess_refunded: u128,
+        /// Hash of the purchase agreement the sale closed under, if any
+        terms_hash: Option<Hash>,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        released_by: AccountId,
+    }
+
+    /// Event emitted when escrow is refunded
+    /// Indexed fields: escrow_id, property_id, buyer, seller for efficient querying
+    #[ink(event)]
+    pub struct EscrowRefunded {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        refunded_by: AccountId,
+    }
+
+    /// Event emitted when an escrow passes its deadline and the buyer reclaims the deposit
+    /// Indexed fields: escrow_id, property_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct EscrowExpired {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        deadline: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a keeper refunds a batch of expired escrows
+    /// Indexed fields: keeper for efficient querying
+    #[ink(event)]
+    pub struct ExpiredEscrowsSwept {
+        #[ink(topic)]
+        keeper: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        escrow_ids: Vec<u64>,
+        count: u64,
+        bounty: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a buyer is barred from opening escrows
+    /// Indexed fields: buyer for efficient querying
+    #[ink(event)]
+    pub struct BuyerBarred {
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        barred_until: u64,
+        defaults: u32,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        barred_by: Option<AccountId>,
+    }
+
+    /// Event emitted when a party disputes an escrow
+    /// Indexed fields: escrow_id, property_id, raised_by for efficient querying
+    #[ink(event)]
+    pub struct EscrowDisputed {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        raised_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        reason: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when the arbitrator resolves an escrow dispute
+    /// Indexed fields: escrow_id, property_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct EscrowDisputeResolved {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        buyer_amount: u128,
+        seller_amount: u128,
+        title_transferred: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        resolved_by: AccountId,
+    }
+
+    /// Event emitted when one party asks to cancel an escrow
+    /// Indexed fields: escrow_id, property_id, requested_by for efficient querying
+    #[ink(event)]
+    pub struct EscrowCancelRequested {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        requested_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when both parties agree to cancel an escrow
+    /// Indexed fields: escrow_id, property_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct EscrowCancelled {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        confirmed_by: AccountId,
+    }
+
+    /// Event emitted when accumulated platform fees are withdrawn
+    /// Indexed fields: treasury for efficient querying
+    #[ink(event)]
+    pub struct FeesWithdrawn {
+        #[ink(topic)]
+        treasury: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        withdrawn_by: AccountId,
+    }
+
+    /// Event emitted when a buyer walks away and the earnest deposit goes to the seller
+    /// Indexed fields: escrow_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct EarnestForfeited {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        refunded_to_buyer: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a closing condition is attached to an escrow
+    /// Indexed fields: escrow_id, added_by for efficient querying
+    #[ink(event)]
+    pub struct EscrowConditionAdded {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        added_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        condition_index: u32,
+        name: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a closing condition is marked satisfied
+    /// Indexed fields: escrow_id, satisfied_by for efficient querying
+    #[ink(event)]
+    pub struct EscrowConditionSatisfied {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        satisfied_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        condition_index: u32,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a designated signer approves an escrow release
+    /// Indexed fields: escrow_id, signer for efficient querying
+    #[ink(event)]
+    pub struct EscrowReleaseApproved {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        signer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        approvals: u32,
+        threshold: u32,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when both parties agree to route an escrow into the yield source
+    /// Indexed fields: escrow_id, confirmed_by for efficient querying
+    #[ink(event)]
+    pub struct EscrowYieldEnabled {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        confirmed_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        buyer_share_bps: u32,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an escrow's yield position is closed and interest or loss is split
+    /// Indexed fields: escrow_id for efficient querying
+    #[ink(event)]
+    pub struct EscrowYieldSettled {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        principal: u128,
+        buyer_interest: u128,
+        seller_interest: u128,
+        /// Shortfall when the source returned less than the principal
+        loss: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when release is approved and the cooling-off window starts
+    /// Indexed fields: escrow_id, approved_by for efficient querying
+    #[ink(event)]
+    pub struct EscrowReleaseScheduled {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        approved_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        approved_at: u64,
+        release_after: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an escrow's notary signs off on the release
+    /// Indexed fields: escrow_id, notary for efficient querying
+    #[ink(event)]
+    pub struct EscrowNotaryApproved {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        notary: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an escrow party appoints an agent
+    /// Indexed fields: escrow_id, principal, agent for efficient querying
+    #[ink(event)]
+    pub struct EscrowAgentDelegated {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        principal: AccountId,
+        #[ink(topic)]
+        agent: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        expires_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an escrow party revokes an agent
+    /// Indexed fields: escrow_id, principal, agent for efficient querying
+    #[ink(event)]
+    pub struct EscrowAgentRevoked {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        principal: AccountId,
+        #[ink(topic)]
+        agent: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a purchase agreement hash is attached to an escrow
+    /// Indexed fields: escrow_id, terms_hash for efficient querying
+    #[ink(event)]
+    pub struct EscrowTermsAttached {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        terms_hash: Hash,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        attached_by: AccountId,
+    }
+
+    /// Event emitted when an escrow party acknowledges the attached purchase agreement
+    /// Indexed fields: escrow_id, party for efficient querying
+    #[ink(event)]
+    pub struct EscrowTermsAcknowledged {
+        #[ink(topic)]
+        escrow_id: u64,
+        #[ink(topic)]
+        party: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        terms_hash: Hash,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner lists a property for sale
+    /// Indexed fields: listing_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct PropertyListed {
+        #[ink(topic)]
+        listing_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        price: u128,
+        expires_at: Option<u64>,
+        /// True for listings reserved for a named buyer
+        private: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a seller changes a listing's price
+    /// Indexed fields: listing_id, property_id for efficient querying
+    #[ink(event)]
+    pub struct ListingPriceUpdated {
+        #[ink(topic)]
+        listing_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        old_price: u128,
+        new_price: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a seller withdraws a listing
+    /// Indexed fields: listing_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct PropertyDelisted {
+        #[ink(topic)]
+        listing_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a listing is bought
+    /// Indexed fields: listing_id, property_id, buyer, seller for efficient querying
+    #[ink(event)]
+    pub struct ListingPurchased {
+        #[ink(topic)]
+        listing_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        price: u128,
+        private: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner puts a property up for auction
+    /// Indexed fields: auction_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct AuctionStarted {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        reserve_price: u128,
+        ends_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a bid becomes the highest bid of an auction
+    /// Indexed fields: auction_id, bidder for efficient querying
+    #[ink(event)]
+    pub struct BidPlaced {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        bidder: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        /// Previous highest bidder, refunded by this bid
+        outbid: Option<AccountId>,
+        /// Auction end time after this bid, including any anti-sniping extension
+        ends_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an auction is finalized
+    /// Indexed fields: auction_id, property_id, winner for efficient querying
+    #[ink(event)]
+    pub struct AuctionFinalized {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        winner: Option<AccountId>,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        finalized_by: AccountId,
+    }
+
+    /// Event emitted when an owner starts a declining-price auction
+    /// Indexed fields: auction_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct DutchAuctionStarted {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        start_price: u128,
+        floor_price: u128,
+        ends_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a declining-price auction is bought or cancelled
+    /// Indexed fields: auction_id, property_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct DutchAuctionClosed {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: Option<AccountId>,
+        #[ink(topic)]
+        event_version: u8,
+        price: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner starts a sealed-bid auction
+    /// Indexed fields: auction_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct SealedAuctionStarted {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        reserve_price: u128,
+        commit_ends_at: u64,
+        reveal_ends_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a bidder commits or reveals a sealed bid
+    /// Indexed fields: auction_id, bidder for efficient querying
+    #[ink(event)]
+    pub struct SealedBidSubmitted {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        bidder: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        deposit: u128,
+        /// Revealed amount; None for a commitment
+        revealed_amount: Option<u128>,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a sealed-bid auction is finalized
+    /// Indexed fields: auction_id, property_id, winner for efficient querying
+    #[ink(event)]
+    pub struct SealedAuctionFinalized {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        winner: Option<AccountId>,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        finalized_by: AccountId,
+    }
+
+    /// Event emitted when a purchase offer is made on a property
+    /// Indexed fields: offer_id, property_id, offeror for efficient querying
+    #[ink(event)]
+    pub struct OfferMade {
+        #[ink(topic)]
+        offer_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        offeror: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        expires_at: Option<u64>,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when the owner counters a purchase offer
+    /// Indexed fields: offer_id, property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct OfferCountered {
+        #[ink(topic)]
+        offer_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        offered_amount: u128,
+        counter_price: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a purchase offer is accepted, rejected or withdrawn
+    /// Indexed fields: offer_id, property_id, offeror for efficient querying
+    #[ink(event)]
+    pub struct OfferResolved {
+        #[ink(topic)]
+        offer_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        offeror: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        status: OfferStatus,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        resolved_by: AccountId,
+    }
+
+    /// Event emitted when an owner lists several properties as one bundle
+    /// Indexed fields: bundle_id, seller for efficient querying
+    #[ink(event)]
+    pub struct BundleListed {
+        #[ink(topic)]
+        bundle_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        property_ids: Vec<u64>,
+        price: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a bundle is bought or cancelled
+    /// Indexed fields: bundle_id, seller, buyer for efficient querying
+    #[ink(event)]
+    pub struct BundleClosed {
+        #[ink(topic)]
+        bundle_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        buyer: Option<AccountId>,
+        #[ink(topic)]
+        event_version: u8,
+        status: ListingStatus,
+        property_ids: Vec<u64>,
+        price: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a platform fee is taken from a marketplace sale
+    /// Indexed fields: property_id, seller for efficient querying
+    #[ink(event)]
+    pub struct MarketplaceFeeCharged {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        sale_type: SaleType,
+        asset: EscrowAsset,
+        sale_price: u128,
+        fee: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a royalty is paid out of a sale
+    /// Indexed fields: property_id, receiver, seller for efficient querying
+    #[ink(event)]
+    pub struct RoyaltyPaid {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        receiver: AccountId,
+        #[ink(topic)]
+        seller: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        sale_price: u128,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lien is recorded against a property for a financed purchase
+    /// Indexed fields: property_id, creditor, debtor for efficient querying
+    #[ink(event)]
+    pub struct LienRecorded {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        creditor: AccountId,
+        #[ink(topic)]
+        debtor: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lien is repaid in part or cleared
+    /// Indexed fields: property_id, creditor, debtor for efficient querying
+    #[ink(event)]
+    pub struct LienUpdated {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        creditor: AccountId,
+        #[ink(topic)]
+        debtor: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        /// Amount repaid by this update
+        repaid: u128,
+        /// Balance still owed; the lien is cleared at zero
+        outstanding: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        updated_by: AccountId,
+    }
+
+    /// Event emitted when a marketplace contract is added to or removed from the operator whitelist
+    /// Indexed fields: operator for efficient querying
+    #[ink(event)]
+    pub struct MarketplaceOperatorUpdated {
+        #[ink(topic)]
+        operator: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        approved: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        updated_by: AccountId,
+    }
+
+    /// Event emitted when a property is split into fractional shares
+    /// Indexed fields: property_id, owner for efficient querying
+    #[ink(event)]
+    pub struct SharesIssued {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        total_shares: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when fractional shares of a property change hands
+    /// Indexed fields: property_id, from, to for efficient querying
+    #[ink(event)]
+    pub struct SharesTransferred {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a PSP22 share token is instantiated for a property
+    /// Indexed fields: property_id, token for efficient querying
+    #[ink(event)]
+    pub struct ShareTokenDeployed {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        token: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        total_shares: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when income is deposited for a property's shareholders
+    /// Indexed fields: property_id, depositor for efficient querying
+    #[ink(event)]
+    pub struct IncomeDistributed {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        depositor: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a shareholder claims their share of distributed income
+    /// Indexed fields: property_id, holder for efficient querying
+    #[ink(event)]
+    pub struct IncomeClaimed {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        holder: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a sole shareholder burns all shares and takes back the whole title
+    /// Indexed fields: property_id, owner for efficient querying
+    #[ink(event)]
+    pub struct PropertyDefractionalized {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        burned_shares: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a property's cap table is snapshotted
+    /// Indexed fields: property_id, snapshot_id for efficient querying
+    #[ink(event)]
+    pub struct ShareSnapshotTaken {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        snapshot_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        total_shares: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when new shares of a property are offered
+    /// Indexed fields: issuance_id, property_id for efficient querying
+    #[ink(event)]
+    pub struct ShareIssuanceOpened {
+        #[ink(topic)]
+        issuance_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        new_shares: u128,
+        price_per_share: u128,
+        preemption_ends_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when newly issued shares are bought
+    /// Indexed fields: issuance_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct IssuedSharesPurchased {
+        #[ink(topic)]
+        issuance_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        shares: u128,
+        preemptive: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a share issuance closes, leaving any unsold shares unissued
+    /// Indexed fields: issuance_id, property_id for efficient querying
+    #[ink(event)]
+    pub struct ShareIssuanceClosed {
+        #[ink(topic)]
+        issuance_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        sold: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a holder sets how many shares a spender may move for them
+    /// Indexed fields: property_id, owner, spender for efficient querying
+    #[ink(event)]
+    pub struct SharesApproved {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a holder pledges shares to a lender
+    /// Indexed fields: pledge_id, holder, lender for efficient querying
+    #[ink(event)]
+    pub struct SharesPledged {
+        #[ink(topic)]
+        pledge_id: u64,
+        #[ink(topic)]
+        holder: AccountId,
+        #[ink(topic)]
+        lender: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        property_id: u64,
+        shares: u128,
+        maturity: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lender releases pledged shares back to the holder
+    /// Indexed fields: pledge_id for efficient querying
+    #[ink(event)]
+    pub struct PledgeReleased {
+        #[ink(topic)]
+        pledge_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lender records that the holder defaulted on a pledge
+    /// Indexed fields: pledge_id for efficient querying
+    #[ink(event)]
+    pub struct PledgeDefaulted {
+        #[ink(topic)]
+        pledge_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lender takes pledged shares after a default
+    /// Indexed fields: pledge_id, lender for efficient querying
+    #[ink(event)]
+    pub struct PledgedSharesSeized {
+        #[ink(topic)]
+        pledge_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        shares: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when shares are issued to a holder under a lockup or vesting schedule
+    /// Indexed fields: property_id, holder for efficient querying
+    #[ink(event)]
+    pub struct SharesVestingGranted {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        holder: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        amount: u128,
+        cliff: u64,
+        end: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a shareholder opens a proposal
+    /// Indexed fields: proposal_id, property_id, proposer for efficient querying
+    #[ink(event)]
+    pub struct ProposalCreated {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        proposer: AccountId,
+        event_version: u8,
+        action: ProposalAction,
+        voting_ends_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a shareholder votes on a proposal
+    /// Indexed fields: proposal_id, voter for efficient querying
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        voter: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        support: bool,
+        weight: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a passed proposal's action is carried out
+    /// Indexed fields: proposal_id, property_id for efficient querying
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        #[ink(topic)]
+        proposal_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        action: ProposalAction,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a majority holder buys out the remaining shares of a property
+    /// Indexed fields: property_id, buyer for efficient querying
+    #[ink(event)]
+    pub struct BuyoutStarted {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        price_per_share: u128,
+        outstanding_shares: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a minority holder claims their buyout payout
+    /// Indexed fields: property_id, holder for efficient querying
+    #[ink(event)]
+    pub struct BuyoutClaimed {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        holder: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        shares: u128,
+        payout: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when admin is changed
+    /// Indexed fields: old_admin, new_admin for efficient querying
+    #[ink(event)]
+    pub struct AdminChanged {
+        #[ink(topic)]
+        old_admin: AccountId,
+        #[ink(topic)]
+        new_admin: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        changed_by: AccountId,
+    }
+
+    /// Batch event for multiple property registrations
+    /// Indexed fields: owner for efficient filtering
+    #[ink(event)]
+    pub struct BatchPropertyRegistered {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        property_ids: Vec<u64>,
+        count: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Batch event for multiple property transfers to the same recipient
+    /// Indexed fields: from, to for efficient querying
+    #[ink(event)]
+    pub struct BatchPropertyTransferred {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        property_ids: Vec<u64>,
+        count: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        transferred_by: AccountId,
+    }
+
+    /// Batch event for multiple escrows settled in one transaction
+    /// Indexed fields: buyer for efficient querying
+    #[ink(event)]
+    pub struct BatchEscrowReleased {
+        #[ink(topic)]
+        buyer: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        escrow_ids: Vec<u64>,
+        count: u64,
+        total_amount: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Batch event for multiple metadata updates
+    /// Indexed fields: owner for efficient filtering
+    #[ink(event)]
+    pub struct BatchMetadataUpdated {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        property_ids: Vec<u64>,
+        count: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Batch event for multiple property transfers to different recipients
+    /// Indexed fields: from for efficient querying
+    #[ink(event)]
+    pub struct BatchPropertyTransferredToMultiple {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        transfers: Vec<(u64, AccountId)>, // (property_id, to)
+        count: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        transferred_by: AccountId,
+    }
+
+    /// Event emitted when a badge is issued to a property
+    #[ink(event)]
+    pub struct BadgeIssued {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        badge_type: BadgeType,
+        #[ink(topic)]
+        issued_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        expires_at: Option<u64>,
+        metadata_url: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a badge is revoked
+    #[ink(event)]
+    pub struct BadgeRevoked {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        badge_type: BadgeType,
+        #[ink(topic)]
+        revoked_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        reason: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a verification is requested
+    #[ink(event)]
+    pub struct VerificationRequested {
+        #[ink(topic)]
+        request_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        badge_type: BadgeType,
+        #[ink(topic)]
+        requester: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        evidence_url: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a verification is reviewed
+    #[ink(event)]
+    pub struct VerificationReviewed {
+        #[ink(topic)]
+        request_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        reviewer: AccountId,
+        #[ink(topic)]
+        approved: bool,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an appeal is submitted
+    #[ink(event)]
+    pub struct AppealSubmitted {
+        #[ink(topic)]
+        appeal_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        badge_type: BadgeType,
+        #[ink(topic)]
+        appellant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        reason: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an appeal is resolved
+    #[ink(event)]
+    pub struct AppealResolved {
+        #[ink(topic)]
+        appeal_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        resolved_by: AccountId,
+        #[ink(topic)]
+        approved: bool,
+        #[ink(topic)]
+        event_version: u8,
+        resolution: String,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a verifier is added or removed
+    #[ink(event)]
+    pub struct VerifierUpdated {
+        #[ink(topic)]
+        verifier: AccountId,
+        #[ink(topic)]
+        authorized: bool,
+        #[ink(topic)]
+        updated_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when contract is paused
+    #[ink(event)]
+    pub struct ContractPaused {
+        #[ink(topic)]
+        by: AccountId,
+        #[ink(topic)]
+        reason: String,
+        timestamp: u64,
+        auto_resume_at: Option<u64>,
+    }
+
+    /// Event emitted when a resume is requested
+    #[ink(event)]
+    pub struct ResumeRequested {
+        #[ink(topic)]
+        requester: AccountId,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a resume request is approved
+    #[ink(event)]
+    pub struct ResumeApproved {
+        #[ink(topic)]
+        approver: AccountId,
+        current_approvals: u32,
+        required_approvals: u32,
+        timestamp: u64,
+    }
+
+    /// Event emitted when contract is resumed
+    #[ink(event)]
+    pub struct ContractResumed {
+        #[ink(topic)]
+        by: AccountId,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a pause guardian is updated
+    #[ink(event)]
+    pub struct PauseGuardianUpdated {
+        #[ink(topic)]
+        guardian: AccountId,
+        #[ink(topic)]
+        is_guardian: bool,
+        updated_by: AccountId,
+    }
+
+    /// Event emitted when an owner offers a lease on a property
+    /// Indexed fields: lease_id, property_id, tenant for efficient querying
+    #[ink(event)]
+    pub struct LeaseCreated {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        tenant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        rent: u128,
+        period: u64,
+        term_end: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant buys the leased property under a rent-to-own option
+    /// Indexed fields: lease_id, property_id, tenant for efficient querying
+    #[ink(event)]
+    pub struct PurchaseOptionExercised {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        tenant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        credit_applied: u128,
+        paid: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner offers to renew a lease on new terms
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct LeaseRenewalProposed {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        rent: u128,
+        term_end: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant accepts renewal terms and the lease is extended
+    /// Indexed fields: lease_id, tenant for efficient querying
+    #[ink(event)]
+    pub struct LeaseRenewed {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        tenant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        rent: u128,
+        term_end: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant disputes habitability and starts paying rent into escrow
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct RentDisputeOpened {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        evidence: Hash,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when the arbitrator releases rent held over a dispute
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct RentEscrowReleased {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        to_owner: u128,
+        to_tenant: u128,
+        arbitrator: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant files a maintenance request
+    /// Indexed fields: request_id, lease_id for efficient querying
+    #[ink(event)]
+    pub struct MaintenanceRequested {
+        #[ink(topic)]
+        request_id: u64,
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        description_hash: Hash,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner marks a maintenance request resolved
+    /// Indexed fields: request_id, lease_id for efficient querying
+    #[ink(event)]
+    pub struct MaintenanceResolved {
+        #[ink(topic)]
+        request_id: u64,
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        resolution_hash: Hash,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant proposes assigning their lease
+    /// Indexed fields: lease_id, assignee for efficient querying
+    #[ink(event)]
+    pub struct LeaseAssignmentProposed {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        assignee: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        tenant: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner approves a lease assignment
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct LeaseAssignmentApproved {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        approved_by: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lease passes to a new tenant
+    /// Indexed fields: lease_id, from, to for efficient querying
+    #[ink(event)]
+    pub struct LeaseAssigned {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant proposes subletting their lease
+    /// Indexed fields: sublease_id, parent_lease_id for efficient querying
+    #[ink(event)]
+    pub struct SubleaseProposed {
+        #[ink(topic)]
+        sublease_id: u64,
+        #[ink(topic)]
+        parent_lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        subtenant: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a property owner approves a sublease
+    /// Indexed fields: sublease_id for efficient querying
+    #[ink(event)]
+    pub struct SubleaseApproved {
+        #[ink(topic)]
+        sublease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        approved_by: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant accepts a lease
+    /// Indexed fields: lease_id, tenant for efficient querying
+    #[ink(event)]
+    pub struct LeaseAccepted {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        tenant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        document_hash: Option<Hash>,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant pays a period of rent
+    /// Indexed fields: lease_id, tenant for efficient querying
+    #[ink(event)]
+    pub struct RentPaid {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        tenant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        rent: u128,
+        late_fee: u128,
+        paid_through: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a party gives notice to end a lease
+    /// Indexed fields: lease_id, given_by for efficient querying
+    #[ink(event)]
+    pub struct LeaseNoticeGiven {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        given_by: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        term_end: u64,
+        penalty: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner claims deductions from a security deposit
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct DepositDeductionClaimed {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        deduction: u128,
+        evidence: Hash,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a tenant disputes claimed deposit deductions
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct DepositDisputed {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a security deposit is paid out
+    /// Indexed fields: lease_id for efficient querying
+    #[ink(event)]
+    pub struct DepositSettled {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        deduction: u128,
+        refund: u128,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an owner terminates a lease over missed rent
+    /// Indexed fields: lease_id, tenant for efficient querying
+    #[ink(event)]
+    pub struct LeaseTerminatedForDefault {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        tenant: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        missed_periods: u64,
+        arrears: u128,
+        terminated_by: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when a lease ends or a pending lease is withdrawn
+    /// Indexed fields: lease_id, property_id for efficient querying
+    #[ink(event)]
+    pub struct LeaseEnded {
+        #[ink(topic)]
+        lease_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        event_version: u8,
+        ended_by: AccountId,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted when an account is added to or removed from the sanctions blocklist
+    /// Indexed fields: account for efficient querying
+    #[ink(event)]
+    pub struct SanctionsListUpdated {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        sanctioned: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        updated_by: AccountId,
+    }
+
+    /// Event emitted when an account is exempted from or returned to compliance checks
+    /// Indexed fields: account for efficient querying
+    #[ink(event)]
+    pub struct ComplianceExemptionUpdated {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        exempt: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+        updated_by: AccountId,
+    }
+
+    /// Event emitted when a signed compliance attestation is accepted
+    /// Indexed fields: account for efficient querying
+    #[ink(event)]
+    pub struct ComplianceAttested {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        event_version: u8,
+        tier: u8,
+        nonce: u64,
+        expires_at: u64,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    /// Event emitted whenever an account is screened for compliance
+    /// Indexed fields: account, operation for efficient querying
+    #[ink(event)]
+    pub struct ComplianceChecked {
+        #[ink(topic)]
+        account: AccountId,
+        #[ink(topic)]
+        operation: ComplianceOperation,
+        #[ink(topic)]
+        event_version: u8,
+        result: bool,
+        timestamp: u64,
+        block_number: u32,
+        transaction_hash: Hash,
+    }
+
+    impl PropertyRegistry {
+        /// Creates a new PropertyRegistry contract
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let caller = Self::env().caller();
+            let timestamp = Self::env().block_timestamp();
+            let block_number = Self::env().block_number();
+
+            let contract = Self {
+                properties: Mapping::default(),
+                owner_properties: Mapping::default(),
+                property_owners: Mapping::default(),
+                approvals: Mapping::default(),
+                property_count: 0,
+                version: 1,
+                admin: caller,
+                escrows: Mapping::default(),
+                escrow_count: 0,
+                escrow_conditions: Mapping::default(),
+                escrow_signers: Mapping::default(),
+                escrow_shares: Mapping::default(),
+                property_escrow_count: Mapping::default(),
+                property_escrows: Mapping::default(),
+                buyer_escrow_count: Mapping::default(),
+                buyer_escrows: Mapping::default(),
+                seller_escrow_count: Mapping::default(),
+                seller_escrows: Mapping::default(),
+                total_escrowed: 0,
+                yield_source: None,
+                escrow_yield_proposals: Mapping::default(),
+                escrow_cooling_off_period: 0,
+                escrow_acceptance_window: 0,
+                escrow_delegations: Mapping::default(),
+                sale_records: Mapping::default(),
+                sale_count: 0,
+                property_sales: Mapping::default(),
+                escrow_arbitrator: None,
+                accepted_escrow_assets: Vec::from([EscrowAsset::Native]),
+                buyer_defaults: Mapping::default(),
+                barred_buyers: Mapping::default(),
+                buyer_default_threshold: 0,
+                buyer_bar_period: 0,
+                escrow_min_valuation_bps: 0,
+                escrow_fee_bps: 0,
+                escrow_sweep_bounty: 0,
+                escrow_sweep_cursor: 0,
+                treasury: caller,
+                treasury_balance: 0,
+                gas_tracker: GasTracker {
+                    total_gas_used: 0,
+                    operation_count: 0,
+                    last_operation_gas: 0,
+                    min_gas_used: u64::MAX,
+                    max_gas_used: 0,
+                },
+                compliance_registry: None,
+                additional_compliance_registries: Vec::new(),
+                compliance_policy: CompliancePolicy::All,
+                compliance_fail_open: false,
+                compliance_cache_ttl: 0,
+                compliance_cache_epoch: 0,
+                compliance_cache: Mapping::default(),
+                kyc_tier_thresholds: Vec::new(),
+                compliance_officer: None,
+                sanctioned_accounts: Mapping::default(),
+                compliance_exempt_accounts: Mapping::default(),
+                compliance_confirmed_at: Mapping::default(),
+                compliance_max_age: 0,
+                compliance_operations: Vec::from([
+                    ComplianceOperation::RegisterProperty,
+                    ComplianceOperation::TransferProperty,
+                    ComplianceOperation::ListForSale,
+                    ComplianceOperation::Purchase,
+                    ComplianceOperation::CreateEscrow,
+                    ComplianceOperation::ReleaseEscrow,
+                    ComplianceOperation::Lease,
+                ]),
+                compliance_signer: None,
+                attestation_nonces: Mapping::default(),
+                compliance_signer_epoch: 0,
+                compliance_attestations: Mapping::default(),
+                property_badges: Mapping::default(),
+                badge_verifiers: Mapping::default(),
+                verification_requests: Mapping::default(),
+                verification_count: 0,
+                appeals: Mapping::default(),
+                appeal_count: 0,
+                pause_info: PauseInfo {
+                    paused: false,
+                    paused_at: None,
+                    paused_by: None,
+                    reason: None,
+                    auto_resume_at: None,
+                    resume_request_active: false,
+                    resume_requester: None,
+                    resume_approvals: Vec::new(),
+                    required_approvals: 2, // Default requirement
+                },
+                pause_guardians: Mapping::default(),
+                oracle: None,
+                fee_manager: None,
+                fractional: Mapping::default(),
+                share_balances: Mapping::default(),
+                share_snapshots: Mapping::default(),
+                share_snapshot_count: 0,
+                latest_share_snapshots: Mapping::default(),
+                share_balance_history: Mapping::default(),
+                share_issuances: Mapping::default(),
+                share_issuance_count: 0,
+                property_issuances: Mapping::default(),
+                preemption_purchases: Mapping::default(),
+                shareholder_count: Mapping::default(),
+                shareholders: Mapping::default(),
+                shareholder_positions: Mapping::default(),
+                share_token_code_hash: None,
+                share_tokens: Mapping::default(),
+                share_compliance_exempt_below: None,
+                income_per_share: Mapping::default(),
+                income_checkpoints: Mapping::default(),
+                unclaimed_income: Mapping::default(),
+                buyout_threshold_bps: 9_000,
+                buyouts: Mapping::default(),
+                proposals: Mapping::default(),
+                proposal_count: 0,
+                proposal_votes: Mapping::default(),
+                proposal_voting_period: 604_800_000,
+                approved_renovations: Mapping::default(),
+                share_vesting: Mapping::default(),
+                min_share_holdings: Mapping::default(),
+                share_allowances: Mapping::default(),
+                share_pledges: Mapping::default(),
+                share_pledge_count: 0,
+                pledged_shares: Mapping::default(),
+                listings: Mapping::default(),
+                listing_count: 0,
+                property_listings: Mapping::default(),
+                auctions: Mapping::default(),
+                auction_count: 0,
+                property_auctions: Mapping::default(),
+                auction_refunds: Mapping::default(),
+                dutch_auctions: Mapping::default(),
+                dutch_auction_count: 0,
+                property_dutch_auctions: Mapping::default(),
+                sealed_auctions: Mapping::default(),
+                sealed_auction_count: 0,
+                property_sealed_auctions: Mapping::default(),
+                sealed_bids: Mapping::default(),
+                offers: Mapping::default(),
+                offer_count: 0,
+                property_offers: Mapping::default(),
+                bundles: Mapping::default(),
+                bundle_count: 0,
+                property_bundles: Mapping::default(),
+                property_royalties: Mapping::default(),
+                marketplace_fee_bps: Mapping::default(),
+                active_listing_count: 0,
+                total_sales_volume: 0,
+                seller_sale_counts: Mapping::default(),
+                property_liens: Mapping::default(),
+                marketplace_operators: Mapping::default(),
+                sale_verification_requirements: Mapping::default(),
+                leases: Mapping::default(),
+                lease_count: 0,
+                property_active_leases: Mapping::default(),
+                property_lease_count: Mapping::default(),
+                property_leases: Mapping::default(),
+                tenant_lease_count: Mapping::default(),
+                tenant_leases: Mapping::default(),
+                lease_subleases: Mapping::default(),
+                lease_renewals: Mapping::default(),
+                lease_assignments: Mapping::default(),
+                rent_disputes: Mapping::default(),
+                rent_arbitrator: None,
+                maintenance_requests: Mapping::default(),
+                maintenance_request_count: 0,
+                lease_maintenance_requests: Mapping::default(),
+                rent_late_fee_bps: 500,
+                eviction_threshold_periods: 3,
+                lease_deposits: Mapping::default(),
+                deposit_claim_period: 1_209_600_000,
+            };
+
+            // Emit contract initialization event
+            Self::env().emit_event(ContractInitialized {
+                admin: caller,
+                contract_version: 1,
+                timestamp,
+                block_number,
+            });
+
+            contract
+        }
+
+        /// Returns the contract version
+        #[ink(message)]
+        pub fn version(&self) -> u32 {
+            self.version
+        }
+
+        /// Returns the admin account
+        #[ink(message)]
+        pub fn admin(&self) -> AccountId {
+            self.admin
+        }
+
+        /// Set the oracle contract address
+        #[ink(message)]
+        pub fn set_oracle(&mut self, oracle: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.oracle = Some(oracle);
+            Ok(())
+        }
+
+        /// Returns the oracle contract address
+        #[ink(message)]
+        pub fn oracle(&self) -> Option<AccountId> {
+            self.oracle
+        }
+
+        /// Set the fee manager contract address (admin only)
+        #[ink(message)]
+        pub fn set_fee_manager(&mut self, fee_manager: Option<AccountId>) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.fee_manager = fee_manager;
+            Ok(())
+        }
+
+        /// Returns the fee manager contract address
+        #[ink(message)]
+        pub fn get_fee_manager(&self) -> Option<AccountId> {
+            self.fee_manager
+        }
+
+        /// Get dynamic fee for an operation (calls fee manager if set; otherwise returns 0)
+        #[ink(message)]
+        pub fn get_dynamic_fee(&self, operation: FeeOperation) -> u128 {
+            let fee_manager_addr = match self.fee_manager {
+                Some(addr) => addr,
+                None => return 0,
+            };
+            use ink::env::call::FromAccountId;
+            let fee_manager: ink::contract_ref!(DynamicFeeProvider) =
+                FromAccountId::from_account_id(fee_manager_addr);
+            fee_manager.get_recommended_fee(operation)
+        }
+
+        /// Update property valuation using the oracle
+        #[ink(message)]
+        pub fn update_valuation_from_oracle(&mut self, property_id: u64) -> Result<(), Error> {
+            let oracle_addr = self.oracle.ok_or(Error::OracleError)?;
+
+            // Use the Oracle trait to perform the cross-contract call
+            use ink::env::call::FromAccountId;
+            let oracle: ink::contract_ref!(Oracle) = FromAccountId::from_account_id(oracle_addr);
+
+            // Fetch valuation from oracle
+            let valuation = oracle
+                .get_valuation(property_id)
+                .map_err(|_| Error::OracleError)?;
+
+            // Update the property's recorded valuation in its metadata
+            if let Some(mut property) = self.properties.get(&property_id) {
+                property.metadata.valuation = valuation.valuation;
+                self.properties.insert(&property_id, &property);
+            } else {
+                return Err(Error::PropertyNotFound);
+            }
+
+            Ok(())
+        }
+
+        /// Changes the admin account (only callable by current admin)
+        #[ink(message)]
+        pub fn change_admin(&mut self, new_admin: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let old_admin = self.admin;
+            self.admin = new_admin;
+
+            // Emit enhanced admin changed event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(AdminChanged {
+                old_admin,
+                new_admin,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                changed_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Sets the compliance registry contract address (admin only)
+        #[ink(message)]
+        pub fn set_compliance_registry(
+            &mut self,
+            registry: Option<AccountId>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_registry = registry;
+            // Results from the previous registry no longer apply
+            self.compliance_cache_epoch = self.compliance_cache_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// Gets the compliance registry address
+        #[ink(message)]
+        pub fn get_compliance_registry(&self) -> Option<AccountId> {
+            self.compliance_registry
+        }
+
+        /// Adds a registry consulted alongside the primary compliance registry (admin only)
+        #[ink(message)]
+        pub fn add_compliance_registry(&mut self, registry: AccountId) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if self.additional_compliance_registries.contains(&registry) {
+                return Ok(());
+            }
+            if self.additional_compliance_registries.len() as u32
+                >= MAX_ADDITIONAL_COMPLIANCE_REGISTRIES
+            {
+                return Err(Error::TooManyComplianceRegistries);
+            }
+            self.additional_compliance_registries.push(registry);
+            self.compliance_cache_epoch = self.compliance_cache_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// Removes an additional compliance registry (admin only)
+        #[ink(message)]
+        pub fn remove_compliance_registry(&mut self, registry: AccountId) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.additional_compliance_registries
+                .retain(|existing| *existing != registry);
+            self.compliance_cache_epoch = self.compliance_cache_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// Gets every registry consulted by compliance checks, primary first
+        #[ink(message)]
+        pub fn get_compliance_registries(&self) -> Vec<AccountId> {
+            self.compliance_registry
+                .into_iter()
+                .chain(self.additional_compliance_registries.iter().copied())
+                .collect()
+        }
+
+        /// Sets whether all registries or any one of them must pass an account (admin only)
+        #[ink(message)]
+        pub fn set_compliance_policy(&mut self, policy: CompliancePolicy) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_policy = policy;
+            self.compliance_cache_epoch = self.compliance_cache_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// Gets how the answers of several compliance registries combine
+        #[ink(message)]
+        pub fn get_compliance_policy(&self) -> CompliancePolicy {
+            self.compliance_policy
+        }
+
+        /// Sets whether checks pass or fail when the compliance registry is unreachable (admin only)
+        /// Fail-closed by default: a failed registry call blocks the operation.
+        #[ink(message)]
+        pub fn set_compliance_fail_open(&mut self, fail_open: bool) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_fail_open = fail_open;
+            Ok(())
+        }
+
+        /// Returns true if compliance checks pass when the registry call fails
+        #[ink(message)]
+        pub fn is_compliance_fail_open(&self) -> bool {
+            self.compliance_fail_open
+        }
+
+        /// Sets how long (ms) a positive compliance result is cached (admin only)
+        /// Zero disables caching, so every check calls the registry.
+        #[ink(message)]
+        pub fn set_compliance_cache_ttl(&mut self, ttl: u64) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_cache_ttl = ttl;
+            Ok(())
+        }
+
+        /// Gets the compliance cache time-to-live (ms)
+        #[ink(message)]
+        pub fn get_compliance_cache_ttl(&self) -> u64 {
+            self.compliance_cache_ttl
+        }
+
+        /// Drops every cached compliance result (admin only)
+        #[ink(message)]
+        pub fn flush_compliance_cache(&mut self) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_cache_epoch = self.compliance_cache_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// Drops an account's cached compliance result, e.g. after the registry revokes it
+        /// Callable by the admin or any configured compliance registry.
+        #[ink(message)]
+        pub fn invalidate_compliance_cache(&mut self, account: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.get_compliance_registries().contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_cache.remove(account);
+            Ok(())
+        }
+
+        /// Returns true if the account has an unexpired cached compliance pass
+        #[ink(message)]
+        pub fn is_compliance_cached(&self, account: AccountId) -> bool {
+            self.compliance_cached_in(account, None, 1)
+        }
+
+        /// Helper: true if a cached pass covers the jurisdiction and tier
+        /// A pass cached for a jurisdiction also covers checks without one.
+        fn compliance_cached_in(
+            &self,
+            account: AccountId,
+            jurisdiction: Option<Jurisdiction>,
+            min_tier: u8,
+        ) -> bool {
+            match self.compliance_cache.get(account) {
+                Some((epoch, expires_at, cached, tier)) => {
+                    epoch == self.compliance_cache_epoch
+                        && self.env().block_timestamp() < expires_at
+                        && (jurisdiction.is_none() || cached == jurisdiction)
+                        && tier >= min_tier
+                }
+                None => false,
+            }
+        }
+
+        /// Sets the KYC tier required for properties valued at or above each threshold (admin only)
+        /// Properties below every threshold need tier 1, i.e. a plain compliance pass.
+        #[ink(message)]
+        pub fn set_kyc_tier_thresholds(
+            &mut self,
+            thresholds: Vec<(u128, u8)>,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if thresholds.iter().any(|&(_, tier)| tier == 0) {
+                return Err(Error::InvalidKycTier);
+            }
+            self.kyc_tier_thresholds = thresholds;
+            Ok(())
+        }
+
+        /// Gets the KYC tier thresholds as (minimum valuation, tier)
+        #[ink(message)]
+        pub fn get_kyc_tier_thresholds(&self) -> Vec<(u128, u8)> {
+            self.kyc_tier_thresholds.clone()
+        }
+
+        /// Designates the compliance officer who may maintain the sanctions blocklist (admin only)
+        #[ink(message)]
+        pub fn set_compliance_officer(&mut self, officer: Option<AccountId>) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_officer = officer;
+            Ok(())
+        }
+
+        /// Gets the compliance officer
+        #[ink(message)]
+        pub fn get_compliance_officer(&self) -> Option<AccountId> {
+            self.compliance_officer
+        }
+
+        /// Adds an account to the sanctions blocklist (admin or compliance officer)
+        /// Sanctioned accounts can neither register, receive nor list properties.
+        #[ink(message)]
+        pub fn add_to_sanctions_list(&mut self, account: AccountId) -> Result<(), Error> {
+            self.update_sanctions_list(account, true)
+        }
+
+        /// Removes an account from the sanctions blocklist (admin or compliance officer)
+        #[ink(message)]
+        pub fn remove_from_sanctions_list(&mut self, account: AccountId) -> Result<(), Error> {
+            self.update_sanctions_list(account, false)
+        }
+
+        /// Returns true if the account is on the sanctions blocklist
+        #[ink(message)]
+        pub fn is_sanctioned(&self, account: AccountId) -> bool {
+            self.sanctioned_accounts.get(account).unwrap_or(false)
+        }
+
+        /// Helper: records a blocklist change made by the admin or compliance officer
+        fn update_sanctions_list(
+            &mut self,
+            account: AccountId,
+            sanctioned: bool,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin && Some(caller) != self.compliance_officer {
+                return Err(Error::Unauthorized);
+            }
+
+            if sanctioned {
+                self.sanctioned_accounts.insert(account, &true);
+            } else {
+                self.sanctioned_accounts.remove(account);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SanctionsListUpdated {
+                account,
+                event_version: 1,
+                sanctioned,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                updated_by: caller,
+            });
+            Ok(())
+        }
+
+        /// Exempts an account from compliance checks, or removes the exemption (admin only)
+        /// Meant for internal settlement flows and registrars that must not stall on a registry
+        /// outage; the sanctions blocklist still applies.
+        #[ink(message)]
+        pub fn set_compliance_exempt(
+            &mut self,
+            account: AccountId,
+            exempt: bool,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if exempt {
+                self.compliance_exempt_accounts.insert(account, &true);
+            } else {
+                self.compliance_exempt_accounts.remove(account);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ComplianceExemptionUpdated {
+                account,
+                event_version: 1,
+                exempt,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                updated_by: caller,
+            });
+            Ok(())
+        }
+
+        /// Sets the key whose signed attestations stand in for a registry answer (admin only)
+        #[ink(message)]
+        pub fn set_compliance_signer(
+            &mut self,
+            signer: Option<ComplianceSigner>,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_signer = signer;
+            // Attestations accepted under the previous key must not outlive it
+            self.compliance_signer_epoch = self.compliance_signer_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// Gets the compliance attestation signer
+        #[ink(message)]
+        pub fn get_compliance_signer(&self) -> Option<ComplianceSigner> {
+            self.compliance_signer
+        }
+
+        /// Gets an account's unexpired attestation as (expires at, jurisdiction, tier), if any
+        #[ink(message)]
+        pub fn get_compliance_attestation(
+            &self,
+            account: AccountId,
+        ) -> Option<(u64, Option<Jurisdiction>, u8)> {
+            let (epoch, expires_at, jurisdiction, tier) =
+                self.compliance_attestations.get(account)?;
+            (epoch == self.compliance_signer_epoch && self.env().block_timestamp() < expires_at)
+                .then_some((expires_at, jurisdiction, tier))
+        }
+
+        /// Helper: whether an unexpired attestation covers the jurisdiction and KYC tier
+        fn attested_in(
+            &self,
+            account: AccountId,
+            jurisdiction: Option<Jurisdiction>,
+            min_tier: u8,
+        ) -> bool {
+            match self.get_compliance_attestation(account) {
+                Some((_, attested, tier)) => {
+                    (jurisdiction.is_none() || attested == jurisdiction) && tier >= min_tier
+                }
+                None => false,
+            }
+        }
+
+        /// Gets the highest attestation nonce accepted for an account (0 if none)
+        #[ink(message)]
+        pub fn get_attestation_nonce(&self, account: AccountId) -> u64 {
+            self.attestation_nonces.get(account).unwrap_or(0)
+        }
+
+        /// Presents a signed compliance attestation for an account
+        /// Anyone may submit it; once verified the account is treated as confirmed compliant
+        /// at the attested tier and jurisdiction until the attestation expires, without a
+        /// registry call. Attestations are kept apart from the compliance cache, so they
+        /// survive cache flushes and the re-screening done at settlement.
+        #[ink(message)]
+        pub fn submit_compliance_attestation(
+            &mut self,
+            attestation: ComplianceAttestation,
+            signature: AttestationSignature,
+        ) -> Result<(), Error> {
+            self.apply_compliance_attestation(&attestation, &signature)
+        }
+
+        /// Transfers a property to a recipient vouched for by a signed compliance attestation
+        /// Useful when the compliance registry lives off-chain.
+        #[ink(message)]
+        pub fn transfer_property_with_attestation(
+            &mut self,
+            property_id: u64,
+            to: AccountId,
+            attestation: ComplianceAttestation,
+            signature: AttestationSignature,
+        ) -> Result<(), Error> {
+            if attestation.account != to {
+                return Err(Error::InvalidAttestation);
+            }
+            self.apply_compliance_attestation(&attestation, &signature)?;
+            self.transfer_property(property_id, to)
+        }
+
+        /// Helper: verifies an attestation, consumes its nonce and records the confirmation
+        fn apply_compliance_attestation(
+            &mut self,
+            attestation: &ComplianceAttestation,
+            signature: &AttestationSignature,
+        ) -> Result<(), Error> {
+            let signer = self
+                .compliance_signer
+                .ok_or(Error::ComplianceSignerNotSet)?;
+            if attestation.tier == 0 {
+                return Err(Error::InvalidAttestation);
+            }
+            let now = self.env().block_timestamp();
+            if attestation.expires_at <= now {
+                return Err(Error::AttestationExpired);
+            }
+            let account = attestation.account;
+            if attestation.nonce <= self.get_attestation_nonce(account) {
+                return Err(Error::AttestationNonceUsed);
+            }
+
+            use ink::env::hash::{Blake2x256, HashOutput};
+            let mut message = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(
+                &(self.env().account_id(), attestation),
+                &mut message,
+            );
+            let verified = match (signer, signature) {
+                (
+                    ComplianceSigner::Sr25519(public_key),
+                    AttestationSignature::Sr25519(signature),
+                ) => self
+                    .env()
+                    .sr25519_verify(signature, &message, &public_key)
+                    .is_ok(),
+                (ComplianceSigner::Ecdsa(public_key), AttestationSignature::Ecdsa(signature)) => {
+                    self.env()
+                        .ecdsa_recover(signature, &message)
+                        .is_ok_and(|recovered| recovered == public_key)
+                }
+                _ => false,
+            };
+            if !verified {
+                return Err(Error::InvalidAttestationSignature);
+            }
+
+            self.attestation_nonces.insert(account, &attestation.nonce);
+            self.compliance_confirmed_at.insert(account, &now);
+            self.compliance_attestations.insert(
+                account,
+                &(
+                    self.compliance_signer_epoch,
+                    attestation.expires_at,
+                    attestation.jurisdiction,
+                    attestation.tier,
+                ),
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ComplianceAttested {
+                account,
+                event_version: 1,
+                tier: attestation.tier,
+                nonce: attestation.nonce,
+                expires_at: attestation.expires_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Returns true if the account is exempt from compliance checks
+        #[ink(message)]
+        pub fn is_compliance_exempt(&self, account: AccountId) -> bool {
+            self.compliance_exempt_accounts
+                .get(account)
+                .unwrap_or(false)
+        }
+
+        /// Sets the age (ms) after which an account's last compliance confirmation is stale
+        /// (admin only). Stale accounts bypass the cache and cannot pass under fail-open, so
+        /// they must be re-confirmed by the registry. Zero disables the policy.
+        #[ink(message)]
+        pub fn set_compliance_max_age(&mut self, max_age: u64) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.compliance_max_age = max_age;
+            Ok(())
+        }
+
+        /// Gets the compliance confirmation max age (ms)
+        #[ink(message)]
+        pub fn get_compliance_max_age(&self) -> u64 {
+            self.compliance_max_age
+        }
+
+        /// Gets when the registry last confirmed the account as compliant
+        #[ink(message)]
+        pub fn get_compliance_confirmed_at(&self, account: AccountId) -> Option<u64> {
+            self.compliance_confirmed_at.get(account)
+        }
+
+        /// Returns true if the account's last confirmation is older than the max age
+        /// Accounts never confirmed are stale once the policy is enabled.
+        #[ink(message)]
+        pub fn compliance_stale(&self, account: AccountId) -> bool {
+            if self.compliance_max_age == 0 {
+                return false;
+            }
+            match self.compliance_confirmed_at.get(account) {
+                Some(confirmed_at) => {
+                    self.env().block_timestamp()
+                        >= confirmed_at.saturating_add(self.compliance_max_age)
+                }
+                None => true,
+            }
+        }
+
+        /// Helper: rejects accounts on the sanctions blocklist
+        fn ensure_not_sanctioned(&self, account: AccountId) -> Result<(), Error> {
+            if self.is_sanctioned(account) {
+                return Err(Error::AccountSanctioned);
+            }
+            Ok(())
+        }
+
+        /// Gets the KYC tier required to receive a property of the given valuation
+        #[ink(message)]
+        pub fn get_required_kyc_tier(&self, valuation: u128) -> u8 {
+            self.kyc_tier_thresholds
+                .iter()
+                .filter(|&&(min_valuation, _)| valuation >= min_valuation)
+                .map(|&(_, tier)| tier)
+                .max()
+                .unwrap_or(1)
+        }
+
+        /// Sets whether an operation requires the acting or receiving account to be compliant
+        /// (admin only). Register, transfer, list, purchase, escrow and lease operations are all
+        /// required by default; the sanctions blocklist applies either way.
+        #[ink(message)]
+        pub fn set_compliance_required(
+            &mut self,
+            operation: ComplianceOperation,
+            required: bool,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if matches!(
+                operation,
+                ComplianceOperation::UpdateMetadata | ComplianceOperation::BridgeTransfer
+            ) {
+                return Err(Error::InvalidComplianceOperation);
+            }
+            self.compliance_operations.retain(|op| *op != operation);
+            if required {
+                self.compliance_operations.push(operation);
+            }
+            Ok(())
+        }
+
+        /// Returns true if the operation requires accounts to pass the compliance check
+        #[ink(message)]
+        pub fn is_compliance_required(&self, operation: ComplianceOperation) -> bool {
+            self.compliance_operations.contains(&operation)
+        }
+
+        /// Sets whether an escrow step requires the buyer to be compliant (admin only)
+        /// Only `CreateEscrow` and `ReleaseEscrow` are escrow steps; both are required by default.
+        #[ink(message)]
+        pub fn set_escrow_compliance_required(
+            &mut self,
+            operation: ComplianceOperation,
+            required: bool,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if !matches!(
+                operation,
+                ComplianceOperation::CreateEscrow | ComplianceOperation::ReleaseEscrow
+            ) {
+                return Err(Error::InvalidComplianceOperation);
+            }
+            self.set_compliance_required(operation, required)
+        }
+
+        /// Returns true if the escrow step requires the buyer to be compliant
+        #[ink(message)]
+        pub fn is_escrow_compliance_required(&self, operation: ComplianceOperation) -> bool {
+            matches!(
+                operation,
+                ComplianceOperation::CreateEscrow | ComplianceOperation::ReleaseEscrow
+            ) && self.is_compliance_required(operation)
+        }
+
+        /// Sets whether tenants must pass the compliance check to accept a lease (admin only)
+        /// Required by default; rental KYC rules vary by jurisdiction.
+        #[ink(message)]
+        pub fn set_tenant_compliance_required(&mut self, required: bool) -> Result<(), Error> {
+            self.set_compliance_required(ComplianceOperation::Lease, required)
+        }
+
+        /// Returns true if tenants must pass the compliance check to accept a lease
+        #[ink(message)]
+        pub fn is_tenant_compliance_required(&self) -> bool {
+            self.is_compliance_required(ComplianceOperation::Lease)
+        }
+
+        /// Helper: runs the compliance check on an escrow participant, re-screening at release
+        fn check_escrow_compliance(
+            &mut self,
+            operation: ComplianceOperation,
+            property_id: u64,
+            account: AccountId,
+        ) -> Result<(), Error> {
+            if operation == ComplianceOperation::ReleaseEscrow {
+                self.check_settlement_compliance(account, operation, property_id)
+            } else {
+                self.check_property_compliance(account, operation, property_id)
+            }
+        }
+
+        /// Helper: Check compliance for an account via the compliance registry (Issue #45).
+        /// Returns Ok if compliant or no registry set, Err(NotCompliant) or Err(ComplianceCheckFailed) otherwise.
+        fn check_compliance(
+            &mut self,
+            account: AccountId,
+            operation: ComplianceOperation,
+        ) -> Result<(), Error> {
+            self.check_compliance_in(account, operation, None, 0)
+        }
+
+        /// Helper: checks an account against the jurisdiction and valuation tier of the property
+        /// it would receive
+        fn check_property_compliance(
+            &mut self,
+            account: AccountId,
+            operation: ComplianceOperation,
+            property_id: u64,
+        ) -> Result<(), Error> {
+            let (jurisdiction, valuation) = self
+                .properties
+                .get(property_id)
+                .map(|property| (property.metadata.jurisdiction, property.metadata.valuation))
+                .unwrap_or((None, 0));
+            self.check_compliance_in(account, operation, jurisdiction, valuation)
+        }
+
+        /// Helper: re-screens the recipient when a deferred sale settles
+        /// Any cached pass is dropped first, so a lapse or revocation since the offer, bid or
+        /// escrow was opened is caught at closing.
+        fn check_settlement_compliance(
+            &mut self,
+            account: AccountId,
+            operation: ComplianceOperation,
+            property_id: u64,
+        ) -> Result<(), Error> {
+            self.compliance_cache.remove(account);
+            self.check_property_compliance(account, operation, property_id)
+        }
+
+        /// Helper: Check compliance, requiring verification in the jurisdiction when one is given
+        /// and the KYC tier the valuation calls for.
+        fn check_compliance_in(
+            &mut self,
+            account: AccountId,
+            operation: ComplianceOperation,
+            jurisdiction: Option<Jurisdiction>,
+            valuation: u128,
+        ) -> Result<(), Error> {
+            if !self.is_compliance_required(operation) {
+                return self.ensure_not_sanctioned(account);
+            }
+            self.screen_account(account, operation, jurisdiction, valuation)
+        }
+
+        /// Helper: screens an account against sanctions, exemptions, attestations, the cache and
+        /// the registry
+        /// Positive registry answers are cached for the configured TTL. Each registry
+        /// consultation is recorded in a `ComplianceChecked` event for the audit trail.
+        fn screen_account(
+            &mut self,
+            account: AccountId,
+            operation: ComplianceOperation,
+            jurisdiction: Option<Jurisdiction>,
+            valuation: u128,
+        ) -> Result<(), Error> {
+            self.ensure_not_sanctioned(account)?;
+            if self.is_compliance_exempt(account) {
+                return Ok(());
+            }
+            let min_tier = self.get_required_kyc_tier(valuation);
+            // A signed attestation stands in for the registry's answer
+            if self.attested_in(account, jurisdiction, min_tier) {
+                return Ok(());
+            }
+            let stale = self.compliance_stale(account);
+            if !stale && self.compliance_cached_in(account, jurisdiction, min_tier) {
+                return Ok(());
+            }
+            let Some(answer) = self.call_compliance_registry(account, jurisdiction, min_tier)
+            else {
+                return Ok(());
+            };
+            let result = match answer {
+                Ok(0) => Err(Error::NotCompliant),
+                Ok(tier) if tier < min_tier => Err(Error::InsufficientKycTier),
+                Ok(tier) => {
+                    self.compliance_confirmed_at
+                        .insert(account, &self.env().block_timestamp());
+                    if self.compliance_cache_ttl > 0 {
+                        let expires_at = self
+                            .env()
+                            .block_timestamp()
+                            .saturating_add(self.compliance_cache_ttl);
+                        self.compliance_cache.insert(
+                            account,
+                            &(self.compliance_cache_epoch, expires_at, jurisdiction, tier),
+                        );
+                    }
+                    Ok(())
+                }
+                Err(_) if self.compliance_fail_open && stale => Err(Error::ComplianceStale),
+                Err(_) if self.fails_open_for(account) => Ok(()),
+                Err(error) => Err(error),
+            };
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ComplianceChecked {
+                account,
+                operation,
+                event_version: 1,
+                result: result.is_ok(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            result
+        }
+
+        /// Helper: whether an unreachable registry lets the account through
+        /// Fail-open only covers accounts whose last confirmation is still fresh, so the
+        /// views and the enforcing checks agree on stale accounts.
+        fn fails_open_for(&self, account: AccountId) -> bool {
+            self.compliance_fail_open && !self.compliance_stale(account)
+        }
+
+        /// Check if an account is compliant (delegates to registry when set). For use by frontends.
+        #[ink(message)]
+        pub fn check_account_compliance(&self, account: AccountId) -> Result<bool, Error> {
+            if self.is_sanctioned(account) {
+                return Ok(false);
+            }
+            if self.is_compliance_exempt(account) || self.attested_in(account, None, 1) {
+                return Ok(true);
+            }
+            if !self.compliance_stale(account) && self.is_compliance_cached(account) {
+                return Ok(true);
+            }
+            match self.call_compliance_registry(account, None, 1) {
+                None => Ok(true),
+                Some(Err(_)) if self.compliance_fail_open => Ok(self.fails_open_for(account)),
+                Some(result) => result.map(|tier| tier > 0),
+            }
+        }
+
+        /// Checks many accounts at once so marketplaces can pre-validate counterparties
+        /// Accounts not settled locally (sanctions, exemptions, attestations, cache) are sent to
+        /// the registry in a single batch call. Results follow the input order.
+        #[ink(message)]
+        pub fn check_compliance_batch(&self, accounts: Vec<AccountId>) -> Result<Vec<bool>, Error> {
+            if accounts.len() as u32 > MAX_COMPLIANCE_BATCH_SIZE {
+                return Err(Error::ComplianceBatchTooLarge);
+            }
+
+            let mut results = Vec::with_capacity(accounts.len());
+            let mut pending = Vec::new();
+            for &account in accounts.iter() {
+                if self.is_sanctioned(account) {
+                    results.push(Some(false));
+                } else if self.is_compliance_exempt(account)
+                    || self.attested_in(account, None, 1)
+                    || (!self.compliance_stale(account) && self.is_compliance_cached(account))
+                {
+                    results.push(Some(true));
+                } else {
+                    results.push(None);
+                    pending.push(account);
+                }
+            }
+
+            // Without a registry every pending account passes, whatever the policy
+            let registries = self.compliance_checkers();
+            if pending.is_empty() || registries.is_empty() {
+                return Ok(results
+                    .into_iter()
+                    .map(|result| result.unwrap_or(true))
+                    .collect());
+            }
+
+            // Registries that cannot be reached fall back to the fail-open rules
+            let pending_count = pending.len();
+            let mut replies = Vec::new();
+            use ink::codegen::TraitCallBuilder;
+            for registry in registries {
+                replies.push(
+                    match registry.call().are_compliant(pending.clone()).try_invoke() {
+                        Ok(Ok(answers)) if answers.len() == pending_count => Some(answers),
+                        _ => None,
+                    },
+                );
+            }
+            let answered: Vec<Vec<bool>> = replies.iter().flatten().cloned().collect();
+            let failed = match self.compliance_policy {
+                CompliancePolicy::All => answered.len() < replies.len(),
+                CompliancePolicy::Any => answered.is_empty(),
+            };
+            let answers: Vec<bool> = if failed {
+                if !self.compliance_fail_open {
+                    return Err(Error::ComplianceCheckFailed);
+                }
+                pending
+                    .iter()
+                    .map(|&account| self.fails_open_for(account))
+                    .collect()
+            } else {
+                (0..pending_count)
+                    .map(|index| match self.compliance_policy {
+                        CompliancePolicy::All => answered.iter().all(|answers| answers[index]),
+                        CompliancePolicy::Any => answered.iter().any(|answers| answers[index]),
+                    })
+                    .collect()
+            };
+
+            let mut answers = answers.into_iter();
+            Ok(results
+                .into_iter()
+                .map(|result| result.unwrap_or_else(|| answers.next().unwrap_or(true)))
+                .collect())
+        }
+
+        /// Helper: asks the registries for an account's KYC tier without trapping on failure
+        /// Tier 0 means not compliant. The tier is only fetched when more than tier 1 is needed;
+        /// otherwise a compliant account reports tier 1. Under the `All` policy the lowest
+        /// tier counts, under `Any` the highest. None when no registry is set; failed calls
+        /// map to ComplianceCheckFailed unless the policy can be decided without them.
+        fn call_compliance_registry(
+            &self,
+            account: AccountId,
+            jurisdiction: Option<Jurisdiction>,
+            min_tier: u8,
+        ) -> Option<Result<u8, Error>> {
+            let registries = self.compliance_checkers();
+            if registries.is_empty() {
+                return None;
+            }
+
+            use ink::codegen::TraitCallBuilder;
+            let mut tiers = Vec::new();
+            let mut failed = false;
+            for registry in registries.iter() {
+                let result = if min_tier > 1 {
+                    registry
+                        .call()
+                        .compliance_tier(account, jurisdiction)
+                        .try_invoke()
+                } else {
+                    let is_compliant = match jurisdiction {
+                        Some(jurisdiction) => registry
+                            .call()
+                            .is_compliant_in(account, jurisdiction)
+                            .try_invoke(),
+                        None => registry.call().is_compliant(account).try_invoke(),
+                    };
+                    is_compliant.map(|result| result.map(u8::from))
+                };
+                match result {
+                    Ok(Ok(tier)) => tiers.push(tier),
+                    _ => failed = true,
+                }
+            }
+
+            Some(match self.compliance_policy {
+                // A definite rejection decides an All check even if another registry failed
+                CompliancePolicy::All if tiers.contains(&0) => Ok(0),
+                CompliancePolicy::All if failed => Err(Error::ComplianceCheckFailed),
+                CompliancePolicy::All => Ok(tiers.into_iter().min().unwrap_or(0)),
+                CompliancePolicy::Any => match tiers.into_iter().max() {
+                    Some(tier) if tier > 0 || !failed => Ok(tier),
+                    _ => Err(Error::ComplianceCheckFailed),
+                },
+            })
+        }
+
+        /// Helper: typed references to the configured compliance registries, called through the
+        /// `ComplianceChecker` trait so selectors always match the registry's implementation
+        fn compliance_checkers(&self) -> Vec<ink::contract_ref!(ComplianceChecker)> {
+            use ink::env::call::FromAccountId;
+            self.get_compliance_registries()
+                .into_iter()
+                .map(FromAccountId::from_account_id)
+                .collect()
+        }
+
+        /// Helper to check if contract is paused
+        pub fn ensure_not_paused(&self) -> Result<(), Error> {
+            if self.pause_info.paused {
+                // Check for auto-resume
+                if let Some(resume_time) = self.pause_info.auto_resume_at {
+                    if self.env().block_timestamp() >= resume_time {
+                        // In a real scenario we might want to auto-resume here or require a trigger.
+                        // For safety, we usually require explicit resume even if time passed,
+                        // purely to update the state, OR we treat it as not paused.
+                        // However, since state mutability is needed to update 'paused' flag,
+                        // and this is a read-only check often, we'll return Error::ContractPaused
+                        // unless someone triggers the resume.
+                        // But requirements say "Time-based automatic resume".
+                        // Use a separate method or assume logic handles it.
+                        // For strict safety:
+                        return Err(Error::ContractPaused);
+                    }
+                }
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
+
+        // --- Pause/Resume Functionality ---
+
+        /// Pauses the contract. Can be called by admin or pause guardians.
+        #[ink(message)]
+        pub fn pause_contract(
+            &mut self,
+            reason: String,
+            duration_seconds: Option<u64>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let is_admin = caller == self.admin;
+            let is_guardian = self.pause_guardians.get(caller).unwrap_or(false);
+
+            if !is_admin && !is_guardian {
+                return Err(Error::NotAuthorizedToPause);
+            }
+
+            if self.pause_info.paused {
+                return Err(Error::AlreadyPaused);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let auto_resume_at = duration_seconds.map(|d| timestamp + d);
+
+            self.pause_info.paused = true;
+            self.pause_info.paused_at = Some(timestamp);
+            self.pause_info.paused_by = Some(caller);
+            self.pause_info.reason = Some(reason.clone());
+            self.pause_info.auto_resume_at = auto_resume_at;
+
+            // Clear any previous resume requests
+            self.pause_info.resume_request_active = false;
+            self.pause_info.resume_approvals.clear();
+
+            self.env().emit_event(ContractPaused {
+                by: caller,
+                reason,
+                timestamp,
+                auto_resume_at,
+            });
+
+            Ok(())
+        }
+
+        /// Emergency pause - same as pause but implies critical severity
+        #[ink(message)]
+        pub fn emergency_pause(&mut self, reason: String) -> Result<(), Error> {
+            self.pause_contract(reason, None)
+        }
+
+        /// Provide a mechanism to try auto-resume if time passed
+        #[ink(message)]
+        pub fn try_auto_resume(&mut self) -> Result<(), Error> {
+            if !self.pause_info.paused {
+                return Err(Error::NotPaused);
+            }
+
+            if let Some(resume_time) = self.pause_info.auto_resume_at {
+                if self.env().block_timestamp() >= resume_time {
+                    self.pause_info.paused = false;
+                    self.pause_info.reason = None;
+
+                    self.env().emit_event(ContractResumed {
+                        by: self.env().caller(), // triggered by
+                        timestamp: self.env().block_timestamp(),
+                    });
+                    return Ok(());
+                }
+            }
+            Err(Error::ContractPaused)
+        }
+
+        /// Request to resume the contract. Requires multi-sig approval.
+        #[ink(message)]
+        pub fn request_resume(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            // Only admin or guardians can request resume
+            let is_admin = caller == self.admin;
+            let is_guardian = self.pause_guardians.get(caller).unwrap_or(false);
+
+            if !is_admin && !is_guardian {
+                return Err(Error::Unauthorized);
+            }
+
+            if !self.pause_info.paused {
+                return Err(Error::NotPaused);
+            }
+
+            if self.pause_info.resume_request_active {
+                return Err(Error::ResumeRequestAlreadyActive);
+            }
+
+            self.pause_info.resume_request_active = true;
+            self.pause_info.resume_requester = Some(caller);
+            self.pause_info.resume_approvals.clear();
+            // Auto-approve by requester? Usually yes, let's say yes.
+            self.pause_info.resume_approvals.push(caller);
+
+            self.env().emit_event(ResumeRequested {
+                requester: caller,
+                timestamp: self.env().block_timestamp(),
+            });
+
+            // If only 1 approval required (e.g. dev mode), check immediately
+            if self.pause_info.required_approvals <= 1 {
+                self._execute_resume()?;
+            }
+
+            Ok(())
+        }
+
+        /// Approve the pending resume request
+        #[ink(message)]
+        pub fn approve_resume(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let is_admin = caller == self.admin;
+            let is_guardian = self.pause_guardians.get(caller).unwrap_or(false);
+
+            if !is_admin && !is_guardian {
+                return Err(Error::Unauthorized);
+            }
+
+            if !self.pause_info.resume_request_active {
+                return Err(Error::ResumeRequestNotFound);
+            }
+
+            if self.pause_info.resume_approvals.contains(&caller) {
+                return Err(Error::AlreadyApproved);
+            }
+
+            self.pause_info.resume_approvals.push(caller);
+
+            let approvals_count = self.pause_info.resume_approvals.len() as u32;
+
+            self.env().emit_event(ResumeApproved {
+                approver: caller,
+                current_approvals: approvals_count,
+                required_approvals: self.pause_info.required_approvals,
+                timestamp: self.env().block_timestamp(),
+            });
+
+            if approvals_count >= self.pause_info.required_approvals {
+                self._execute_resume()?;
+            }
+
+            Ok(())
+        }
+
+        fn _execute_resume(&mut self) -> Result<(), Error> {
+            self.pause_info.paused = false;
+            self.pause_info.resume_request_active = false;
+            self.pause_info.reason = None;
+
+            self.env().emit_event(ContractResumed {
+                by: self.env().caller(),
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Manage pause guardians
+        #[ink(message)]
+        pub fn set_pause_guardian(
+            &mut self,
+            guardian: AccountId,
+            is_enabled: bool,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.pause_guardians.insert(guardian, &is_enabled);
+
+            self.env().emit_event(PauseGuardianUpdated {
+                guardian,
+                is_guardian: is_enabled,
+                updated_by: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Get pause state
+        #[ink(message)]
+        pub fn get_pause_state(&self) -> PauseInfo {
+            self.pause_info.clone()
+        }
+
+        /// Registers a new property
+        /// Optionally checks compliance if compliance registry is set
+        #[ink(message)]
+        pub fn register_property(&mut self, metadata: PropertyMetadata) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Check compliance for property registration (optional but recommended)
+            self.check_compliance_in(
+                caller,
+                ComplianceOperation::RegisterProperty,
+                metadata.jurisdiction,
+                metadata.valuation,
+            )?;
+
+            self.property_count += 1;
+            let property_id = self.property_count;
+
+            let property_info = PropertyInfo {
+                id: property_id,
+                owner: caller,
+                metadata,
+                registered_at: self.env().block_timestamp(),
+            };
+
+            self.properties.insert(property_id, &property_info);
+            // Optimized: Also store reverse mapping for faster owner lookups
+            self.property_owners.insert(property_id, &caller);
+
+            let mut owner_props = self.owner_properties.get(caller).unwrap_or_default();
+            owner_props.push(property_id);
+            self.owner_properties.insert(caller, &owner_props);
+
+            // Track gas usage
+            self.track_gas_usage("register_property".as_bytes());
+
+            // Emit enhanced property registration event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyRegistered {
+                property_id,
+                owner: caller,
+                event_version: 1,
+                location: property_info.metadata.location.clone(),
+                size: property_info.metadata.size,
+                valuation: property_info.metadata.valuation,
+                timestamp: property_info.registered_at,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(property_id)
+        }
+
+        /// Registers a new property with a royalty on every later sale
+        /// The royalty goes to `receiver`, or to the registrant if none is given; it is fixed
+        /// at registration and capped at `MAX_ROYALTY_BPS`.
+        #[ink(message)]
+        pub fn register_property_with_royalty(
+            &mut self,
+            metadata: PropertyMetadata,
+            receiver: Option<AccountId>,
+            royalty_bps: u32,
+        ) -> Result<u64, Error> {
+            if royalty_bps > MAX_ROYALTY_BPS {
+                return Err(Error::InvalidBasisPoints);
+            }
+
+            let property_id = self.register_property(metadata)?;
+            if royalty_bps > 0 {
+                self.property_royalties.insert(
+                    property_id,
+                    &RoyaltyInfo {
+                        receiver: receiver.unwrap_or(self.env().caller()),
+                        bps: royalty_bps,
+                    },
+                );
+            }
+
+            Ok(property_id)
+        }
+
+        /// Gets the resale royalty configured for a property, if any
+        #[ink(message)]
+        pub fn get_property_royalty(&self, property_id: u64) -> Option<RoyaltyInfo> {
+            self.property_royalties.get(property_id)
+        }
+
+        /// Transfers property ownership
+        /// Requires recipient to be compliant if compliance registry is set
+        #[ink(message)]
+        pub fn transfer_property(&mut self, property_id: u64, to: AccountId) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            let approved = self.approvals.get(property_id);
+            if property.owner != caller && Some(caller) != approved {
+                return Err(Error::Unauthorized);
+            }
+            if property.owner != caller {
+                self.ensure_operator_allowed(caller)?;
+            }
+
+            self.ensure_whole_title_transferable(property_id)?;
+
+            // Check compliance for recipient
+            self.check_property_compliance(to, ComplianceOperation::TransferProperty, property_id)?;
+
+            self.move_property(&mut property, to, caller);
+
+            // Track gas usage
+            self.track_gas_usage("transfer_property".as_bytes());
+
+            Ok(())
+        }
+
+        /// Helper: moves a property to a new owner without any authorization checks.
+        /// Callers are responsible for verifying the transfer is allowed.
+        fn move_property(&mut self, property: &mut PropertyInfo, to: AccountId, by: AccountId) {
+            let property_id = property.id;
+            let from = property.owner;
+
+            // Remove from current owner's properties
+            let mut current_owner_props = self.owner_properties.get(from).unwrap_or_default();
+            current_owner_props.retain(|&id| id != property_id);
+            self.owner_properties.insert(from, &current_owner_props);
+
+            // Add to new owner's properties
+            let mut new_owner_props = self.owner_properties.get(to).unwrap_or_default();
+            new_owner_props.push(property_id);
+            self.owner_properties.insert(to, &new_owner_props);
+
+            // Update property owner
+            property.owner = to;
+            self.properties.insert(property_id, &*property);
+            // Optimized: Update reverse mapping
+            self.property_owners.insert(property_id, &to);
+
+            // Clear approval
+            self.approvals.remove(property_id);
+            // Listings and auctions by the previous owner can no longer be bought
+            self.cancel_property_listing(property_id);
+            self.cancel_property_auctions(property_id, by);
+            self.property_dutch_auctions.remove(property_id);
+            self.property_bundles.remove(property_id);
+
+            // Emit enhanced property transfer event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyTransferred {
+                property_id,
+                from,
+                to,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                transferred_by: by,
+            });
+        }
+
+        /// Gets property information
+        #[ink(message)]
+        pub fn get_property(&self, property_id: u64) -> Option<PropertyInfo> {
+            self.properties.get(property_id)
+        }
+
+        /// Gets properties owned by an account
+        #[ink(message)]
+        pub fn get_owner_properties(&self, owner: AccountId) -> Vec<u64> {
+            self.owner_properties.get(owner).unwrap_or_default()
+        }
+
+        /// Gets total property count
+        #[ink(message)]
+        pub fn property_count(&self) -> u64 {
+            self.property_count
+        }
+
+        /// Updates property metadata
+        #[ink(message)]
+        pub fn update_metadata(
+            &mut self,
+            property_id: u64,
+            metadata: PropertyMetadata,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            // check if metadata is valid (basic check)
+            if metadata.location.is_empty() {
+                return Err(Error::InvalidMetadata);
+            }
+
+            // Shareholders must approve a renovation before a fractionalized property changes
+            if self.is_fractional(property_id) {
+                if !self.approved_renovations.contains(property_id) {
+                    return Err(Error::RenovationNotApproved);
+                }
+                self.approved_renovations.remove(property_id);
+            }
+
+            // Store old metadata for event
+            let old_location = property.metadata.location.clone();
+            let old_valuation = property.metadata.valuation;
+
+            property.metadata = metadata.clone();
+            self.properties.insert(property_id, &property);
+
+            // Emit enhanced metadata update event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyMetadataUpdated {
+                property_id,
+                owner: caller,
+                event_version: 1,
+                old_location,
+                new_location: metadata.location,
+                old_valuation,
+                new_valuation: metadata.valuation,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Batch registers multiple properties in a single transaction
+        #[ink(message)]
+        pub fn batch_register_properties(
+            &mut self,
+            properties: Vec<PropertyMetadata>,
+        ) -> Result<Vec<u64>, Error> {
+            self.ensure_not_paused()?;
+            let mut results = Vec::new();
+            let caller = self.env().caller();
+
+            // Pre-calculate all property IDs to avoid repeated storage reads
+            let start_id = self.property_count + 1;
+            let end_id = start_id + properties.len() as u64 - 1;
+            self.property_count = end_id;
+
+            // Get existing owner properties to avoid repeated storage reads
+            let mut owner_props = self.owner_properties.get(caller).unwrap_or_default();
+
+            for (i, metadata) in properties.into_iter().enumerate() {
+                let property_id = start_id + i as u64;
+
+                let property_info = PropertyInfo {
+                    id: property_id,
+                    owner: caller,
+                    metadata,
+                    registered_at: self.env().block_timestamp(),
+                };
+
+                self.properties.insert(property_id, &property_info);
+                owner_props.push(property_id);
+
+                results.push(property_id);
+            }
+
+            // Update owner properties once at the end
+            self.owner_properties.insert(caller, &owner_props);
+
+            // Emit enhanced batch registration event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BatchPropertyRegistered {
+                owner: caller,
+                event_version: 1,
+                property_ids: results.clone(),
+                count: results.len() as u64,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            // Track gas usage
+            self.track_gas_usage("batch_register_properties".as_bytes());
+
+            Ok(results)
+        }
+
+        /// Batch transfers multiple properties to the same recipient
+        #[ink(message)]
+        pub fn batch_transfer_properties(
+            &mut self,
+            property_ids: Vec<u64>,
+            to: AccountId,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Validate all properties first to avoid partial transfers
+            for &property_id in &property_ids {
+                let property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+
+                let approved = self.approvals.get(property_id);
+                if property.owner != caller && Some(caller) != approved {
+                    return Err(Error::Unauthorized);
+                }
+                if property.owner != caller {
+                    self.ensure_operator_allowed(caller)?;
+                }
+                self.ensure_whole_title_transferable(property_id)?;
+                self.check_property_compliance(
+                    to,
+                    ComplianceOperation::TransferProperty,
+                    property_id,
+                )?;
+            }
+
+            // Capture the original owner before transfers (fix for bug)
+            let from = if !property_ids.is_empty() {
+                let first_property = self
+                    .properties
+                    .get(property_ids[0])
+                    .ok_or(Error::PropertyNotFound)?;
+                first_property.owner
+            } else {
+                return Ok(()); // No properties to transfer
+            };
+
+            // Perform all transfers
+            for property_id in &property_ids {
+                let mut property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+                self.move_property(&mut property, to, caller);
+            }
+
+            // Emit enhanced batch transfer event
+            if !property_ids.is_empty() {
+                let transaction_hash: Hash = [0u8; 32].into();
+                self.env().emit_event(BatchPropertyTransferred {
+                    from,
+                    to,
+                    event_version: 1,
+                    property_ids: property_ids.clone(),
+                    count: property_ids.len() as u64,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                    transferred_by: caller,
+                });
+            }
+
+            // Track gas usage
+            self.track_gas_usage("batch_transfer_properties".as_bytes());
+
+            Ok(())
+        }
+
+        /// Batch updates metadata for multiple properties
+        #[ink(message)]
+        pub fn batch_update_metadata(
+            &mut self,
+            updates: Vec<(u64, PropertyMetadata)>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Validate all properties first to avoid partial updates
+            for (property_id, ref metadata) in &updates {
+                let property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+
+                if property.owner != caller {
+                    return Err(Error::Unauthorized);
+                }
+                if self.is_fractional(*property_id) {
+                    return Err(Error::PropertyFractionalized);
+                }
+
+                // Check if metadata is valid (basic check)
+                if metadata.location.is_empty() {
+                    return Err(Error::InvalidMetadata);
+                }
+            }
+
+            // Perform all updates
+            let mut updated_property_ids = Vec::new();
+            for (property_id, metadata) in updates {
+                let mut property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+
+                property.metadata = metadata.clone();
+                self.properties.insert(property_id, &property);
+                updated_property_ids.push(property_id);
+            }
+
+            // Emit enhanced batch metadata update event
+            if !updated_property_ids.is_empty() {
+                let count = updated_property_ids.len() as u64;
+
+                let transaction_hash: Hash = [0u8; 32].into();
+                self.env().emit_event(BatchMetadataUpdated {
+                    owner: caller,
+                    event_version: 1,
+                    property_ids: updated_property_ids,
+                    count,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+
+            // Track gas usage
+            self.track_gas_usage("batch_update_metadata".as_bytes());
+
+            Ok(())
+        }
+
+        /// Transfers multiple properties to different recipients
+        #[ink(message)]
+        pub fn batch_transfer_properties_to_multiple(
+            &mut self,
+            transfers: Vec<(u64, AccountId)>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Validate all properties first to avoid partial transfers
+            for &(property_id, to) in &transfers {
+                let property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+
+                let approved = self.approvals.get(property_id);
+                if property.owner != caller && Some(caller) != approved {
+                    return Err(Error::Unauthorized);
+                }
+                if property.owner != caller {
+                    self.ensure_operator_allowed(caller)?;
+                }
+                self.ensure_whole_title_transferable(property_id)?;
+                self.check_property_compliance(
+                    to,
+                    ComplianceOperation::TransferProperty,
+                    property_id,
+                )?;
+            }
+
+            // Perform all transfers
+            let mut transferred_property_ids = Vec::new();
+            for &(property_id, to) in &transfers {
+                let mut property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+                self.move_property(&mut property, to, caller);
+                transferred_property_ids.push(property_id);
+            }
+
+            // Emit enhanced batch transfer to multiple recipients event
+            if !transferred_property_ids.is_empty() {
+                let first_property = self
+                    .properties
+                    .get(transferred_property_ids[0])
+                    .ok_or(Error::PropertyNotFound)?;
+                let from = first_property.owner;
+
+                let transaction_hash: Hash = [0u8; 32].into();
+                self.env().emit_event(BatchPropertyTransferredToMultiple {
+                    from,
+                    event_version: 1,
+                    transfers: transfers.clone(),
+                    count: transfers.len() as u64,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                    transferred_by: caller,
+                });
+            }
+
+            // Track gas usage
+            self.track_gas_usage("batch_transfer_properties_to_multiple".as_bytes());
+
+            Ok(())
+        }
+
+        /// Approves an account to transfer a specific property
+        #[ink(message)]
+        pub fn approve(&mut self, property_id: u64, to: Option<AccountId>) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+
+            if let Some(account) = to {
+                self.ensure_operator_allowed(account)?;
+                self.approvals.insert(property_id, &account);
+                // Emit enhanced approval granted event
+                self.env().emit_event(ApprovalGranted {
+                    property_id,
+                    owner: caller,
+                    approved: account,
+                    event_version: 1,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            } else {
+                self.approvals.remove(property_id);
+                // Emit enhanced approval cleared event
+                self.env().emit_event(ApprovalCleared {
+                    property_id,
+                    owner: caller,
+                    event_version: 1,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Gets the approved account for a property
+        #[ink(message)]
+        pub fn get_approved(&self, property_id: u64) -> Option<AccountId> {
+            self.approvals.get(property_id)
+        }
+
+        /// Adds or removes a marketplace contract from the operator whitelist (admin only)
+        /// Only whitelisted contracts can be approved for, or carry out, transfers on an
+        /// owner's behalf; approvals of ordinary accounts are unaffected.
+        #[ink(message)]
+        pub fn set_marketplace_operator(
+            &mut self,
+            operator: AccountId,
+            approved: bool,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if approved {
+                self.marketplace_operators.insert(operator, &true);
+            } else {
+                self.marketplace_operators.remove(operator);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(MarketplaceOperatorUpdated {
+                operator,
+                event_version: 1,
+                approved,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                updated_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Returns true if a marketplace contract is on the operator whitelist
+        #[ink(message)]
+        pub fn is_marketplace_operator(&self, operator: AccountId) -> bool {
+            self.marketplace_operators.get(operator).unwrap_or(false)
+        }
+
+        /// Helper: rejects contract operators that are not on the marketplace whitelist
+        fn ensure_operator_allowed(&self, operator: AccountId) -> Result<(), Error> {
+            if self.env().is_contract(&operator) && !self.is_marketplace_operator(operator) {
+                return Err(Error::OperatorNotApproved);
+            }
+            Ok(())
+        }
+
+        /// Creates a new escrow for property transfer
+        /// Seller creates escrow and specifies the buyer
+        #[ink(message)]
+        pub fn create_escrow(
+            &mut self,
+            property_id: u64,
+            buyer: AccountId,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Escrow)?;
+            self.ensure_escrow_amount_allowed(&property, amount)?;
+            let listing_id =
+                self.escrow_listing(property_id, buyer, amount, EscrowAsset::Native)?;
+
+            // Only property owner (seller) can create escrow
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if amount == 0 {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            self.validate_escrow_deadline(deadline)?;
+            self.ensure_escrow_asset_accepted(EscrowAsset::Native)?;
+            self.check_escrow_compliance(ComplianceOperation::CreateEscrow, property_id, buyer)?;
+            self.ensure_buyer_not_barred(buyer)?;
+
+            Ok(self.open_new_escrow(
+                EscrowInfo {
+                    listing_id,
+                    deadline,
+                    ..EscrowInfo::new(
+                        property_id,
+                        buyer,
+                        property.owner,
+                        amount,
+                        EscrowAsset::Native,
+                    )
+                },
+                0,
+                true,
+            ))
+        }
+
+        /// Opens an escrow as a prospective buyer against a property owned by someone else
+        /// The current owner becomes the seller and must accept before the escrow can settle.
+        /// The agreed amount may be deposited with this call or later via `fund_escrow`.
+        #[ink(message, payable)]
+        pub fn open_escrow(
+            &mut self,
+            property_id: u64,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let transferred = self.env().transferred_value();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Escrow)?;
+            self.ensure_escrow_amount_allowed(&property, amount)?;
+            let listing_id =
+                self.escrow_listing(property_id, caller, amount, EscrowAsset::Native)?;
+
+            // The buyer cannot open an escrow against their own property
+            if property.owner == caller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+
+            if amount == 0 {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            // An initial installment may accompany the call but cannot exceed the amount
+            if transferred > amount {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            self.validate_escrow_deadline(deadline)?;
+            self.ensure_escrow_asset_accepted(EscrowAsset::Native)?;
+            self.check_escrow_compliance(ComplianceOperation::CreateEscrow, property_id, caller)?;
+            self.ensure_buyer_not_barred(caller)?;
+
+            Ok(self.open_new_escrow(
+                EscrowInfo {
+                    listing_id,
+                    deadline,
+                    ..EscrowInfo::new(
+                        property_id,
+                        caller,
+                        property.owner,
+                        amount,
+                        EscrowAsset::Native,
+                    )
+                },
+                transferred,
+                false,
+            ))
+        }
+
+        /// Sells a block of the caller's shares in a fractionalized property through an escrow
+        /// The shares stay with the seller but cannot be transferred while the escrow is open;
+        /// on release they move to the buyer in the same message as the payment.
+        #[ink(message)]
+        pub fn create_share_escrow(
+            &mut self,
+            property_id: u64,
+            buyer: AccountId,
+            shares: u128,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, Error> {
+            let seller = self.env().caller();
+            self.open_share_block_escrow(property_id, seller, buyer, shares, amount, deadline)
+        }
+
+        /// Opens an escrow as a prospective buyer for a block of another holder's shares
+        /// The holder becomes the seller and must accept, which reserves the shares until
+        /// settlement; funds may accompany the call or follow via `fund_escrow`.
+        #[ink(message, payable)]
+        pub fn open_share_escrow(
+            &mut self,
+            property_id: u64,
+            seller: AccountId,
+            shares: u128,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, Error> {
+            let buyer = self.env().caller();
+            self.open_share_block_escrow(property_id, seller, buyer, shares, amount, deadline)
+        }
+
+        /// Helper: opens a share-block escrow on behalf of whichever party is calling
+        /// A seller-initiated escrow reserves the shares at once, so they must be free now. A
+        /// buyer-initiated one only needs the holder to own them, and may carry a deposit.
+        fn open_share_block_escrow(
+            &mut self,
+            property_id: u64,
+            seller: AccountId,
+            buyer: AccountId,
+            shares: u128,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let seller_initiated = self.env().caller() == seller;
+            let deposit = if seller_initiated {
+                0
+            } else {
+                self.env().transferred_value()
+            };
+            if !self.properties.contains(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFractionalized);
+            }
+            if buyer == seller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+            if shares == 0 {
+                return Err(Error::InsufficientShares);
+            }
+            if seller_initiated {
+                if shares > self.get_transferable_shares(property_id, seller) {
+                    return Err(Error::SharesLocked);
+                }
+            } else if shares > self.get_share_balance(property_id, seller) {
+                return Err(Error::InsufficientShares);
+            }
+            if amount == 0 || deposit > amount {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            self.validate_escrow_deadline(deadline)?;
+            self.ensure_escrow_asset_accepted(EscrowAsset::Native)?;
+            self.check_escrow_compliance(ComplianceOperation::CreateEscrow, property_id, buyer)?;
+            self.ensure_buyer_not_barred(buyer)?;
+
+            let escrow_id = self.open_new_escrow(
+                EscrowInfo {
+                    deadline,
+                    ..EscrowInfo::new(property_id, buyer, seller, amount, EscrowAsset::Native)
+                },
+                deposit,
+                seller_initiated,
+            );
+            self.escrow_shares.insert(escrow_id, &shares);
+
+            Ok(escrow_id)
+        }
+
+        /// Gets the number of shares a share-block escrow sells (None for whole-title escrows)
+        #[ink(message)]
+        pub fn get_escrow_shares(&self, escrow_id: u64) -> Option<u128> {
+            self.escrow_shares.get(escrow_id)
+        }
+
+        /// Opens a PSP22-denominated escrow as a prospective buyer
+        /// The full amount is pulled from the caller via `transfer_from`, so the token
+        /// allowance must be approved beforehand. Release and refund pay out in the same token.
+        #[ink(message)]
+        pub fn create_escrow_psp22(
+            &mut self,
+            property_id: u64,
+            token: AccountId,
+            amount: u128,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Escrow)?;
+            self.ensure_escrow_amount_allowed(&property, amount)?;
+            let listing_id =
+                self.escrow_listing(property_id, caller, amount, EscrowAsset::Psp22(token))?;
+
+            if property.owner == caller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+
+            if amount == 0 {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            self.ensure_escrow_asset_accepted(EscrowAsset::Psp22(token))?;
+            self.check_escrow_compliance(ComplianceOperation::CreateEscrow, property_id, caller)?;
+            self.ensure_buyer_not_barred(caller)?;
+
+            self.pull_psp22(token, caller, amount)?;
+
+            Ok(self.open_new_escrow(
+                EscrowInfo {
+                    listing_id,
+                    ..EscrowInfo::new(
+                        property_id,
+                        caller,
+                        property.owner,
+                        amount,
+                        EscrowAsset::Psp22(token),
+                    )
+                },
+                amount,
+                false,
+            ))
+        }
+
+        /// Opens an escrow as a prospective buyer, backed by an earnest-money deposit
+        /// The transferred value is locked as earnest: it counts toward the escrow amount at
+        /// settlement but is forfeited to the seller if the buyer abandons an accepted escrow.
+        #[ink(message, payable)]
+        pub fn open_escrow_with_earnest(
+            &mut self,
+            property_id: u64,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, Error> {
+            let earnest = self.env().transferred_value();
+            if earnest == 0 {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            let escrow_id = self.open_escrow(property_id, amount, deadline)?;
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+            escrow.earnest_amount = earnest;
+            self.escrows.insert(escrow_id, &escrow);
+
+            Ok(escrow_id)
+        }
+
+        /// Accepts a buyer-initiated escrow (seller only)
+        #[ink(message)]
+        pub fn accept_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            // Only seller (or their agent) can accept
+            if self.escrow_principal(&escrow, caller) != escrow.seller {
+                return Err(Error::Unauthorized);
+            }
+
+            if escrow.seller_accepted {
+                return Err(Error::EscrowAlreadyAccepted);
+            }
+
+            let now = self.env().block_timestamp();
+            if escrow
+                .acceptance_deadline
+                .is_some_and(|deadline| now > deadline)
+            {
+                return Err(Error::EscrowAcceptanceExpired);
+            }
+
+            // Accepting a share-block escrow reserves the shares, so they must be free now
+            if let Some(shares) = self.escrow_shares.get(escrow_id) {
+                if shares > self.get_transferable_shares(escrow.property_id, escrow.seller) {
+                    return Err(Error::SharesLocked);
+                }
+            }
+
+            escrow.seller_accepted = true;
+            escrow.accepted_at = Some(now);
+            self.escrows.insert(escrow_id, &escrow);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowAccepted {
+                escrow_id,
+                property_id: escrow.property_id,
+                seller: escrow.seller,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Deposits native balance towards the agreed escrow amount
+        /// The buyer may fund in installments; deposits accumulate until the amount is reached
+        #[ink(message, payable)]
+        pub fn fund_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.deposit_into_escrow(escrow_id, false)
+        }
+
+        /// Adds native balance to an escrow, beyond the agreed amount if needed (buyer only)
+        /// Any surplus over the agreed price can be taken back with `withdraw_excess` and is
+        /// returned to the buyer automatically at settlement.
+        #[ink(message, payable)]
+        pub fn top_up_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.deposit_into_escrow(escrow_id, true)
+        }
+
+        /// Withdraws the part of the funded balance above the agreed amount (buyer only)
+        /// Returns the amount withdrawn.
+        #[ink(message)]
+        pub fn withdraw_excess(&mut self, escrow_id: u64) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let excess = escrow.funded_amount.saturating_sub(escrow.amount);
+            if excess == 0 {
+                return Err(Error::NoExcessToWithdraw);
+            }
+
+            self.pay_out_escrow(escrow.asset, caller, excess)?;
+            escrow.funded_amount = escrow.amount;
+            self.escrows.insert(escrow_id, &escrow);
+
+            Ok(excess)
+        }
+
+        /// Helper: records a native deposit from the buyer
+        /// Unless `allow_excess` is set, deposits may not overshoot the agreed amount.
+        fn deposit_into_escrow(&mut self, escrow_id: u64, allow_excess: bool) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let transferred = self.env().transferred_value();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            // Only buyer (or their agent) can fund
+            if self.escrow_principal(&escrow, caller) != escrow.buyer {
+                return Err(Error::Unauthorized);
+            }
+
+            // Token escrows are funded in full when they are opened
+            if escrow.asset != EscrowAsset::Native {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            if !allow_excess && escrow.funded_amount >= escrow.amount {
+                return Err(Error::EscrowAlreadyFunded);
+            }
+
+            // Deposits must be non-zero and may not overshoot the agreed amount
+            let funded_amount = escrow
+                .funded_amount
+                .checked_add(transferred)
+                .ok_or(Error::InvalidEscrowDeposit)?;
+            if transferred == 0 || (!allow_excess && funded_amount > escrow.amount) {
+                return Err(Error::InvalidEscrowDeposit);
+            }
+
+            escrow.funded_amount = funded_amount;
+            // The seller's acceptance window starts with the buyer's first deposit
+            if !escrow.seller_accepted && escrow.acceptance_deadline.is_none() {
+                escrow.acceptance_deadline = self.acceptance_deadline();
+            }
+            if escrow.state == EscrowState::Created && funded_amount >= escrow.amount {
+                Self::transition_escrow(&mut escrow, EscrowState::Funded)?;
+                self.route_escrow_to_yield(&mut escrow)?;
+            }
+            self.escrows.insert(escrow_id, &escrow);
+            self.total_escrowed = self.total_escrowed.saturating_add(transferred);
+
+            // Emit escrow funded event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowFunded {
+                escrow_id,
+                buyer: escrow.buyer,
+                event_version: 1,
+                amount: transferred,
+                funded_amount,
+                fully_funded: funded_amount >= escrow.amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Releases escrow funds and transfers property
+        /// With a cooling-off period configured this only approves the release, and the
+        /// settlement happens later through `finalize_escrow`.
+        #[ink(message)]
+        pub fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            // Only buyer (or their agent) can release
+            if self.escrow_principal(&escrow, caller) != escrow.buyer {
+                return Err(Error::Unauthorized);
+            }
+
+            self.ensure_release_ready(&escrow)?;
+            self.approve_or_settle(escrow, caller)?;
+            Ok(())
+        }
+
+        /// Releases several escrows in one transaction (buyer only)
+        /// Every escrow is validated before any is settled, so either all succeed or none do.
+        #[ink(message)]
+        pub fn release_escrows(&mut self, escrow_ids: Vec<u64>) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Validate all escrows first to avoid partial settlement
+            let mut escrows = Vec::new();
+            for (index, &escrow_id) in escrow_ids.iter().enumerate() {
+                if escrow_ids[..index].contains(&escrow_id) {
+                    return Err(Error::InvalidEscrowTransition);
+                }
+                let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+                Self::ensure_escrow_active(&escrow)?;
+                if self.escrow_principal(&escrow, caller) != escrow.buyer {
+                    return Err(Error::Unauthorized);
+                }
+                self.ensure_release_ready(&escrow)?;
+                // Share-block sellers are shareholders, not necessarily the title owner
+                if !self.escrow_shares.contains(escrow_id)
+                    && self.property_owners.get(escrow.property_id) != Some(escrow.seller)
+                {
+                    return Err(Error::EscrowSellerNotOwner);
+                }
+                escrows.push(escrow);
+            }
+
+            let mut released = Vec::new();
+            let mut total_amount: u128 = 0;
+            for escrow in escrows {
+                let (escrow_id, amount) = (escrow.id, escrow.amount);
+                if self.approve_or_settle(escrow, caller)? {
+                    released.push(escrow_id);
+                    total_amount = total_amount.saturating_add(amount);
+                }
+            }
+
+            if !released.is_empty() {
+                let transaction_hash: Hash = [0u8; 32].into();
+                self.env().emit_event(BatchEscrowReleased {
+                    buyer: caller,
+                    event_version: 1,
+                    count: released.len() as u64,
+                    escrow_ids: released,
+                    total_amount,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Settles an approved escrow once its cooling-off window has elapsed
+        /// Callable by the buyer or seller.
+        #[ink(message)]
+        pub fn finalize_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let approved_at = escrow.approved_at.ok_or(Error::EscrowNotApproved)?;
+            let release_after = approved_at.saturating_add(self.escrow_cooling_off_period);
+            if self.env().block_timestamp() < release_after {
+                return Err(Error::CoolingOffActive);
+            }
+
+            self.ensure_release_ready(&escrow)?;
+            self.settle_escrow(escrow, caller)
+        }
+
+        /// Sets the cooling-off delay between release approval and settlement (admin only)
+        #[ink(message)]
+        pub fn set_escrow_cooling_off_period(&mut self, period: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.escrow_cooling_off_period = period;
+            Ok(())
+        }
+
+        /// Gets the cooling-off delay between release approval and settlement
+        #[ink(message)]
+        pub fn get_escrow_cooling_off_period(&self) -> u64 {
+            self.escrow_cooling_off_period
+        }
+
+        /// Sets how long a seller has to accept an escrow once it is funded (admin only)
+        /// After the window lapses the seller can no longer accept, and the buyer can walk away
+        /// through `abandon_escrow` without forfeiting earnest money.
+        #[ink(message)]
+        pub fn set_escrow_acceptance_window(&mut self, window: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.escrow_acceptance_window = window;
+            Ok(())
+        }
+
+        /// Gets how long a seller has to accept an escrow once it is funded
+        #[ink(message)]
+        pub fn get_escrow_acceptance_window(&self) -> u64 {
+            self.escrow_acceptance_window
+        }
+
+        /// Helper: computes the acceptance deadline for an escrow funded now
+        fn acceptance_deadline(&self) -> Option<u64> {
+            if self.escrow_acceptance_window == 0 {
+                return None;
+            }
+            Some(
+                self.env()
+                    .block_timestamp()
+                    .saturating_add(self.escrow_acceptance_window),
+            )
+        }
+
+        /// Helper: settles a release-ready escrow, or starts its cooling-off window when one is
+        /// configured. Returns true if the escrow was settled.
+        fn approve_or_settle(
+            &mut self,
+            mut escrow: EscrowInfo,
+            caller: AccountId,
+        ) -> Result<bool, Error> {
+            if self.escrow_cooling_off_period == 0 {
+                self.settle_escrow(escrow, caller)?;
+                return Ok(true);
+            }
+
+            if escrow.approved_at.is_some() {
+                return Err(Error::AlreadyApproved);
+            }
+
+            let approved_at = self.env().block_timestamp();
+            escrow.approved_at = Some(approved_at);
+            self.escrows.insert(escrow.id, &escrow);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowReleaseScheduled {
+                escrow_id: escrow.id,
+                approved_by: caller,
+                event_version: 1,
+                approved_at,
+                release_after: approved_at.saturating_add(self.escrow_cooling_off_period),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(false)
+        }
+
+        /// Configures N-of-M signers who must approve before the escrow can be released
+        /// Callable by the buyer or seller while the escrow is still awaiting its full deposit.
+        /// Passing an empty signer list removes the requirement.
+        #[ink(message)]
+        pub fn set_escrow_signers(
+            &mut self,
+            escrow_id: u64,
+            signers: Vec<AccountId>,
+            threshold: u32,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if escrow.state != EscrowState::Created {
+                return Err(Error::EscrowAlreadyFunded);
+            }
+
+            if signers.is_empty() {
+                self.escrow_signers.remove(escrow_id);
+                return Ok(());
+            }
+
+            let mut unique = signers.clone();
+            unique.sort();
+            unique.dedup();
+            if unique.len() != signers.len()
+                || signers.len() as u32 > MAX_ESCROW_SIGNERS
+                || threshold == 0
+                || threshold as usize > signers.len()
+            {
+                return Err(Error::InvalidSignerThreshold);
+            }
+
+            self.escrow_signers.insert(
+                escrow_id,
+                &EscrowSigners {
+                    signers,
+                    threshold,
+                    approvals: Vec::new(),
+                },
+            );
+            Ok(())
+        }
+
+        /// Approves the release of an escrow as one of its designated signers
+        /// Once the threshold is met and the escrow is otherwise ready, the release executes
+        /// immediately (or enters its cooling-off window). Returns true if this approval settled
+        /// the escrow.
+        #[ink(message)]
+        pub fn approve_release(&mut self, escrow_id: u64) -> Result<bool, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            let mut config = self
+                .escrow_signers
+                .get(escrow_id)
+                .ok_or(Error::Unauthorized)?;
+            // Agents approve on behalf of the party that appointed them
+            let signer = self.escrow_principal(&escrow, caller);
+            if !config.signers.contains(&signer) {
+                return Err(Error::Unauthorized);
+            }
+            if config.approvals.contains(&signer) {
+                return Err(Error::AlreadyApproved);
+            }
+
+            config.approvals.push(signer);
+            self.escrow_signers.insert(escrow_id, &config);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowReleaseApproved {
+                escrow_id,
+                signer,
+                event_version: 1,
+                approvals: config.approvals.len() as u32,
+                threshold: config.threshold,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            if self.ensure_release_ready(&escrow).is_ok() {
+                return self.approve_or_settle(escrow, caller);
+            }
+            Ok(false)
+        }
+
+        /// Gets the release signers and approvals configured for an escrow
+        #[ink(message)]
+        pub fn get_escrow_signers(&self, escrow_id: u64) -> Option<EscrowSigners> {
+            self.escrow_signers.get(escrow_id)
+        }
+
+        /// Helper: checks every precondition for releasing an escrow besides who is calling
+        fn ensure_release_ready(&mut self, escrow: &EscrowInfo) -> Result<(), Error> {
+            if !escrow.seller_accepted {
+                return Err(Error::EscrowNotAccepted);
+            }
+
+            if escrow.funded_amount < escrow.amount {
+                return Err(Error::EscrowNotFunded);
+            }
+
+            let conditions = self.escrow_conditions.get(escrow.id).unwrap_or_default();
+            if conditions.iter().any(|condition| !condition.satisfied) {
+                return Err(Error::EscrowConditionsNotMet);
+            }
+
+            if escrow.notary.is_some() && !escrow.notary_approved {
+                return Err(Error::NotaryApprovalRequired);
+            }
+
+            if escrow.terms_hash.is_some()
+                && !(escrow.buyer_acknowledged_terms && escrow.seller_acknowledged_terms)
+            {
+                return Err(Error::EscrowTermsNotAcknowledged);
+            }
+
+            if let Some(config) = self.escrow_signers.get(escrow.id) {
+                if (config.approvals.len() as u32) < config.threshold {
+                    return Err(Error::InsufficientApprovals);
+                }
+            }
+
+            // Compliance status can lapse between creation and release
+            self.check_escrow_compliance(
+                ComplianceOperation::ReleaseEscrow,
+                escrow.property_id,
+                escrow.buyer,
+            )
+        }
+
+        /// Helper: moves the title to the buyer and pays the seller for a release-ready escrow
+        fn settle_escrow(
+            &mut self,
+            mut escrow: EscrowInfo,
+            caller: AccountId,
+        ) -> Result<(), Error> {
+            let escrow_id = escrow.id;
+            let price = escrow.amount.min(escrow.funded_amount);
+            // Share blocks are not resales of the property, so no royalty or sale receipt
+            let share_block = self.escrow_shares.contains(escrow_id);
+
+            // The bound listing is sold before the title moves, so the move does not cancel it
+            if !share_block {
+                self.close_escrowed_listing(&escrow, price);
+            }
+            // Move the title from seller to buyer in the same message as the payout
+            self.transfer_escrowed_title(&escrow, caller)?;
+
+            // Pay the agreed price to the seller, keeping the platform fee, and return any
+            // surplus deposit to the buyer
+            let excess = escrow.funded_amount.saturating_sub(price);
+            let fee = price
+                .saturating_mul(self.escrow_fee_bps as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            let royalty = if share_block {
+                None
+            } else {
+                self.royalty_due(escrow.property_id, escrow.seller, price)
+            };
+            let royalty_amount = royalty.map(|(_, amount)| amount).unwrap_or(0);
+            let payout = price.saturating_sub(fee).saturating_sub(royalty_amount);
+            let loss = self.unwind_escrow_yield(&mut escrow)?;
+            let (excess, payout) = loss.charge(excess, payout);
+            escrow.funded_amount = 0;
+            Self::transition_escrow(&mut escrow, EscrowState::Released)?;
+            self.escrows.insert(escrow_id, &escrow);
+            match escrow.asset {
+                EscrowAsset::Native => {
+                    self.total_escrowed = self.total_escrowed.saturating_sub(fee);
+                    self.treasury_balance = self.treasury_balance.saturating_add(fee);
+                }
+                // The treasury accrues native balance only; token fees are sent to the
+                // treasury account
+                EscrowAsset::Psp22(_) => self.pay_out_escrow(escrow.asset, self.treasury, fee)?,
+            }
+            if let Some((receiver, amount)) = royalty {
+                self.pay_out_escrow(escrow.asset, receiver, amount)?;
+                self.emit_royalty_paid(escrow.property_id, escrow.seller, receiver, price, amount);
+            }
+            self.pay_out_escrow(escrow.asset, escrow.seller, payout)?;
+            self.pay_out_escrow(escrow.asset, escrow.buyer, excess)?;
+            if !share_block {
+                self.record_sale(&escrow, price);
+            }
+
+            // Emit enhanced escrow released event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowReleased {
+                escrow_id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                seller: escrow.seller,
+                event_version: 1,
+                amount: escrow.amount,
+                fee,
+                royalty: royalty_amount,
+                seller_payout: payout,
+                excess_refunded: excess,
+                terms_hash: escrow.terms_hash,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                released_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Walks away from an escrow before closing (buyer only)
+        /// Once the seller has accepted, the earnest deposit is forfeited to the seller and only
+        /// the remaining balance is returned; before acceptance everything is returned.
+        #[ink(message)]
+        pub fn abandon_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            // Only buyer can walk away
+            if escrow.buyer != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let forfeited = if escrow.seller_accepted {
+                escrow.earnest_amount.min(escrow.funded_amount)
+            } else {
+                0
+            };
+            let refund = escrow.funded_amount.saturating_sub(forfeited);
+            let loss = self.unwind_escrow_yield(&mut escrow)?;
+            let (refund, forfeited) = loss.charge(refund, forfeited);
+            escrow.funded_amount = 0;
+            Self::transition_escrow(&mut escrow, EscrowState::Refunded)?;
+            self.escrows.insert(escrow_id, &escrow);
+            self.pay_out_escrow(escrow.asset, escrow.seller, forfeited)?;
+            self.pay_out_escrow(escrow.asset, escrow.buyer, refund)?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            if forfeited > 0 {
+                self.env().emit_event(EarnestForfeited {
+                    escrow_id,
+                    property_id: escrow.property_id,
+                    seller: escrow.seller,
+                    event_version: 1,
+                    amount: forfeited,
+                    refunded_to_buyer: refund,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            } else {
+                self.env().emit_event(EscrowRefunded {
+                    escrow_id,
+                    property_id: escrow.property_id,
+                    buyer: escrow.buyer,
+                    seller: escrow.seller,
+                    event_version: 1,
+                    amount: refund,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                    refunded_by: caller,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Refunds escrow funds
+        #[ink(message)]
+        pub fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            // Only seller (or their agent) can refund
+            if self.escrow_principal(&escrow, caller) != escrow.seller {
+                return Err(Error::Unauthorized);
+            }
+
+            // Return any locked balance to the buyer. The state check comes first so a closed
+            // escrow is never paid out twice; a failed transfer reverts the whole refund.
+            let refund = escrow.funded_amount;
+            Self::transition_escrow(&mut escrow, EscrowState::Refunded)?;
+            let (refund, _) = self.unwind_escrow_yield(&mut escrow)?.charge(refund, 0);
+            self.pay_out_escrow(escrow.asset, escrow.buyer, refund)?;
+            escrow.funded_amount = 0;
+            self.escrows.insert(escrow_id, &escrow);
+
+            // Emit enhanced escrow refunded event
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowRefunded {
+                escrow_id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                seller: escrow.seller,
+                event_version: 1,
+                amount: refund,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                refunded_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Reclaims the deposit of an escrow whose deadline has passed (buyer only)
+        #[ink(message)]
+        pub fn claim_expired_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            // Only buyer can reclaim
+            if escrow.buyer != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if !self.is_escrow_expired(&escrow) {
+                return Err(Error::EscrowNotExpired);
+            }
+
+            self.expire_escrow(escrow)?;
+            Ok(())
+        }
+
+        /// Refunds expired escrows in batches, paying the caller a bounty from platform fees
+        /// Anyone may call this. Up to `limit` escrow IDs are examined per call, continuing from
+        /// where the previous sweep stopped. Returns the number of escrows refunded.
+        /// The bounty is only earned on escrows that returned a deposit, and never exceeds that
+        /// deposit; a buyer's own escrows are left for `claim_expired_escrow`. If any refund
+        /// cannot be paid the whole sweep fails, so no escrow is left with a half-applied
+        /// unwind.
+        #[ink(message)]
+        pub fn sweep_expired_escrows(&mut self, limit: u32) -> Result<u32, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            let scan = (limit.min(MAX_ESCROW_PAGE_SIZE) as u64).min(self.escrow_count);
+            let mut swept = Vec::new();
+            let mut earned: u128 = 0;
+            for _ in 0..scan {
+                self.escrow_sweep_cursor = self.escrow_sweep_cursor % self.escrow_count + 1;
+                let escrow_id = self.escrow_sweep_cursor;
+                let escrow = match self.escrows.get(escrow_id) {
+                    Some(escrow) => escrow,
+                    None => continue,
+                };
+                if escrow.buyer == caller {
+                    continue;
+                }
+                if Self::ensure_escrow_active(&escrow).is_ok() && self.is_escrow_expired(&escrow) {
+                    let refund = self.expire_escrow(escrow)?;
+                    swept.push(escrow_id);
+                    if refund > 0 {
+                        earned = earned.saturating_add(self.escrow_sweep_bounty.min(refund));
+                    }
+                }
+            }
+
+            // The bounty is capped by the fees available in the pool
+            let bounty = earned.min(self.treasury_balance);
+            if bounty > 0 {
+                self.treasury_balance = self.treasury_balance.saturating_sub(bounty);
+                self.env()
+                    .transfer(caller, bounty)
+                    .map_err(|_| Error::EscrowTransferFailed)?;
+            }
+
+            let count = swept.len() as u32;
+            if count > 0 {
+                let transaction_hash: Hash = [0u8; 32].into();
+                self.env().emit_event(ExpiredEscrowsSwept {
+                    keeper: caller,
+                    event_version: 1,
+                    escrow_ids: swept,
+                    count: count as u64,
+                    bounty,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+
+            Ok(count)
+        }
+
+        /// Sets the bounty paid per expired escrow swept by a keeper (admin only)
+        #[ink(message)]
+        pub fn set_escrow_sweep_bounty(&mut self, bounty: u128) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.escrow_sweep_bounty = bounty;
+            Ok(())
+        }
+
+        /// Gets the bounty paid per expired escrow swept by a keeper
+        #[ink(message)]
+        pub fn get_escrow_sweep_bounty(&self) -> u128 {
+            self.escrow_sweep_bounty
+        }
+
+        /// Helper: returns true once an escrow's deadline has passed
+        fn is_escrow_expired(&self, escrow: &EscrowInfo) -> bool {
+            escrow
+                .deadline
+                .is_some_and(|deadline| self.env().block_timestamp() >= deadline)
+        }
+
+        /// Helper: returns an expired escrow's deposit to the buyer and closes it
+        /// Once the seller has accepted, the earnest deposit is forfeited to the seller as if
+        /// the buyer had abandoned the escrow, and the lapse counts as a default against a
+        /// buyer who had funded it. Returns the amount refunded to the buyer.
+        fn expire_escrow(&mut self, mut escrow: EscrowInfo) -> Result<u128, Error> {
+            let escrow_id = escrow.id;
+            let funded = escrow.funded_amount;
+            if !escrow.state.can_transition_to(EscrowState::Expired) {
+                return Err(Error::InvalidEscrowTransition);
+            }
+            let forfeited = if escrow.seller_accepted {
+                escrow.earnest_amount.min(funded)
+            } else {
+                0
+            };
+            let (refund, forfeited) = self
+                .unwind_escrow_yield(&mut escrow)?
+                .charge(funded.saturating_sub(forfeited), forfeited);
+            self.pay_out_escrow(escrow.asset, escrow.seller, forfeited)?;
+            self.pay_out_escrow(escrow.asset, escrow.buyer, refund)?;
+            escrow.funded_amount = 0;
+            Self::transition_escrow(&mut escrow, EscrowState::Expired)?;
+            self.escrows.insert(escrow_id, &escrow);
+            // Letting an accepted, funded escrow lapse counts against the buyer
+            if escrow.seller_accepted && funded > 0 {
+                self.record_buyer_default(escrow.buyer);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            if forfeited > 0 {
+                self.env().emit_event(EarnestForfeited {
+                    escrow_id,
+                    property_id: escrow.property_id,
+                    seller: escrow.seller,
+                    event_version: 1,
+                    amount: forfeited,
+                    refunded_to_buyer: refund,
+                    timestamp: self.env().block_timestamp(),
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+            self.env().emit_event(EscrowExpired {
+                escrow_id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                event_version: 1,
+                amount: refund,
+                deadline: escrow.deadline.unwrap_or_default(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(refund)
+        }
+
+        /// Cancels an escrow by mutual consent of buyer and seller
+        /// The first call records the caller's consent; the counterparty's call returns
+        /// any locked balance to the buyer and closes the escrow.
+        #[ink(message)]
+        pub fn cancel_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            match escrow.cancel_requested_by {
+                Some(requested_by) if requested_by == caller => {
+                    Err(Error::CancellationAlreadyRequested)
+                }
+                Some(_) => {
+                    let refund = escrow.funded_amount;
+                    let (refund, _) = self.unwind_escrow_yield(&mut escrow)?.charge(refund, 0);
+                    escrow.funded_amount = 0;
+                    Self::transition_escrow(&mut escrow, EscrowState::Refunded)?;
+                    self.escrows.insert(escrow_id, &escrow);
+                    self.pay_out_escrow(escrow.asset, escrow.buyer, refund)?;
+
+                    self.env().emit_event(EscrowCancelled {
+                        escrow_id,
+                        property_id: escrow.property_id,
+                        buyer: escrow.buyer,
+                        event_version: 1,
+                        amount: refund,
+                        timestamp: self.env().block_timestamp(),
+                        block_number: self.env().block_number(),
+                        transaction_hash,
+                        confirmed_by: caller,
+                    });
+                    Ok(())
+                }
+                None => {
+                    escrow.cancel_requested_by = Some(caller);
+                    self.escrows.insert(escrow_id, &escrow);
+
+                    self.env().emit_event(EscrowCancelRequested {
+                        escrow_id,
+                        property_id: escrow.property_id,
+                        requested_by: caller,
+                        event_version: 1,
+                        timestamp: self.env().block_timestamp(),
+                        block_number: self.env().block_number(),
+                        transaction_hash,
+                    });
+                    Ok(())
+                }
+            }
+        }
+
+        /// Raises a dispute on an escrow, freezing it until the arbitrator resolves it
+        /// Callable by the buyer or seller
+        #[ink(message)]
+        pub fn dispute_escrow(&mut self, escrow_id: u64, reason: String) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            Self::transition_escrow(&mut escrow, EscrowState::Disputed)?;
+            self.escrows.insert(escrow_id, &escrow);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowDisputed {
+                escrow_id,
+                property_id: escrow.property_id,
+                raised_by: caller,
+                event_version: 1,
+                reason,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Resolves a disputed escrow (arbitrator only)
+        /// Splits the locked funds, giving the buyer `buyer_share_bps` basis points and the seller
+        /// the rest (0 awards everything to the seller, 10_000 everything to the buyer), and
+        /// optionally forces the title transfer from seller to buyer.
+        #[ink(message)]
+        pub fn resolve_escrow_dispute(
+            &mut self,
+            escrow_id: u64,
+            buyer_share_bps: u32,
+            transfer_title: bool,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if self.escrow_arbitrator != Some(caller) {
+                return Err(Error::NotArbitrator);
+            }
+
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            if escrow.state != EscrowState::Disputed {
+                Self::ensure_escrow_active(&escrow)?;
+                return Err(Error::EscrowNotDisputed);
+            }
+
+            if buyer_share_bps > 10_000 {
+                return Err(Error::InvalidBasisPoints);
+            }
+
+            let funded = escrow.funded_amount;
+            let buyer_amount = funded
+                .saturating_mul(buyer_share_bps as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            let seller_amount = funded.saturating_sub(buyer_amount);
+            // Share blocks are not resales of the property, so no listing or sale receipt
+            let share_block = self.escrow_shares.contains(escrow_id);
+
+            if transfer_title {
+                self.check_escrow_compliance(
+                    ComplianceOperation::ReleaseEscrow,
+                    escrow.property_id,
+                    escrow.buyer,
+                )?;
+                if !share_block {
+                    self.close_escrowed_listing(&escrow, seller_amount);
+                }
+                self.transfer_escrowed_title(&escrow, caller)?;
+            }
+            let loss = self.unwind_escrow_yield(&mut escrow)?;
+            let (buyer_amount, seller_amount) = loss.charge(buyer_amount, seller_amount);
+            escrow.funded_amount = 0;
+            // The escrow counts as released when the sale went through or the seller kept
+            // everything, and as refunded whenever the buyer got money back without the title
+            let next = if transfer_title || buyer_amount == 0 {
+                EscrowState::Released
+            } else {
+                EscrowState::Refunded
+            };
+            Self::transition_escrow(&mut escrow, next)?;
+            self.escrows.insert(escrow_id, &escrow);
+            if next == EscrowState::Refunded {
+                self.record_buyer_default(escrow.buyer);
+            }
+            self.pay_out_escrow(escrow.asset, escrow.seller, seller_amount)?;
+            self.pay_out_escrow(escrow.asset, escrow.buyer, buyer_amount)?;
+            if transfer_title && !share_block {
+                self.record_sale(&escrow, seller_amount);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowDisputeResolved {
+                escrow_id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                event_version: 1,
+                buyer_amount,
+                seller_amount,
+                title_transferred: transfer_title,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                resolved_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Sets the escrow dispute arbitrator (admin only)
+        #[ink(message)]
+        pub fn set_escrow_arbitrator(
+            &mut self,
+            arbitrator: Option<AccountId>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.escrow_arbitrator = arbitrator;
+            Ok(())
+        }
+
+        /// Gets the escrow dispute arbitrator
+        #[ink(message)]
+        pub fn get_escrow_arbitrator(&self) -> Option<AccountId> {
+            self.escrow_arbitrator
+        }
+
+        /// Attaches a notary who must co-sign the release (buyer or seller)
+        /// The notary can only be changed while the escrow is awaiting its full deposit.
+        #[ink(message)]
+        pub fn set_escrow_notary(
+            &mut self,
+            escrow_id: u64,
+            notary: Option<AccountId>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if escrow.state != EscrowState::Created {
+                return Err(Error::EscrowAlreadyFunded);
+            }
+
+            escrow.notary = notary;
+            escrow.notary_approved = false;
+            self.escrows.insert(escrow_id, &escrow);
+            Ok(())
+        }
+
+        /// Attaches the hash of the off-chain purchase agreement (buyer or seller)
+        /// Counts as the caller's acknowledgement; the counterparty must acknowledge the same
+        /// hash before release. Terms can be changed until the release has been approved, and a
+        /// new hash clears both earlier acknowledgements.
+        #[ink(message)]
+        pub fn attach_escrow_terms(
+            &mut self,
+            escrow_id: u64,
+            terms_hash: Hash,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            let party = self.escrow_principal(&escrow, caller);
+            if escrow.buyer != party && escrow.seller != party {
+                return Err(Error::Unauthorized);
+            }
+
+            if escrow.approved_at.is_some() {
+                return Err(Error::EscrowTermsLocked);
+            }
+
+            if escrow.terms_hash != Some(terms_hash) {
+                escrow.terms_hash = Some(terms_hash);
+                escrow.buyer_acknowledged_terms = false;
+                escrow.seller_acknowledged_terms = false;
+            }
+            if party == escrow.buyer {
+                escrow.buyer_acknowledged_terms = true;
+            }
+            if party == escrow.seller {
+                escrow.seller_acknowledged_terms = true;
+            }
+            self.escrows.insert(escrow_id, &escrow);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowTermsAttached {
+                escrow_id,
+                terms_hash,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                attached_by: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Acknowledges the purchase agreement attached to an escrow (buyer or seller)
+        /// The hash must match the attached one, so a party cannot sign off on changed terms.
+        #[ink(message)]
+        pub fn acknowledge_escrow_terms(
+            &mut self,
+            escrow_id: u64,
+            terms_hash: Hash,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            let party = self.escrow_principal(&escrow, caller);
+            if party == escrow.buyer {
+                escrow.buyer_acknowledged_terms = true;
+            } else if party == escrow.seller {
+                escrow.seller_acknowledged_terms = true;
+            } else {
+                return Err(Error::Unauthorized);
+            }
+
+            if escrow.terms_hash != Some(terms_hash) {
+                return Err(Error::EscrowTermsMismatch);
+            }
+
+            self.escrows.insert(escrow_id, &escrow);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowTermsAcknowledged {
+                escrow_id,
+                party,
+                event_version: 1,
+                terms_hash,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Signs off on an escrow's release as its attached notary
+        #[ink(message)]
+        pub fn notary_approve(&mut self, escrow_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.notary != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            if escrow.notary_approved {
+                return Err(Error::AlreadyApproved);
+            }
+
+            escrow.notary_approved = true;
+            self.escrows.insert(escrow_id, &escrow);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowNotaryApproved {
+                escrow_id,
+                notary: caller,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Attaches a named closing condition that must be satisfied before release
+        /// Callable by the seller or the escrow's notary. Returns the condition index.
+        #[ink(message)]
+        pub fn add_escrow_condition(&mut self, escrow_id: u64, name: String) -> Result<u32, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.seller != caller && escrow.notary != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut conditions = self.escrow_conditions.get(escrow_id).unwrap_or_default();
+            if conditions.len() as u32 >= MAX_ESCROW_CONDITIONS {
+                return Err(Error::TooManyEscrowConditions);
+            }
+
+            let condition_index = conditions.len() as u32;
+            conditions.push(EscrowCondition {
+                name: name.clone(),
+                satisfied: false,
+                satisfied_by: None,
+            });
+            self.escrow_conditions.insert(escrow_id, &conditions);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowConditionAdded {
+                escrow_id,
+                added_by: caller,
+                event_version: 1,
+                condition_index,
+                name,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(condition_index)
+        }
+
+        /// Marks a closing condition as satisfied
+        /// Callable by the buyer, whom the conditions protect, or the escrow's notary.
+        #[ink(message)]
+        pub fn satisfy_escrow_condition(
+            &mut self,
+            escrow_id: u64,
+            condition_index: u32,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.notary != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut conditions = self.escrow_conditions.get(escrow_id).unwrap_or_default();
+            let condition = conditions
+                .get_mut(condition_index as usize)
+                .ok_or(Error::EscrowConditionNotFound)?;
+            if condition.satisfied {
+                return Err(Error::EscrowConditionAlreadySatisfied);
+            }
+            condition.satisfied = true;
+            condition.satisfied_by = Some(caller);
+            self.escrow_conditions.insert(escrow_id, &conditions);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowConditionSatisfied {
+                escrow_id,
+                satisfied_by: caller,
+                event_version: 1,
+                condition_index,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Appoints an agent to act on the caller's behalf in an escrow until `expires_at`
+        /// Callable by the buyer or seller. Re-delegating to the same agent updates the expiry.
+        #[ink(message)]
+        pub fn delegate_escrow_agent(
+            &mut self,
+            escrow_id: u64,
+            agent: AccountId,
+            expires_at: u64,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            // The counterparty cannot act as an agent, and an agent serves only one principal
+            if agent == escrow.buyer
+                || agent == escrow.seller
+                || expires_at <= self.env().block_timestamp()
+            {
+                return Err(Error::InvalidDelegation);
+            }
+            if let Some(existing) = self.escrow_delegations.get((escrow_id, agent)) {
+                if existing.principal != caller {
+                    return Err(Error::InvalidDelegation);
+                }
+            }
+
+            self.escrow_delegations.insert(
+                (escrow_id, agent),
+                &EscrowDelegation {
+                    principal: caller,
+                    expires_at,
+                },
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowAgentDelegated {
+                escrow_id,
+                principal: caller,
+                agent,
+                event_version: 1,
+                expires_at,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Revokes an agent previously appointed by the caller
+        #[ink(message)]
+        pub fn revoke_escrow_agent(
+            &mut self,
+            escrow_id: u64,
+            agent: AccountId,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let delegation = self
+                .escrow_delegations
+                .get((escrow_id, agent))
+                .ok_or(Error::DelegationNotFound)?;
+            if delegation.principal != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            self.escrow_delegations.remove((escrow_id, agent));
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowAgentRevoked {
+                escrow_id,
+                principal: caller,
+                agent,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Gets the delegation held by an agent on an escrow, if any
+        #[ink(message)]
+        pub fn get_escrow_delegation(
+            &self,
+            escrow_id: u64,
+            agent: AccountId,
+        ) -> Option<EscrowDelegation> {
+            self.escrow_delegations.get((escrow_id, agent))
+        }
+
+        /// Helper: resolves the escrow party an account acts for
+        /// Returns the principal for an agent with an unexpired delegation, otherwise the
+        /// account itself.
+        fn escrow_principal(&self, escrow: &EscrowInfo, account: AccountId) -> AccountId {
+            match self.escrow_delegations.get((escrow.id, account)) {
+                Some(delegation) if delegation.expires_at > self.env().block_timestamp() => {
+                    delegation.principal
+                }
+                _ => account,
+            }
+        }
+
+        /// Gets the closing conditions attached to an escrow
+        #[ink(message)]
+        pub fn get_escrow_conditions(&self, escrow_id: u64) -> Vec<EscrowCondition> {
+            self.escrow_conditions.get(escrow_id).unwrap_or_default()
+        }
+
+        /// Sets the yield source escrowed native funds may be routed into (admin only)
+        #[ink(message)]
+        pub fn set_yield_source(&mut self, yield_source: Option<AccountId>) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.yield_source = yield_source;
+            Ok(())
+        }
+
+        /// Gets the configured yield source
+        #[ink(message)]
+        pub fn get_yield_source(&self) -> Option<AccountId> {
+            self.yield_source
+        }
+
+        /// Agrees to route an escrow's funds into the yield source while they are locked
+        /// The first party proposes the buyer's share of interest; the escrow earns yield once the
+        /// counterparty calls with the same share. Funds move when the escrow is fully funded.
+        #[ink(message)]
+        pub fn enable_escrow_yield(
+            &mut self,
+            escrow_id: u64,
+            buyer_share_bps: u32,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
+
+            Self::ensure_escrow_active(&escrow)?;
+
+            if escrow.buyer != caller && escrow.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if self.yield_source.is_none() {
+                return Err(Error::YieldSourceNotSet);
+            }
+
+            // Only native escrows can be routed, and only once
+            if escrow.asset != EscrowAsset::Native || escrow.yield_buyer_share_bps.is_some() {
+                return Err(Error::InvalidEscrowTransition);
+            }
+
+            if buyer_share_bps > 10_000 {
+                return Err(Error::InvalidBasisPoints);
+            }
+
+            match self.escrow_yield_proposals.get(escrow_id) {
+                Some((proposer, share)) if proposer != caller && share == buyer_share_bps => {
+                    self.escrow_yield_proposals.remove(escrow_id);
+                    escrow.yield_buyer_share_bps = Some(buyer_share_bps);
+                    self.route_escrow_to_yield(&mut escrow)?;
+                    self.escrows.insert(escrow_id, &escrow);
+
+                    let transaction_hash: Hash = [0u8; 32].into();
+                    self.env().emit_event(EscrowYieldEnabled {
+                        escrow_id,
+                        confirmed_by: caller,
+                        event_version: 1,
+                        buyer_share_bps,
+                        timestamp: self.env().block_timestamp(),
+                        block_number: self.env().block_number(),
+                        transaction_hash,
+                    });
+                }
+                _ => {
+                    self.escrow_yield_proposals
+                        .insert(escrow_id, &(caller, buyer_share_bps));
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Helper: deposits a fully funded, yield-enabled escrow's balance into the yield source
+        fn route_escrow_to_yield(&mut self, escrow: &mut EscrowInfo) -> Result<(), Error> {
+            if escrow.yield_buyer_share_bps.is_none()
+                || escrow.yield_principal > 0
+                || escrow.state != EscrowState::Funded
+                || escrow.funded_amount == 0
+            {
+                return Ok(());
+            }
+            let yield_source = self.yield_source.ok_or(Error::YieldSourceNotSet)?;
+
+            // Only the agreed amount is invested; any surplus stays withdrawable
+            let principal = escrow.amount.min(escrow.funded_amount);
+
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            let mut source: ink::contract_ref!(YieldSource) =
+                FromAccountId::from_account_id(yield_source);
+            source
+                .call_mut()
+                .deposit(escrow.id)
+                .transferred_value(principal)
+                .invoke()
+                .map_err(|_| Error::YieldSourceFailed)?;
+
+            escrow.yield_principal = principal;
+            Ok(())
+        }
+
+        /// Helper: withdraws an escrow's yield position and splits accrued interest
+        /// The principal returns to the contract so the normal payout can follow. A source that
+        /// returns less than the principal does not block settlement: the shortfall is split
+        /// like the interest would have been, and callers charge it to each party's payout.
+        fn unwind_escrow_yield(&mut self, escrow: &mut EscrowInfo) -> Result<YieldLoss, Error> {
+            if escrow.yield_principal == 0 {
+                return Ok(YieldLoss::default());
+            }
+            let yield_source = self.yield_source.ok_or(Error::YieldSourceNotSet)?;
+
+            use ink::env::call::FromAccountId;
+            let mut source: ink::contract_ref!(YieldSource) =
+                FromAccountId::from_account_id(yield_source);
+            let returned = source
+                .withdraw_all(escrow.id)
+                .map_err(|_| Error::YieldSourceFailed)?;
+
+            let principal = escrow.yield_principal;
+            let buyer_share_bps = escrow.yield_buyer_share_bps.unwrap_or(0);
+            let loss = YieldLoss::split(principal.saturating_sub(returned), buyer_share_bps);
+            // Recorded before the interest is paid out, so a failed payout cannot leave the
+            // escrow claiming a position the source no longer holds, or funds it lost
+            escrow.yield_principal = 0;
+            escrow.funded_amount = escrow.funded_amount.saturating_sub(loss.total());
+            self.total_escrowed = self.total_escrowed.saturating_sub(loss.total());
+            self.escrows.insert(escrow.id, &*escrow);
+
+            let interest = returned.saturating_sub(principal);
+            let buyer_interest = interest
+                .saturating_mul(buyer_share_bps as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            let seller_interest = interest.saturating_sub(buyer_interest);
+            for (to, amount) in [
+                (escrow.buyer, buyer_interest),
+                (escrow.seller, seller_interest),
+            ] {
+                if amount > 0 {
+                    self.env()
+                        .transfer(to, amount)
+                        .map_err(|_| Error::EscrowTransferFailed)?;
+                }
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowYieldSettled {
+                escrow_id: escrow.id,
+                event_version: 1,
+                principal,
+                buyer_interest,
+                seller_interest,
+                loss: loss.total(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(loss)
+        }
+
+        /// Sets the platform fee charged on escrow release, in basis points (admin only)
+        #[ink(message)]
+        pub fn set_escrow_fee_bps(&mut self, fee_bps: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if fee_bps > 10_000 {
+                return Err(Error::InvalidEscrowFee);
+            }
+            self.escrow_fee_bps = fee_bps;
+            Ok(())
+        }
+
+        /// Accepts or withdraws a settlement asset for new escrows (admin only)
+        /// Existing escrows keep settling in their recorded asset.
+        #[ink(message)]
+        pub fn set_escrow_asset_accepted(
+            &mut self,
+            asset: EscrowAsset,
+            accepted: bool,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.accepted_escrow_assets.retain(|a| *a != asset);
+            if accepted {
+                self.accepted_escrow_assets.push(asset);
+            }
+            Ok(())
+        }
+
+        /// Gets the settlement assets new escrows may be opened in
+        #[ink(message)]
+        pub fn get_accepted_escrow_assets(&self) -> Vec<EscrowAsset> {
+            self.accepted_escrow_assets.clone()
+        }
+
+        /// Returns true if new escrows may be opened in the asset
+        #[ink(message)]
+        pub fn is_escrow_asset_accepted(&self, asset: EscrowAsset) -> bool {
+            self.accepted_escrow_assets.contains(&asset)
+        }
+
+        /// Helper: rejects escrows in settlement assets that are not whitelisted
+        fn ensure_escrow_asset_accepted(&self, asset: EscrowAsset) -> Result<(), Error> {
+            if !self.is_escrow_asset_accepted(asset) {
+                return Err(Error::UnsupportedEscrowAsset);
+            }
+            Ok(())
+        }
+
+        /// Gets the platform fee charged on escrow release, in basis points
+        #[ink(message)]
+        pub fn get_escrow_fee_bps(&self) -> u32 {
+            self.escrow_fee_bps
+        }
+
+        /// Sets the platform fee charged on a type of sale, in basis points (admin only)
+        /// The escrow fee is the same setting as `set_escrow_fee_bps`.
+        #[ink(message)]
+        pub fn set_marketplace_fee_bps(
+            &mut self,
+            sale_type: SaleType,
+            fee_bps: u32,
+        ) -> Result<(), Error> {
+            if sale_type == SaleType::Escrow {
+                return self.set_escrow_fee_bps(fee_bps);
+            }
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if fee_bps > 10_000 {
+                return Err(Error::InvalidEscrowFee);
+            }
+            self.marketplace_fee_bps.insert(sale_type, &fee_bps);
+            Ok(())
+        }
+
+        /// Gets the platform fee charged on a type of sale, in basis points
+        #[ink(message)]
+        pub fn get_marketplace_fee_bps(&self, sale_type: SaleType) -> u32 {
+            match sale_type {
+                SaleType::Escrow => self.escrow_fee_bps,
+                _ => self.marketplace_fee_bps.get(sale_type).unwrap_or(0),
+            }
+        }
+
+        /// Sets the account that receives withdrawn platform fees (admin only)
+        #[ink(message)]
+        pub fn set_treasury(&mut self, treasury: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.treasury = treasury;
+            Ok(())
+        }
+
+        /// Gets the account that receives withdrawn platform fees
+        #[ink(message)]
+        pub fn get_treasury(&self) -> AccountId {
+            self.treasury
+        }
+
+        /// Gets the platform fees accumulated and not yet withdrawn
+        #[ink(message)]
+        pub fn treasury_balance(&self) -> u128 {
+            self.treasury_balance
+        }
+
+        /// Sends all accumulated platform fees to the treasury account (admin only)
+        #[ink(message)]
+        pub fn withdraw_fees(&mut self) -> Result<u128, Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let amount = self.treasury_balance;
+            if amount == 0 {
+                return Err(Error::NoFeesToWithdraw);
+            }
+
+            self.treasury_balance = 0;
+            self.env()
+                .transfer(self.treasury, amount)
+                .map_err(|_| Error::EscrowTransferFailed)?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(FeesWithdrawn {
+                treasury: self.treasury,
+                event_version: 1,
+                amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                withdrawn_by: caller,
+            });
+
+            Ok(amount)
+        }
+
+        /// Helper: the whole title cannot be sold, escrowed or transferred once a property is
+        /// fractionalized, since shareholders hold a stake in it, or while a lien encumbers it
+        fn ensure_whole_title_transferable(&self, property_id: u64) -> Result<(), Error> {
+            if self.is_fractional(property_id) {
+                return Err(Error::PropertyFractionalized);
+            }
+            self.ensure_unencumbered(property_id)
+        }
+
+        /// Helper: rejects transfers of a property while a lien secures unpaid debt on it
+        fn ensure_unencumbered(&self, property_id: u64) -> Result<(), Error> {
+            if self.property_liens.contains(property_id) {
+                return Err(Error::PropertyEncumbered);
+            }
+            Ok(())
+        }
+
+        /// Bars a buyer from opening escrows until `until` (admin only)
+        /// Passing a timestamp in the past lifts the bar.
+        #[ink(message)]
+        pub fn bar_buyer(&mut self, buyer: AccountId, until: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if until <= self.env().block_timestamp() {
+                self.barred_buyers.remove(buyer);
+                return Ok(());
+            }
+            let defaults = self.get_buyer_defaults(buyer);
+            self.apply_buyer_bar(buyer, until, defaults, Some(caller));
+            Ok(())
+        }
+
+        /// Sets the automatic penalty for defaulting buyers (admin only)
+        /// After `threshold` expired or dispute-refunded escrows a buyer is barred for
+        /// `bar_period`; a threshold of 0 leaves barring to the admin.
+        #[ink(message)]
+        pub fn set_buyer_default_policy(
+            &mut self,
+            threshold: u32,
+            bar_period: u64,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.buyer_default_threshold = threshold;
+            self.buyer_bar_period = bar_period;
+            Ok(())
+        }
+
+        /// Gets the automatic penalty policy as (threshold, bar period)
+        #[ink(message)]
+        pub fn get_buyer_default_policy(&self) -> (u32, u64) {
+            (self.buyer_default_threshold, self.buyer_bar_period)
+        }
+
+        /// Gets the defaults recorded against a buyer since they were last barred
+        #[ink(message)]
+        pub fn get_buyer_defaults(&self, buyer: AccountId) -> u32 {
+            self.buyer_defaults.get(buyer).unwrap_or(0)
+        }
+
+        /// Gets the end of a buyer's cooldown if they are currently barred
+        #[ink(message)]
+        pub fn get_buyer_barred_until(&self, buyer: AccountId) -> Option<u64> {
+            self.barred_buyers
+                .get(buyer)
+                .filter(|until| *until > self.env().block_timestamp())
+        }
+
+        /// Helper: rejects buyers serving a default cooldown
+        fn ensure_buyer_not_barred(&self, buyer: AccountId) -> Result<(), Error> {
+            if self.get_buyer_barred_until(buyer).is_some() {
+                return Err(Error::BuyerBarred);
+            }
+            Ok(())
+        }
+
+        /// Helper: counts a default against a buyer, barring them once the threshold is reached
+        fn record_buyer_default(&mut self, buyer: AccountId) {
+            let defaults = self.get_buyer_defaults(buyer).saturating_add(1);
+            if self.buyer_default_threshold > 0 && defaults >= self.buyer_default_threshold {
+                let until = self
+                    .env()
+                    .block_timestamp()
+                    .saturating_add(self.buyer_bar_period);
+                self.apply_buyer_bar(buyer, until, defaults, None);
+            } else {
+                self.buyer_defaults.insert(buyer, &defaults);
+            }
+        }
+
+        /// Helper: bars a buyer and resets their default count
+        fn apply_buyer_bar(
+            &mut self,
+            buyer: AccountId,
+            until: u64,
+            defaults: u32,
+            barred_by: Option<AccountId>,
+        ) {
+            self.barred_buyers.insert(buyer, &until);
+            self.buyer_defaults.remove(buyer);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BuyerBarred {
+                buyer,
+                event_version: 1,
+                barred_until: until,
+                defaults,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                barred_by,
+            });
+        }
+
+        /// Sets the minimum escrow amount as a share of the property's valuation (admin only)
+        /// Guards high-value properties against nuisance escrows; 0 disables the check.
+        #[ink(message)]
+        pub fn set_escrow_min_valuation_bps(&mut self, min_bps: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if min_bps > 10_000 {
+                return Err(Error::InvalidBasisPoints);
+            }
+            self.escrow_min_valuation_bps = min_bps;
+            Ok(())
+        }
+
+        /// Gets the minimum escrow amount as a share of the property's valuation
+        #[ink(message)]
+        pub fn get_escrow_min_valuation_bps(&self) -> u32 {
+            self.escrow_min_valuation_bps
+        }
+
+        /// Helper: rejects escrow amounts below the configured share of the recorded valuation
+        fn ensure_escrow_amount_allowed(
+            &self,
+            property: &PropertyInfo,
+            amount: u128,
+        ) -> Result<(), Error> {
+            let minimum = property
+                .metadata
+                .valuation
+                .saturating_mul(self.escrow_min_valuation_bps as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            if amount < minimum {
+                return Err(Error::EscrowAmountBelowMinimum);
+            }
+            Ok(())
+        }
+
+        /// Helper: finds the active listing a new escrow must honour, if the property has one
+        /// The escrow has to be in the listing's asset, for at least its price and, on a
+        /// private listing, for its named buyer.
+        fn escrow_listing(
+            &self,
+            property_id: u64,
+            buyer: AccountId,
+            amount: u128,
+            asset: EscrowAsset,
+        ) -> Result<Option<u64>, Error> {
+            let listing = match self.get_property_listing(property_id) {
+                Some(listing)
+                    if listing.status == ListingStatus::Active
+                        && !self.has_lapsed(listing.expires_at) =>
+                {
+                    listing
+                }
+                _ => return Ok(None),
+            };
+            if listing.asset != asset || amount < listing.price {
+                return Err(Error::EscrowBelowListingPrice);
+            }
+            if listing
+                .private_buyer
+                .is_some_and(|private_buyer| private_buyer != buyer)
+            {
+                return Err(Error::Unauthorized);
+            }
+            Ok(Some(listing.id))
+        }
+
+        /// Helper: marks the listing an escrow was bound to as sold to the escrow's buyer
+        fn close_escrowed_listing(&mut self, escrow: &EscrowInfo, price: u128) {
+            let Some(mut listing) = escrow
+                .listing_id
+                .and_then(|listing_id| self.listings.get(listing_id))
+            else {
+                return;
+            };
+            if listing.status != ListingStatus::Active
+                || listing.seller != escrow.seller
+                || listing
+                    .private_buyer
+                    .is_some_and(|private_buyer| private_buyer != escrow.buyer)
+            {
+                return;
+            }
+            listing.status = ListingStatus::Sold;
+            listing.buyer = Some(escrow.buyer);
+            self.listings.insert(listing.id, &listing);
+            if self.property_listings.get(listing.property_id) == Some(listing.id) {
+                self.clear_listing_index(listing.property_id);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ListingPurchased {
+                listing_id: listing.id,
+                property_id: listing.property_id,
+                buyer: escrow.buyer,
+                seller: listing.seller,
+                event_version: 1,
+                price,
+                private: listing.private_buyer.is_some(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+        }
+
+        /// Helper: allocates the next escrow ID from the persistent counter
+        fn next_escrow_id(&mut self) -> u64 {
+            self.escrow_count += 1;
+            self.escrow_count
+        }
+
+        /// Helper: opens a new escrow under the next ID and emits its creation events
+        /// A seller-initiated escrow starts out accepted. A buyer-initiated one holds the
+        /// `deposit` paid with it, and once funds are in the seller has until the acceptance
+        /// deadline to accept. Returns the new escrow ID.
+        fn open_new_escrow(
+            &mut self,
+            mut escrow: EscrowInfo,
+            deposit: u128,
+            seller_initiated: bool,
+        ) -> u64 {
+            let escrow_id = self.next_escrow_id();
+            let now = self.env().block_timestamp();
+            escrow.id = escrow_id;
+            escrow.funded_amount = deposit;
+            if seller_initiated {
+                escrow.seller_accepted = true;
+                escrow.accepted_at = Some(now);
+            } else if deposit > 0 {
+                escrow.acceptance_deadline = self.acceptance_deadline();
+            }
+            let fully_funded = deposit >= escrow.amount;
+            if fully_funded {
+                escrow.state = EscrowState::Funded;
+            }
+
+            self.store_new_escrow(&escrow);
+            if escrow.asset == EscrowAsset::Native {
+                self.total_escrowed = self.total_escrowed.saturating_add(deposit);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(EscrowCreated {
+                escrow_id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                seller: escrow.seller,
+                event_version: 1,
+                amount: escrow.amount,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            if deposit > 0 {
+                self.env().emit_event(EscrowFunded {
+                    escrow_id,
+                    buyer: escrow.buyer,
+                    event_version: 1,
+                    amount: deposit,
+                    funded_amount: deposit,
+                    fully_funded,
+                    timestamp: now,
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+
+            escrow_id
+        }
+
+        /// Helper: stores a newly created escrow and adds it to the lookup indexes
+        fn store_new_escrow(&mut self, escrow: &EscrowInfo) {
+            self.escrows.insert(escrow.id, escrow);
+
+            let position = self.get_escrow_count_by_property(escrow.property_id);
+            self.property_escrows
+                .insert((escrow.property_id, position), &escrow.id);
+            self.property_escrow_count
+                .insert(escrow.property_id, &(position + 1));
+
+            let position = self.get_escrow_count_by_buyer(escrow.buyer);
+            self.buyer_escrows
+                .insert((escrow.buyer, position), &escrow.id);
+            self.buyer_escrow_count
+                .insert(escrow.buyer, &(position + 1));
+
+            let position = self.get_escrow_count_by_seller(escrow.seller);
+            self.seller_escrows
+                .insert((escrow.seller, position), &escrow.id);
+            self.seller_escrow_count
+                .insert(escrow.seller, &(position + 1));
+        }
+
+        /// Helper: loads one page of escrows from an index, capped at `MAX_ESCROW_PAGE_SIZE`
+        /// `id_at` maps a position in the index to the escrow ID stored there.
+        fn escrow_page(
+            &self,
+            count: u32,
+            offset: u32,
+            limit: u32,
+            id_at: impl Fn(u32) -> Option<u64>,
+        ) -> Vec<EscrowInfo> {
+            let end = offset
+                .saturating_add(limit.min(MAX_ESCROW_PAGE_SIZE))
+                .min(count);
+            (offset..end)
+                .filter_map(id_at)
+                .filter_map(|id| self.escrows.get(id))
+                .collect()
+        }
+
+        /// Helper: moves an escrowed property from the seller to the buyer
+        /// Skips the caller-ownership check of `transfer_property`: the escrow is the
+        /// authorization, so only the seller's continued ownership is checked. Callers screen
+        /// the buyer for the release beforehand.
+        fn transfer_escrowed_title(
+            &mut self,
+            escrow: &EscrowInfo,
+            by: AccountId,
+        ) -> Result<(), Error> {
+            // Share-block escrows move the reserved shares instead of the title
+            if let Some(shares) = self.escrow_shares.get(escrow.id) {
+                return self.move_shares_with(
+                    escrow.property_id,
+                    escrow.seller,
+                    escrow.buyer,
+                    shares,
+                    Some(escrow.id),
+                );
+            }
+            let mut property = self
+                .properties
+                .get(escrow.property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if property.owner != escrow.seller {
+                return Err(Error::EscrowSellerNotOwner);
+            }
+            self.ensure_whole_title_transferable(escrow.property_id)?;
+            self.move_property(&mut property, escrow.buyer, by);
+            Ok(())
+        }
+
+        /// Helper: rejects changes to escrows that have settled or are frozen by a dispute
+        fn ensure_escrow_active(escrow: &EscrowInfo) -> Result<(), Error> {
+            match escrow.state {
+                EscrowState::Created | EscrowState::Funded => Ok(()),
+                EscrowState::Disputed => Err(Error::EscrowDisputed),
+                EscrowState::Released => Err(Error::EscrowAlreadyReleased),
+                EscrowState::Refunded | EscrowState::Expired => Err(Error::EscrowClosed),
+            }
+        }
+
+        /// Helper: moves an escrow to `next`, rejecting transitions the state machine forbids
+        fn transition_escrow(escrow: &mut EscrowInfo, next: EscrowState) -> Result<(), Error> {
+            if !escrow.state.can_transition_to(next) {
+                return Err(Error::InvalidEscrowTransition);
+            }
+            escrow.state = next;
+            Ok(())
+        }
+
+        /// Helper: pays out escrowed funds in the escrow's asset
+        /// Native payouts are also released from the `total_escrowed` lock total.
+        fn pay_out_escrow(
+            &mut self,
+            asset: EscrowAsset,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            self.transfer_asset(asset, to, amount)?;
+            if asset == EscrowAsset::Native {
+                self.total_escrowed = self.total_escrowed.saturating_sub(amount);
+            }
+            Ok(())
+        }
+
+        /// Helper: pays `amount` of an asset held by the contract to `to`
+        fn transfer_asset(
+            &mut self,
+            asset: EscrowAsset,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            if amount == 0 {
+                return Ok(());
+            }
+            match asset {
+                EscrowAsset::Native => self
+                    .env()
+                    .transfer(to, amount)
+                    .map_err(|_| Error::EscrowTransferFailed),
+                EscrowAsset::Psp22(token) => {
+                    use ink::env::call::FromAccountId;
+                    let mut token: ink::contract_ref!(PSP22) =
+                        FromAccountId::from_account_id(token);
+                    token
+                        .transfer(to, amount, Vec::new())
+                        .map_err(|_| Error::EscrowTransferFailed)
+                }
+            }
+        }
+
+        /// Helper: pulls `amount` of a PSP22 token from `from` into the contract
+        /// `from` must have approved the contract for at least `amount` beforehand.
+        fn pull_psp22(
+            &mut self,
+            token: AccountId,
+            from: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            use ink::env::call::FromAccountId;
+            let mut psp22: ink::contract_ref!(PSP22) = FromAccountId::from_account_id(token);
+            psp22
+                .transfer_from(from, self.env().account_id(), amount, Vec::new())
+                .map_err(|_| Error::EscrowTransferFailed)
+        }
+
+        /// Helper: an escrow deadline, when given, must lie in the future
+        fn validate_escrow_deadline(&self, deadline: Option<u64>) -> Result<(), Error> {
+            if let Some(deadline) = deadline {
+                if deadline <= self.env().block_timestamp() {
+                    return Err(Error::InvalidEscrowDeadline);
+                }
+            }
+            Ok(())
+        }
+
+        /// Gets escrow information
+        #[ink(message)]
+        pub fn get_escrow(&self, escrow_id: u64) -> Option<EscrowInfo> {
+            self.escrows.get(escrow_id)
+        }
+
+        /// Gets the number of escrows created so far (also the highest escrow ID)
+        #[ink(message)]
+        pub fn escrow_count(&self) -> u64 {
+            self.escrow_count
+        }
+
+        /// Gets a page of escrows opened against a property, oldest first
+        #[ink(message)]
+        pub fn get_escrows_by_property(
+            &self,
+            property_id: u64,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<EscrowInfo> {
+            self.escrow_page(
+                self.get_escrow_count_by_property(property_id),
+                offset,
+                limit,
+                |position| self.property_escrows.get((property_id, position)),
+            )
+        }
+
+        /// Gets the number of escrows ever opened against a property
+        #[ink(message)]
+        pub fn get_escrow_count_by_property(&self, property_id: u64) -> u32 {
+            self.property_escrow_count.get(property_id).unwrap_or(0)
+        }
+
+        /// Gets a page of escrows where the account is the buyer, oldest first
+        #[ink(message)]
+        pub fn get_escrows_by_buyer(
+            &self,
+            buyer: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<EscrowInfo> {
+            self.escrow_page(
+                self.get_escrow_count_by_buyer(buyer),
+                offset,
+                limit,
+                |position| self.buyer_escrows.get((buyer, position)),
+            )
+        }
+
+        /// Gets the number of escrows where the account is the buyer
+        #[ink(message)]
+        pub fn get_escrow_count_by_buyer(&self, buyer: AccountId) -> u32 {
+            self.buyer_escrow_count.get(buyer).unwrap_or(0)
+        }
+
+        /// Gets a page of escrows where the account is the seller, oldest first
+        #[ink(message)]
+        pub fn get_escrows_by_seller(
+            &self,
+            seller: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<EscrowInfo> {
+            self.escrow_page(
+                self.get_escrow_count_by_seller(seller),
+                offset,
+                limit,
+                |position| self.seller_escrows.get((seller, position)),
+            )
+        }
+
+        /// Gets the number of escrows where the account is the seller
+        #[ink(message)]
+        pub fn get_escrow_count_by_seller(&self, seller: AccountId) -> u32 {
+            self.seller_escrow_count.get(seller).unwrap_or(0)
+        }
+
+        /// Gets a sale receipt by ID
+        #[ink(message)]
+        pub fn get_sale_record(&self, sale_id: u64) -> Option<SaleRecord> {
+            self.sale_records.get(sale_id)
+        }
+
+        /// Gets a page of a property's sale receipts, oldest first
+        #[ink(message)]
+        pub fn get_property_sales(
+            &self,
+            property_id: u64,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<SaleRecord> {
+            self.property_sales
+                .get(property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit.min(MAX_ESCROW_PAGE_SIZE) as usize)
+                .filter_map(|id| self.sale_records.get(id))
+                .collect()
+        }
+
+        /// Gets the number of sale receipts recorded so far
+        #[ink(message)]
+        pub fn sale_count(&self) -> u64 {
+            self.sale_count
+        }
+
+        /// Helper: records the receipt of a settled escrow
+        fn record_sale(&mut self, escrow: &EscrowInfo, price: u128) {
+            self.store_sale(SaleRecord {
+                id: 0,
+                sale_type: SaleType::Escrow,
+                source_id: escrow.id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                seller: escrow.seller,
+                price,
+                asset: escrow.asset,
+                terms_hash: escrow.terms_hash,
+                timestamp: self.env().block_timestamp(),
+            });
+        }
+
+        /// Helper: assigns a sale receipt its ID and appends it to the property's history,
+        /// dropping the oldest entry from the index once the history is full
+        fn store_sale(&mut self, mut record: SaleRecord) {
+            self.sale_count += 1;
+            record.id = self.sale_count;
+            self.sale_records.insert(record.id, &record);
+            let mut sales = self
+                .property_sales
+                .get(record.property_id)
+                .unwrap_or_default();
+            if sales.len() as u32 >= MAX_SALE_HISTORY {
+                sales.remove(0);
+            }
+            sales.push(record.id);
+            self.property_sales.insert(record.property_id, &sales);
+
+            if record.asset == EscrowAsset::Native {
+                self.total_sales_volume = self.total_sales_volume.saturating_add(record.price);
+            }
+            let seller_sales = self.get_seller_sales_count(record.seller);
+            self.seller_sale_counts
+                .insert(record.seller, &(seller_sales + 1));
+        }
+
+        /// Gets a property's recent sale prices as (timestamp, price) pairs, oldest first
+        #[ink(message)]
+        pub fn get_price_history(&self, property_id: u64) -> Vec<(u64, u128)> {
+            self.property_sales
+                .get(property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|id| self.sale_records.get(id))
+                .map(|sale| (sale.timestamp, sale.price))
+                .collect()
+        }
+
+        /// Returns true once an escrow's deposits cover the agreed amount
+        #[ink(message)]
+        pub fn is_escrow_funded(&self, escrow_id: u64) -> bool {
+            self.escrows
+                .get(escrow_id)
+                .map(|escrow| escrow.funded_amount >= escrow.amount)
+                .unwrap_or(false)
+        }
+
+        /// Gets the total native balance locked across all escrows
+        #[ink(message)]
+        pub fn total_escrowed(&self) -> u128 {
+            self.total_escrowed
+        }
+
+        /// Portfolio Management: Gets summary statistics for properties owned by an account
+        #[ink(message)]
+        pub fn get_portfolio_summary(&self, owner: AccountId) -> PortfolioSummary {
+            let property_ids = self.owner_properties.get(owner).unwrap_or_default();
+            let mut total_valuation = 0u128;
+            let mut total_size = 0u64;
+            let mut property_count = 0u64;
+
+            // Optimized loop with iterator for better performance
+            let iter = property_ids.iter();
+            for &property_id in iter {
+                if let Some(property) = self.properties.get(property_id) {
+                    // Unrolled additions for better performance
+                    total_valuation = total_valuation.wrapping_add(property.metadata.valuation);
+                    total_size = total_size.wrapping_add(property.metadata.size);
+                    property_count += 1;
+                }
+            }
+
+            PortfolioSummary {
+                property_count,
+                total_valuation,
+                average_valuation: if property_count > 0 {
+                    total_valuation
+                        .checked_div(property_count as u128)
+                        .unwrap_or(0)
+                } else {
+                    0
+                },
+                total_size,
+                average_size: if property_count > 0 {
+                    total_size.checked_div(property_count).unwrap_or(0)
+                } else {
+                    0
+                },
+            }
+        }
+
+        /// Portfolio Management: Gets detailed portfolio information for an owner
+        #[ink(message)]
+        pub fn get_portfolio_details(&self, owner: AccountId) -> PortfolioDetails {
+            let property_ids = self.owner_properties.get(owner).unwrap_or_default();
+            let mut properties = Vec::with_capacity(property_ids.len());
+
+            let iter = property_ids.iter();
+            for &property_id in iter {
+                if let Some(property) = self.properties.get(property_id) {
+                    // Direct construction to avoid intermediate allocations
+                    let portfolio_property = PortfolioProperty {
+                        id: property.id,
+                        location: property.metadata.location.clone(),
+                        size: property.metadata.size,
+                        valuation: property.metadata.valuation,
+                        registered_at: property.registered_at,
+                    };
+                    properties.push(portfolio_property);
+                }
+            }
+
+            PortfolioDetails {
+                owner,
+                total_count: properties.len() as u64,
+                properties,
+            }
+        }
+
+        /// Analytics: Gets aggregated statistics across all properties
+        /// WARNING: This is expensive for large datasets. Consider off-chain indexing.
+        #[ink(message)]
+        pub fn get_global_analytics(&self) -> GlobalAnalytics {
+            let mut total_valuation = 0u128;
+            let mut total_size = 0u64;
+            let mut property_count = 0u64;
+            let mut owners = Vec::new();
+
+            // Optimized loop with early termination possibility
+            // Note: This is expensive for large datasets. Consider off-chain indexing.
+            let mut i = 1u64;
+            while i <= self.property_count {
+                if let Some(property) = self.properties.get(i) {
+                    total_valuation += property.metadata.valuation;
+                    total_size += property.metadata.size;
+                    property_count += 1;
+
+                    // Add owner if not already in list (manual deduplication)
+                    if !owners.contains(&property.owner) {
+                        owners.push(property.owner);
+                    }
+                }
+                i += 1;
+            }
+
+            GlobalAnalytics {
+                total_properties: property_count,
+                total_valuation,
+                average_valuation: if property_count > 0 {
+                    total_valuation
+                        .checked_div(property_count as u128)
+                        .unwrap_or(0)
+                } else {
+                    0
+                },
+                total_size,
+                average_size: if property_count > 0 {
+                    total_size.checked_div(property_count).unwrap_or(0)
+                } else {
+                    0
+                },
+                unique_owners: owners.len() as u64,
+            }
+        }
+
+        /// Analytics: Gets properties within a price range
+        #[ink(message)]
+        pub fn get_properties_by_price_range(&self, min_price: u128, max_price: u128) -> Vec<u64> {
+            let mut result = Vec::new();
+
+            // Optimized loop with pre-check to reduce iterations
+            let mut i = 1u64;
+            while i <= self.property_count {
+                if let Some(property) = self.properties.get(i) {
+                    // Unrolled condition check for better performance
+                    let valuation = property.metadata.valuation;
+                    if valuation >= min_price && valuation <= max_price {
+                        result.push(property.id);
+                    }
+                }
+                i += 1;
+            }
+
+            result
+        }
+
+        /// Analytics: Gets properties by size range
+        #[ink(message)]
+        pub fn get_properties_by_size_range(&self, min_size: u64, max_size: u64) -> Vec<u64> {
+            let mut result = Vec::new();
+
+            // Optimized loop with pre-check to reduce iterations
+            let mut i = 1u64;
+            while i <= self.property_count {
+                if let Some(property) = self.properties.get(i) {
+                    // Unrolled condition check for better performance
+                    let size = property.metadata.size;
+                    if size >= min_size && size <= max_size {
+                        result.push(property.id);
+                    }
+                }
+                i += 1;
+            }
+
+            result
+        }
+
+        /// Helper method to track gas usage
+        fn track_gas_usage(&mut self, _operation: &[u8]) {
+            // In a real implementation, this would measure actual gas consumption
+            // For demonstration purposes, we increment counters
+            let gas_used = 10000; // Placeholder value
+            self.gas_tracker.operation_count += 1;
+            self.gas_tracker.last_operation_gas = gas_used;
+            self.gas_tracker.total_gas_used += gas_used;
+
+            // Track min/max gas usage
+            if gas_used < self.gas_tracker.min_gas_used {
+                self.gas_tracker.min_gas_used = gas_used;
+            }
+            if gas_used > self.gas_tracker.max_gas_used {
+                self.gas_tracker.max_gas_used = gas_used;
+            }
+        }
+
+        /// Gas Monitoring: Tracks gas usage for operations
+        #[ink(message)]
+        pub fn get_gas_metrics(&self) -> GasMetrics {
+            GasMetrics {
+                last_operation_gas: self.gas_tracker.last_operation_gas,
+                average_operation_gas: if self.gas_tracker.operation_count > 0 {
+                    self.gas_tracker
+                        .total_gas_used
+                        .checked_div(self.gas_tracker.operation_count)
+                        .unwrap_or(0)
+                } else {
+                    0
+                },
+                total_operations: self.gas_tracker.operation_count,
+                min_gas_used: if self.gas_tracker.min_gas_used == u64::MAX {
+                    0
+                } else {
+                    self.gas_tracker.min_gas_used
+                },
+                max_gas_used: self.gas_tracker.max_gas_used,
+            }
+        }
+
+        /// Performance Monitoring: Gets optimization recommendations
+        #[ink(message)]
+        pub fn get_performance_recommendations(&self) -> Vec<String> {
+            let mut recommendations = Vec::new();
+
+            // Check for high gas usage operations
+            let avg_gas = if self.gas_tracker.operation_count > 0 {
+                self.gas_tracker
+                    .total_gas_used
+                    .checked_div(self.gas_tracker.operation_count)
+                    .unwrap_or(0)
+            } else {
+                0
+            };
+            if avg_gas > 50000 {
+                recommendations
+                    .push("Consider using batch operations for multiple properties".to_string());
+            }
+
+            // Check for many small operations
+            if self.gas_tracker.operation_count > 100 && avg_gas < 10000 {
+                recommendations.push(
+                    "Operations are efficient but consider consolidating related operations"
+                        .to_string(),
+                );
+            }
+
+            // Check for inconsistent gas usage
+            if self.gas_tracker.max_gas_used > self.gas_tracker.min_gas_used * 10 {
+                recommendations
+                    .push("Gas usage varies significantly - review operation patterns".to_string());
+            }
+
+            // General recommendations
+            recommendations
+                .push("Use batch operations for multiple property transfers".to_string());
+            recommendations
+                .push("Prefer portfolio analytics over individual property queries".to_string());
+            recommendations.push("Consider off-chain indexing for complex analytics".to_string());
+
+            recommendations
+        }
+
+        // ============================================================================
+        // BADGE MANAGEMENT SYSTEM
+        // ============================================================================
+
+        /// Adds or removes a badge verifier (admin only)
+        #[ink(message)]
+        pub fn set_verifier(&mut self, verifier: AccountId, authorized: bool) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.badge_verifiers.insert(verifier, &authorized);
+
+            // Emit verifier updated event
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(VerifierUpdated {
+                verifier,
+                authorized,
+                updated_by: caller,
+                event_version: 1,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
+        /// Checks if an account is an authorized verifier
+        #[ink(message)]
+        pub fn is_verifier(&self, account: AccountId) -> bool {
+            self.badge_verifiers.get(account).unwrap_or(false)
+        }
+
+        /// Issues a badge to a property (verifier only)
+        #[ink(message)]
+        pub fn issue_badge(
+            &mut self,
+            property_id: u64,
+            badge_type: BadgeType,
+            expires_at: Option<u64>,
+            metadata_url: String,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Only verifiers can issue badges
+            if !self.is_verifier(caller) && caller != self.admin {
+                return Err(Error::NotVerifier);
+            }
+
+            // Check if property exists
+            self.properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            // Check if badge already exists and is not revoked
+            if let Some(existing_badge) = self.property_badges.get((property_id, badge_type)) {
+                if !existing_badge.revoked {
+                    return Err(Error::BadgeAlreadyIssued);
+                }
+            }
+
+            let badge = Badge {
+                badge_type,
+                issued_at: self.env().block_timestamp(),
+                issued_by: caller,
+                expires_at,
+                metadata_url: metadata_url.clone(),
+                revoked: false,
+                revoked_at: None,
+                revocation_reason: String::new(),
+            };
+
+            self.property_badges
+                .insert((property_id, badge_type), &badge);
+
+            // Emit badge issued event
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(BadgeIssued {
+                property_id,
+                badge_type,
+                issued_by: caller,
+                event_version: 1,
+                expires_at,
+                metadata_url,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
+        /// Revokes a badge from a property (verifier or admin only)
+        #[ink(message)]
+        pub fn revoke_badge(
+            &mut self,
+            property_id: u64,
+            badge_type: BadgeType,
+            reason: String,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            // Only verifiers or admin can revoke badges
+            if !self.is_verifier(caller) && caller != self.admin {
+                return Err(Error::NotVerifier);
+            }
+
+            let mut badge = self
+                .property_badges
+                .get((property_id, badge_type))
+                .ok_or(Error::BadgeNotFound)?;
+
+            if badge.revoked {
+                return Err(Error::BadgeNotFound);
+            }
+
+            badge.revoked = true;
+            badge.revoked_at = Some(self.env().block_timestamp());
+            badge.revocation_reason = reason.clone();
+
+            self.property_badges
+                .insert((property_id, badge_type), &badge);
+
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(BadgeRevoked {
+                property_id,
+                badge_type,
+                revoked_by: caller,
+                event_version: 1,
+                reason,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn request_verification(
+            &mut self,
+            property_id: u64,
+            badge_type: BadgeType,
+            evidence_url: String,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            self.verification_count += 1;
+            let request_id = self.verification_count;
+
+            let request = VerificationRequest {
+                id: request_id,
+                property_id,
+                badge_type,
+                requester: caller,
+                requested_at: self.env().block_timestamp(),
+                evidence_url: evidence_url.clone(),
+                status: VerificationStatus::Pending,
+                reviewed_by: None,
+                reviewed_at: None,
+            };
+
+            self.verification_requests.insert(request_id, &request);
+
+            // Emit verification requested event
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(VerificationRequested {
+                request_id,
+                property_id,
+                badge_type,
+                requester: caller,
+                event_version: 1,
+                evidence_url,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(request_id)
+        }
+
+        #[ink(message)]
+        pub fn review_verification(
+            &mut self,
+            request_id: u64,
+            approved: bool,
+            expires_at: Option<u64>,
+            metadata_url: String,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            if !self.is_verifier(caller) && caller != self.admin {
+                return Err(Error::NotVerifier);
+            }
+
+            let mut request = self
+                .verification_requests
+                .get(request_id)
+                .ok_or(Error::BadgeNotFound)?;
+
+            request.status = if approved {
+                VerificationStatus::Approved
+            } else {
+                VerificationStatus::Rejected
+            };
+            request.reviewed_by = Some(caller);
+            request.reviewed_at = Some(self.env().block_timestamp());
+
+            self.verification_requests.insert(request_id, &request);
+
+            if approved {
+                self.issue_badge(
+                    request.property_id,
+                    request.badge_type,
+                    expires_at,
+                    metadata_url,
+                )?;
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(VerificationReviewed {
+                request_id,
+                property_id: request.property_id,
+                reviewer: caller,
+                approved,
+                event_version: 1,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn submit_appeal(
+            &mut self,
+            property_id: u64,
+            badge_type: BadgeType,
+            reason: String,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            let badge = self
+                .property_badges
+                .get((property_id, badge_type))
+                .ok_or(Error::BadgeNotFound)?;
+
+            if !badge.revoked {
+                return Err(Error::InvalidAppealStatus);
+            }
+
+            self.appeal_count += 1;
+            let appeal_id = self.appeal_count;
+
+            let appeal = Appeal {
+                id: appeal_id,
+                property_id,
+                badge_type,
+                appellant: caller,
+                reason: reason.clone(),
+                submitted_at: self.env().block_timestamp(),
+                status: AppealStatus::Pending,
+                resolved_by: None,
+                resolved_at: None,
+                resolution: String::new(),
+            };
+
+            self.appeals.insert(appeal_id, &appeal);
+
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(AppealSubmitted {
+                appeal_id,
+                property_id,
+                badge_type,
+                appellant: caller,
+                event_version: 1,
+                reason,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(appeal_id)
+        }
+
+        #[ink(message)]
+        pub fn resolve_appeal(
+            &mut self,
+            appeal_id: u64,
+            approved: bool,
+            resolution: String,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut appeal = self.appeals.get(appeal_id).ok_or(Error::AppealNotFound)?;
+
+            appeal.status = if approved {
+                AppealStatus::Approved
+            } else {
+                AppealStatus::Rejected
+            };
+            appeal.resolved_by = Some(caller);
+            appeal.resolved_at = Some(self.env().block_timestamp());
+            appeal.resolution = resolution.clone();
+
+            self.appeals.insert(appeal_id, &appeal);
+
+            // If approved, reinstate the badge
+            if approved {
+                if let Some(mut badge) = self
+                    .property_badges
+                    .get((appeal.property_id, appeal.badge_type))
+                {
+                    badge.revoked = false;
+                    badge.revoked_at = None;
+                    badge.revocation_reason = String::new();
+                    self.property_badges
+                        .insert((appeal.property_id, appeal.badge_type), &badge);
+                }
+            }
+
+            // Emit appeal resolved event
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+            self.env().emit_event(AppealResolved {
+                appeal_id,
+                property_id: appeal.property_id,
+                resolved_by: caller,
+                approved,
+                event_version: 1,
+                resolution,
+                timestamp,
+                block_number,
+                transaction_hash: [0u8; 32].into(),
+            });
+
+            Ok(())
+        }
+
+        /// Gets all badges for a property
+        #[ink(message)]
+        pub fn get_property_badges(&self, property_id: u64) -> Vec<(BadgeType, Badge)> {
+            let mut badges = Vec::new();
+
+            // Check all badge types
+            let badge_types = [
+                BadgeType::OwnerVerification,
+                BadgeType::DocumentVerification,
+                BadgeType::LegalCompliance,
+                BadgeType::PremiumListing,
+            ];
+
+            for badge_type in badge_types.iter() {
+                if let Some(badge) = self.property_badges.get((property_id, *badge_type)) {
+                    if !badge.revoked {
+                        badges.push((*badge_type, badge));
+                    }
+                }
+            }
+
+            badges
+        }
+
+        #[ink(message)]
+        pub fn has_badge(&self, property_id: u64, badge_type: BadgeType) -> bool {
+            if let Some(badge) = self.property_badges.get((property_id, badge_type)) {
+                !badge.revoked
+            } else {
+                false
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_badge(&self, property_id: u64, badge_type: BadgeType) -> Option<Badge> {
+            self.property_badges.get((property_id, badge_type))
+        }
+
+        /// Requires properties to hold a verification badge before they can be sold through a
+        /// sale type (admin only); `None` lifts the requirement
+        #[ink(message)]
+        pub fn set_sale_verification_requirement(
+            &mut self,
+            sale_type: SaleType,
+            badge_type: Option<BadgeType>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if let Some(badge_type) = badge_type {
+                self.sale_verification_requirements
+                    .insert(sale_type, &badge_type);
+            } else {
+                self.sale_verification_requirements.remove(sale_type);
+            }
+            Ok(())
+        }
+
+        /// Gets the badge required before a property can be sold through a sale type, if any
+        #[ink(message)]
+        pub fn get_sale_verification_requirement(&self, sale_type: SaleType) -> Option<BadgeType> {
+            self.sale_verification_requirements.get(sale_type)
+        }
+
+        /// Helper: rejects sales of properties lacking the badge required for the sale type
+        /// Revoked and expired badges do not count.
+        fn ensure_verified_for_sale(
+            &self,
+            property_id: u64,
+            sale_type: SaleType,
+        ) -> Result<(), Error> {
+            let Some(badge_type) = self.sale_verification_requirements.get(sale_type) else {
+                return Ok(());
+            };
+            let now = self.env().block_timestamp();
+            let verified = self
+                .property_badges
+                .get((property_id, badge_type))
+                .is_some_and(|badge| {
+                    !badge.revoked && !badge.expires_at.is_some_and(|expiry| expiry <= now)
+                });
+            if !verified {
+                return Err(Error::PropertyNotVerified);
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_verification_request(&self, request_id: u64) -> Option<VerificationRequest> {
+            self.verification_requests.get(request_id)
+        }
+
+        #[ink(message)]
+        pub fn get_appeal(&self, appeal_id: u64) -> Option<Appeal> {
+            self.appeals.get(appeal_id)
+        }
+    }
+
+    #[cfg(kani)]
+    mod verification {
+        use super::*;
+
+        #[kani::proof]
+        fn verify_arithmetic_overflow() {
+            let a: u64 = kani::any();
+            let b: u64 = kani::any();
+            // Verify that addition is safe
+            if a < 100 && b < 100 {
+                assert!(a + b < 200);
+            }
+        }
+
+        #[kani::proof]
+        fn verify_property_info_struct() {
+            let id: u64 = kani::any();
+            // Verify PropertyInfo layout/safety if needed
+            // This is a placeholder for checking structural invariants
+            if id > 0 {
+                assert!(id > 0);
+            }
+        }
+    }
+
+    impl Default for PropertyRegistry {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl From<Error> for EscrowError {
+        fn from(error: Error) -> Self {
+            match error {
+                Error::EscrowNotFound => EscrowError::EscrowNotFound,
+                Error::PropertyNotFound => EscrowError::PropertyNotFound,
+                Error::Unauthorized | Error::CannotBuyOwnProperty => EscrowError::Unauthorized,
+                Error::ContractPaused => EscrowError::ContractPaused,
+                Error::InvalidEscrowDeposit | Error::InvalidEscrowDeadline => {
+                    EscrowError::InvalidDeposit
+                }
+                Error::EscrowNotFunded => EscrowError::NotFunded,
+                Error::EscrowAlreadyFunded => EscrowError::AlreadyFunded,
+                Error::EscrowNotAccepted => EscrowError::NotAccepted,
+                Error::EscrowConditionsNotMet
+                | Error::NotaryApprovalRequired
+                | Error::InsufficientApprovals => EscrowError::ConditionsNotMet,
+                Error::EscrowAlreadyReleased => EscrowError::AlreadyReleased,
+                Error::EscrowClosed => EscrowError::Closed,
+                Error::EscrowDisputed => EscrowError::Disputed,
+                Error::EscrowNotExpired => EscrowError::NotExpired,
+                Error::NotCompliant | Error::ComplianceCheckFailed => EscrowError::ComplianceFailed,
+                Error::EscrowTransferFailed => EscrowError::TransferFailed,
+                _ => EscrowError::Other,
+            }
+        }
+    }
+
+    impl From<EscrowState> for EscrowStatus {
+        fn from(state: EscrowState) -> Self {
+            match state {
+                EscrowState::Created => EscrowStatus::Created,
+                EscrowState::Funded => EscrowStatus::Funded,
+                EscrowState::Released => EscrowStatus::Released,
+                EscrowState::Refunded => EscrowStatus::Refunded,
+                EscrowState::Disputed => EscrowStatus::Disputed,
+                EscrowState::Expired => EscrowStatus::Expired,
+            }
+        }
+    }
+
+    impl Escrow for PropertyRegistry {
+        #[ink(message, payable)]
+        fn open_escrow(
+            &mut self,
+            property_id: u64,
+            amount: u128,
+            deadline: Option<u64>,
+        ) -> Result<u64, EscrowError> {
+            // The caller is the buyer; the current property owner becomes the seller
+            Ok(self.open_escrow(property_id, amount, deadline)?)
+        }
+
+        #[ink(message, payable)]
+        fn fund_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError> {
+            Ok(self.fund_escrow(escrow_id)?)
+        }
+
+        #[ink(message)]
+        fn release_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError> {
+            Ok(self.release_escrow(escrow_id)?)
+        }
+
+        #[ink(message)]
+        fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError> {
+            Ok(self.refund_escrow(escrow_id)?)
+        }
+
+        #[ink(message)]
+        fn dispute_escrow(&mut self, escrow_id: u64, reason: String) -> Result<(), EscrowError> {
+            Ok(self.dispute_escrow(escrow_id, reason)?)
+        }
+
+        #[ink(message)]
+        fn claim_expired_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError> {
+            Ok(self.claim_expired_escrow(escrow_id)?)
+        }
+
+        #[ink(message)]
+        fn get_escrow_details(&self, escrow_id: u64) -> Option<EscrowDetails> {
+            self.escrows.get(escrow_id).map(|escrow| EscrowDetails {
+                id: escrow.id,
+                property_id: escrow.property_id,
+                buyer: escrow.buyer,
+                seller: escrow.seller,
+                amount: escrow.amount,
+                funded_amount: escrow.funded_amount,
+                deadline: escrow.deadline,
+                status: escrow.state.into(),
+            })
+        }
+
+        #[ink(message)]
+        fn is_escrow_funded(&self, escrow_id: u64) -> bool {
+            self.is_escrow_funded(escrow_id)
+        }
+    }
+
+    impl ShareRegistry for PropertyRegistry {
+        /// Screens a share token recipient like an in-registry share transfer
+        /// Read-only, so nothing is cached or logged; the token reverts when this is false.
+        #[ink(message)]
+        fn can_receive_shares(&self, property_id: u64, to: AccountId, amount: u128) -> bool {
+            if self.is_sanctioned(to) {
+                return false;
+            }
+            if self.is_share_transfer_exempt(amount) {
+                return true;
+            }
+            if !self.is_compliance_required(ComplianceOperation::TransferProperty)
+                || self.is_compliance_exempt(to)
+            {
+                return true;
+            }
+            let (jurisdiction, valuation) = self
+                .properties
+                .get(property_id)
+                .map(|property| (property.metadata.jurisdiction, property.metadata.valuation))
+                .unwrap_or((None, 0));
+            let min_tier = self.get_required_kyc_tier(valuation);
+            if self.attested_in(to, jurisdiction, min_tier)
+                || (!self.compliance_stale(to)
+                    && self.compliance_cached_in(to, jurisdiction, min_tier))
+            {
+                return true;
+            }
+            match self.call_compliance_registry(to, jurisdiction, min_tier) {
+                None => true,
+                Some(Ok(tier)) => tier > 0 && tier >= min_tier,
+                Some(Err(_)) => self.fails_open_for(to),
+            }
+        }
+    }
+
+    impl PropertyRegistry {
+        /// Splits a property into `total_shares` shares, all issued to the current owner.
+        /// The whole title stays with the owner but can no longer be sold or transferred.
+        #[ink(message)]
+        pub fn enable_fractional(
+            &mut self,
+            property_id: u64,
+            total_shares: u128,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if caller != self.admin && caller != property.owner {
+                return Err(Error::Unauthorized);
+            }
+            if total_shares == 0 {
+                return Err(Error::InvalidMetadata);
+            }
+            self.ensure_whole_title_transferable(property_id)?;
+            self.ensure_not_on_sale(property_id)?;
+            if self.buyouts.contains(property_id) {
+                return Err(Error::BuyoutInProgress);
+            }
+
+            let now = self.env().block_timestamp();
+            let info = FractionalInfo {
+                total_shares,
+                enabled: true,
+                created_at: now,
+            };
+            self.fractional.insert(property_id, &info);
+            self.accrue_income(property_id, property.owner);
+            self.set_share_balance(property_id, property.owner, total_shares);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SharesIssued {
+                property_id,
+                owner: property.owner,
+                event_version: 1,
+                total_shares,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Offers `new_shares` additional shares of a fractionalized property (owner only)
+        /// Existing holders get until `preemption_period` has passed to buy their pro-rata
+        /// portion before the remainder opens to everyone. Proceeds go to the owner.
+        /// An already deployed share token keeps its fixed supply, so only that many shares can be wrapped.
+        #[ink(message)]
+        pub fn issue_shares(
+            &mut self,
+            property_id: u64,
+            new_shares: u128,
+            price_per_share: u128,
+            preemption_period: u64,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+            let info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            if new_shares == 0 {
+                return Err(Error::InvalidMetadata);
+            }
+            if self.property_issuances.contains(property_id) {
+                return Err(Error::IssuanceInProgress);
+            }
+
+            let snapshot_id = self.take_share_snapshot(property_id)?;
+            let now = self.env().block_timestamp();
+            self.share_issuance_count += 1;
+            let issuance = ShareIssuance {
+                id: self.share_issuance_count,
+                property_id,
+                issuer: caller,
+                new_shares,
+                sold: 0,
+                price_per_share,
+                snapshot_id,
+                base_shares: info.total_shares,
+                preemption_ends_at: now.saturating_add(preemption_period),
+                open: true,
+            };
+            self.share_issuances.insert(issuance.id, &issuance);
+            self.property_issuances.insert(property_id, &issuance.id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ShareIssuanceOpened {
+                issuance_id: issuance.id,
+                property_id,
+                event_version: 1,
+                new_shares,
+                price_per_share,
+                preemption_ends_at: issuance.preemption_ends_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(issuance.id)
+        }
+
+        /// Buys newly issued shares, paying `price_per_share` for each with the call
+        /// During the preemption window the caller is limited to their remaining pro-rata right.
+        #[ink(message, payable)]
+        pub fn buy_issued_shares(&mut self, issuance_id: u64, shares: u128) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut issuance = self
+                .share_issuances
+                .get(issuance_id)
+                .filter(|issuance| issuance.open)
+                .ok_or(Error::IssuanceNotFound)?;
+            if shares == 0 || shares > issuance.new_shares.saturating_sub(issuance.sold) {
+                return Err(Error::InsufficientShares);
+            }
+            if self.env().transferred_value() != shares.saturating_mul(issuance.price_per_share) {
+                return Err(Error::IncorrectPayment);
+            }
+
+            let preemptive = self.env().block_timestamp() < issuance.preemption_ends_at;
+            if preemptive {
+                if shares > self.get_preemption_right(issuance_id, caller)? {
+                    return Err(Error::ExceedsPreemptionRight);
+                }
+                let bought = self
+                    .preemption_purchases
+                    .get((issuance_id, caller))
+                    .unwrap_or(0);
+                self.preemption_purchases
+                    .insert((issuance_id, caller), &bought.saturating_add(shares));
+            }
+            let property_id = issuance.property_id;
+            let balance = self
+                .get_share_balance(property_id, caller)
+                .saturating_add(shares);
+            self.ensure_min_share_holding(property_id, caller, balance)?;
+            if self.is_share_transfer_exempt(shares) {
+                self.ensure_not_sanctioned(caller)?;
+            } else {
+                self.check_property_compliance(caller, ComplianceOperation::Purchase, property_id)?;
+            }
+
+            let mut info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            info.total_shares = info.total_shares.saturating_add(shares);
+            self.fractional.insert(property_id, &info);
+            self.accrue_income(property_id, caller);
+            self.set_share_balance(property_id, caller, balance);
+
+            let issuer = issuance.issuer;
+            issuance.sold = issuance.sold.saturating_add(shares);
+            self.share_issuances.insert(issuance_id, &issuance);
+            if issuance.sold == issuance.new_shares {
+                self.close_issuance(issuance)?;
+            }
+            self.transfer_asset(EscrowAsset::Native, issuer, self.env().transferred_value())?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(IssuedSharesPurchased {
+                issuance_id,
+                buyer: caller,
+                event_version: 1,
+                shares,
+                preemptive,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Ends a share issuance once the preemption window is over (issuer only)
+        /// Shares not sold by then are never issued.
+        #[ink(message)]
+        pub fn close_share_issuance(&mut self, issuance_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let issuance = self
+                .share_issuances
+                .get(issuance_id)
+                .filter(|issuance| issuance.open)
+                .ok_or(Error::IssuanceNotFound)?;
+            if issuance.issuer != self.env().caller() {
+                return Err(Error::Unauthorized);
+            }
+            if self.env().block_timestamp() < issuance.preemption_ends_at {
+                return Err(Error::PreemptionWindowActive);
+            }
+            self.close_issuance(issuance)
+        }
+
+        /// Helper: marks an issuance closed and frees the property for a new one
+        fn close_issuance(&mut self, mut issuance: ShareIssuance) -> Result<(), Error> {
+            issuance.open = false;
+            self.share_issuances.insert(issuance.id, &issuance);
+            self.property_issuances.remove(issuance.property_id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ShareIssuanceClosed {
+                issuance_id: issuance.id,
+                property_id: issuance.property_id,
+                event_version: 1,
+                sold: issuance.sold,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Gets a share issuance by ID
+        #[ink(message)]
+        pub fn get_share_issuance(&self, issuance_id: u64) -> Option<ShareIssuance> {
+            self.share_issuances.get(issuance_id)
+        }
+
+        /// Gets how many newly issued shares an account may still buy under its preemption right
+        #[ink(message)]
+        pub fn get_preemption_right(
+            &self,
+            issuance_id: u64,
+            account: AccountId,
+        ) -> Result<u128, Error> {
+            let issuance = self
+                .share_issuances
+                .get(issuance_id)
+                .ok_or(Error::IssuanceNotFound)?;
+            let held =
+                self.get_share_balance_at(issuance.property_id, account, issuance.snapshot_id)?;
+            let entitled = issuance
+                .new_shares
+                .saturating_mul(held)
+                .checked_div(issuance.base_shares)
+                .unwrap_or(0);
+            let bought = self
+                .preemption_purchases
+                .get((issuance_id, account))
+                .unwrap_or(0);
+            Ok(entitled.saturating_sub(bought))
+        }
+
+        /// Transfers fractional shares of a property from the caller to `to`
+        #[ink(message)]
+        pub fn transfer_shares(
+            &mut self,
+            property_id: u64,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            self.move_shares(property_id, caller, to, amount)
+        }
+
+        /// Allows `spender` to transfer up to `amount` of the caller's shares in a property
+        /// Replaces any previous allowance; an amount of zero revokes it.
+        #[ink(message)]
+        pub fn approve_shares(
+            &mut self,
+            property_id: u64,
+            spender: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            if amount == 0 {
+                self.share_allowances.remove((property_id, caller, spender));
+            } else {
+                self.share_allowances
+                    .insert((property_id, caller, spender), &amount);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SharesApproved {
+                property_id,
+                owner: caller,
+                spender,
+                event_version: 1,
+                amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Gets how many of `owner`'s shares `spender` may still transfer
+        #[ink(message)]
+        pub fn get_share_allowance(
+            &self,
+            property_id: u64,
+            owner: AccountId,
+            spender: AccountId,
+        ) -> u128 {
+            self.share_allowances
+                .get((property_id, owner, spender))
+                .unwrap_or(0)
+        }
+
+        /// Transfers shares from `from` to `to` using the caller's allowance
+        /// The same lockup, minimum holding and compliance rules apply as for `transfer_shares`.
+        #[ink(message)]
+        pub fn transfer_shares_from(
+            &mut self,
+            property_id: u64,
+            from: AccountId,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            let allowance = self.get_share_allowance(property_id, from, caller);
+            if amount > allowance {
+                return Err(Error::InsufficientShareAllowance);
+            }
+            self.move_shares(property_id, from, to, amount)?;
+            self.share_allowances
+                .insert((property_id, from, caller), &(allowance - amount));
+            Ok(())
+        }
+
+        /// Helper: moves shares between two holders' in-registry balances
+        /// The recipient must pass the compliance check unless it is the registry itself or the
+        /// amount falls under the configured exemption threshold.
+        fn move_shares(
+            &mut self,
+            property_id: u64,
+            from: AccountId,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            self.move_shares_with(property_id, from, to, amount, None)
+        }
+
+        /// Helper: moves shares, treating those reserved by `settling_escrow` as free to move
+        /// The buyer of a settling escrow has already been screened for the release, so is not
+        /// screened again here.
+        fn move_shares_with(
+            &mut self,
+            property_id: u64,
+            from: AccountId,
+            to: AccountId,
+            amount: u128,
+            settling_escrow: Option<u64>,
+        ) -> Result<(), Error> {
+            let from_balance = self.get_share_balance(property_id, from);
+            if amount == 0 || amount > from_balance {
+                return Err(Error::InsufficientShares);
+            }
+            let unavailable = self
+                .get_locked_shares(property_id, from)
+                .saturating_add(self.reserved_shares(property_id, from, settling_escrow))
+                .saturating_add(self.get_pledged_shares(property_id, from));
+            if amount > from_balance.saturating_sub(unavailable) {
+                return Err(Error::SharesLocked);
+            }
+            if from != to {
+                self.ensure_min_share_holding(property_id, from, from_balance - amount)?;
+                let received = self
+                    .get_share_balance(property_id, to)
+                    .saturating_add(amount);
+                self.ensure_min_share_holding(property_id, to, received)?;
+            }
+            if to != self.env().account_id() && settling_escrow.is_none() {
+                // Small transfers skip KYC, but never the sanctions list
+                if self.is_share_transfer_exempt(amount) {
+                    self.ensure_not_sanctioned(to)?;
+                } else {
+                    self.check_property_compliance(
+                        to,
+                        ComplianceOperation::TransferProperty,
+                        property_id,
+                    )?;
+                }
+            }
+            self.accrue_income(property_id, from);
+            self.accrue_income(property_id, to);
+
+            self.set_share_balance(property_id, from, from_balance - amount);
+            let to_balance = self.get_share_balance(property_id, to);
+            self.set_share_balance(property_id, to, to_balance.saturating_add(amount));
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SharesTransferred {
+                property_id,
+                from,
+                to,
+                event_version: 1,
+                amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Deposits the transferred value as income for a property's shareholders
+        /// Used for rental income and sale proceeds. Each holder's portion is proportional to
+        /// their balance now and is paid out when they call `claim_income`; shares wrapped into
+        /// the PSP22 share token do not earn income.
+        #[ink(message, payable)]
+        pub fn distribute_income(&mut self, property_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::IncorrectPayment);
+            }
+            self.credit_income(property_id, self.env().caller(), amount)
+        }
+
+        /// Helper: spreads `amount` of native income held by the contract over the holders
+        fn credit_income(
+            &mut self,
+            property_id: u64,
+            depositor: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            let info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            let eligible_shares = info
+                .total_shares
+                .saturating_sub(self.get_share_balance(property_id, self.env().account_id()));
+            if eligible_shares == 0 {
+                return Err(Error::InsufficientShares);
+            }
+
+            let increment = amount.saturating_mul(INCOME_PRECISION) / eligible_shares;
+            let per_share = self.income_per_share.get(property_id).unwrap_or(0);
+            self.income_per_share
+                .insert(property_id, &per_share.saturating_add(increment));
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(IncomeDistributed {
+                property_id,
+                depositor,
+                event_version: 1,
+                amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Pays out the caller's accumulated income from a property
+        /// Returns the amount paid.
+        #[ink(message)]
+        pub fn claim_income(&mut self, property_id: u64) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            self.accrue_income(property_id, caller);
+            let amount = self
+                .unclaimed_income
+                .get((property_id, caller))
+                .unwrap_or(0);
+            if amount == 0 {
+                return Err(Error::NoIncomeToClaim);
+            }
+            self.unclaimed_income.remove((property_id, caller));
+            self.transfer_asset(EscrowAsset::Native, caller, amount)?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(IncomeClaimed {
+                property_id,
+                holder: caller,
+                event_version: 1,
+                amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(amount)
+        }
+
+        /// Gets the income an account can currently claim from a property
+        #[ink(message)]
+        pub fn get_claimable_income(&self, property_id: u64, account: AccountId) -> u128 {
+            self.unclaimed_income
+                .get((property_id, account))
+                .unwrap_or(0)
+                .saturating_add(self.pending_income(property_id, account))
+        }
+
+        /// Helper: income earned by a holder's current balance since their last checkpoint
+        fn pending_income(&self, property_id: u64, account: AccountId) -> u128 {
+            if account == self.env().account_id() {
+                return 0;
+            }
+            let per_share = self.income_per_share.get(property_id).unwrap_or(0);
+            let checkpoint = self
+                .income_checkpoints
+                .get((property_id, account))
+                .unwrap_or(0);
+            self.get_share_balance(property_id, account)
+                .saturating_mul(per_share.saturating_sub(checkpoint))
+                / INCOME_PRECISION
+        }
+
+        /// Helper: settles a holder's pending income; must run before their balance changes
+        fn accrue_income(&mut self, property_id: u64, account: AccountId) {
+            let pending = self.pending_income(property_id, account);
+            if pending > 0 {
+                let unclaimed = self
+                    .unclaimed_income
+                    .get((property_id, account))
+                    .unwrap_or(0);
+                self.unclaimed_income
+                    .insert((property_id, account), &unclaimed.saturating_add(pending));
+            }
+            let per_share = self.income_per_share.get(property_id).unwrap_or(0);
+            self.income_checkpoints
+                .insert((property_id, account), &per_share);
+        }
+
+        /// Burns every share of a property held by the caller and returns the whole title to them
+        /// Only possible once the caller holds all of the property's shares.
+        #[ink(message)]
+        pub fn defractionalize(&mut self, property_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            if self.get_share_balance(property_id, caller) != info.total_shares {
+                return Err(Error::InsufficientShares);
+            }
+            self.check_property_compliance(
+                caller,
+                ComplianceOperation::TransferProperty,
+                property_id,
+            )?;
+            self.consolidate_title(property_id, caller)?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyDefractionalized {
+                property_id,
+                owner: caller,
+                event_version: 1,
+                burned_shares: info.total_shares,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Sets the share of a property a holder needs to force a buyout (admin only)
+        #[ink(message)]
+        pub fn set_buyout_threshold(&mut self, threshold_bps: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if threshold_bps == 0 || threshold_bps > 10_000 {
+                return Err(Error::InvalidBasisPoints);
+            }
+            self.buyout_threshold_bps = threshold_bps;
+            Ok(())
+        }
+
+        /// Gets the share of a property a holder needs to force a buyout, in basis points
+        #[ink(message)]
+        pub fn get_buyout_threshold(&self) -> u32 {
+            self.buyout_threshold_bps
+        }
+
+        /// Buys out the remaining shares of a property at `price_per_share`
+        /// The caller must hold at least the buyout threshold and transfer exactly the price of
+        /// every share they do not hold. The title consolidates to the caller straight away and
+        /// minority holders collect their payout with `claim_buyout`.
+        #[ink(message, payable)]
+        pub fn start_buyout(
+            &mut self,
+            property_id: u64,
+            price_per_share: u128,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            let held = self.get_share_balance(property_id, caller);
+            let required = info
+                .total_shares
+                .saturating_mul(u128::from(self.buyout_threshold_bps))
+                / 10_000;
+            if held < required {
+                return Err(Error::BelowBuyoutThreshold);
+            }
+            let outstanding_shares = info.total_shares.saturating_sub(held);
+            if self.env().transferred_value() != outstanding_shares.saturating_mul(price_per_share)
+            {
+                return Err(Error::IncorrectPayment);
+            }
+            self.check_property_compliance(caller, ComplianceOperation::Purchase, property_id)?;
+
+            let now = self.env().block_timestamp();
+            self.consolidate_title(property_id, caller)?;
+            if outstanding_shares > 0 {
+                self.buyouts.insert(
+                    property_id,
+                    &Buyout {
+                        buyer: caller,
+                        price_per_share,
+                        outstanding_shares,
+                        started_at: now,
+                    },
+                );
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BuyoutStarted {
+                property_id,
+                buyer: caller,
+                event_version: 1,
+                price_per_share,
+                outstanding_shares,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Surrenders the caller's remaining shares in a bought-out property for their payout
+        /// Returns the amount paid.
+        #[ink(message)]
+        pub fn claim_buyout(&mut self, property_id: u64) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut buyout = self.buyouts.get(property_id).ok_or(Error::BuyoutNotFound)?;
+            let shares = self.get_share_balance(property_id, caller);
+            if shares == 0 {
+                return Err(Error::InsufficientShares);
+            }
+            if self.get_pledged_shares(property_id, caller) > 0 {
+                return Err(Error::SharesLocked);
+            }
+
+            self.accrue_income(property_id, caller);
+            self.set_share_balance(property_id, caller, 0);
+            buyout.outstanding_shares = buyout.outstanding_shares.saturating_sub(shares);
+            if buyout.outstanding_shares == 0 {
+                self.buyouts.remove(property_id);
+            } else {
+                self.buyouts.insert(property_id, &buyout);
+            }
+            let payout = shares.saturating_mul(buyout.price_per_share);
+            self.transfer_asset(EscrowAsset::Native, caller, payout)?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BuyoutClaimed {
+                property_id,
+                holder: caller,
+                event_version: 1,
+                shares,
+                payout,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(payout)
+        }
+
+        /// Gets the buyout awaiting minority claims for a property
+        #[ink(message)]
+        pub fn get_buyout(&self, property_id: u64) -> Option<Buyout> {
+            self.buyouts.get(property_id)
+        }
+
+        /// Helper: ends fractional ownership and hands the whole title to `to`
+        /// `to`'s own shares are retired; other holders keep their balances only as claims.
+        fn consolidate_title(&mut self, property_id: u64, to: AccountId) -> Result<(), Error> {
+            let mut property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if self.get_pledged_shares(property_id, to) > 0 {
+                return Err(Error::SharesLocked);
+            }
+            if let Some(mut info) = self.fractional.get(property_id) {
+                info.enabled = false;
+                self.fractional.insert(property_id, &info);
+            }
+            self.accrue_income(property_id, to);
+            self.set_share_balance(property_id, to, 0);
+            if property.owner != to {
+                self.move_property(&mut property, to, to);
+            }
+            Ok(())
+        }
+
+        /// Sets the smallest non-zero share balance a holder may be left with (owner or admin)
+        /// Transfers that would leave either side with a smaller, non-zero balance are rejected.
+        #[ink(message)]
+        pub fn set_min_share_holding(
+            &mut self,
+            property_id: u64,
+            min_holding: u128,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if caller != self.admin && caller != property.owner {
+                return Err(Error::Unauthorized);
+            }
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            self.min_share_holdings.insert(property_id, &min_holding);
+            Ok(())
+        }
+
+        /// Gets the smallest non-zero share balance a holder may be left with
+        #[ink(message)]
+        pub fn get_min_share_holding(&self, property_id: u64) -> u128 {
+            self.min_share_holdings.get(property_id).unwrap_or(0)
+        }
+
+        /// Helper: rejects a holder balance that is non-zero but below the property's minimum
+        /// The registry's own balance backing wrapped share tokens is exempt.
+        fn ensure_min_share_holding(
+            &self,
+            property_id: u64,
+            account: AccountId,
+            balance: u128,
+        ) -> Result<(), Error> {
+            if account != self.env().account_id()
+                && balance > 0
+                && balance < self.get_min_share_holding(property_id)
+            {
+                return Err(Error::ShareBalanceBelowMinimum);
+            }
+            Ok(())
+        }
+
+        /// Transfers shares from the caller to `to` under a lockup or linear vesting schedule
+        /// Nothing unlocks before `cliff`; the shares then unlock linearly until all are free
+        /// at `end`. Sending to oneself locks the caller's own shares, e.g. developer-retained
+        /// shares. A holder can have one schedule per property.
+        #[ink(message)]
+        pub fn transfer_shares_vested(
+            &mut self,
+            property_id: u64,
+            to: AccountId,
+            amount: u128,
+            cliff: u64,
+            end: u64,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            let now = self.env().block_timestamp();
+            if cliff > end || end <= now {
+                return Err(Error::InvalidVestingSchedule);
+            }
+            if self.get_locked_shares(property_id, to) > 0 {
+                return Err(Error::VestingScheduleExists);
+            }
+
+            self.move_shares(property_id, caller, to, amount)?;
+            self.share_vesting.insert(
+                (property_id, to),
+                &ShareVesting {
+                    total: amount,
+                    start: now,
+                    cliff,
+                    end,
+                },
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SharesVestingGranted {
+                property_id,
+                holder: to,
+                event_version: 1,
+                amount,
+                cliff,
+                end,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Gets the lockup or vesting schedule on an account's shares
+        #[ink(message)]
+        pub fn get_share_vesting(
+            &self,
+            property_id: u64,
+            account: AccountId,
+        ) -> Option<ShareVesting> {
+            self.share_vesting.get((property_id, account))
+        }
+
+        /// Gets how many of an account's shares are still locked
+        #[ink(message)]
+        pub fn get_locked_shares(&self, property_id: u64, account: AccountId) -> u128 {
+            let Some(vesting) = self.share_vesting.get((property_id, account)) else {
+                return 0;
+            };
+            let now = self.env().block_timestamp();
+            if now >= vesting.end {
+                return 0;
+            }
+            if now < vesting.cliff {
+                return vesting.total;
+            }
+            let elapsed = u128::from(now.saturating_sub(vesting.start));
+            let duration = u128::from(vesting.end.saturating_sub(vesting.start));
+            let vested = vesting
+                .total
+                .saturating_mul(elapsed)
+                .checked_div(duration)
+                .unwrap_or(vesting.total);
+            vesting.total.saturating_sub(vested)
+        }
+
+        /// Gets how many of an account's shares it can transfer right now
+        /// Excludes shares still locked by a schedule and shares committed to open escrows.
+        #[ink(message)]
+        pub fn get_transferable_shares(&self, property_id: u64, account: AccountId) -> u128 {
+            self.get_share_balance(property_id, account)
+                .saturating_sub(self.get_locked_shares(property_id, account))
+                .saturating_sub(self.get_reserved_shares(property_id, account))
+                .saturating_sub(self.get_pledged_shares(property_id, account))
+        }
+
+        /// Pledges transferable shares to `lender` as collateral until `maturity`
+        /// The shares stay with the caller, earning income and voting, but cannot be moved
+        /// until the lender releases them.
+        #[ink(message)]
+        pub fn pledge_shares(
+            &mut self,
+            property_id: u64,
+            lender: AccountId,
+            shares: u128,
+            maturity: u64,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if lender == caller {
+                return Err(Error::InvalidPledge);
+            }
+            if shares == 0 || shares > self.get_transferable_shares(property_id, caller) {
+                return Err(Error::SharesLocked);
+            }
+            let pledged = self.get_pledged_shares(property_id, caller);
+            self.pledged_shares
+                .insert((property_id, caller), &pledged.saturating_add(shares));
+            self.share_pledge_count += 1;
+            let pledge = SharePledge {
+                id: self.share_pledge_count,
+                property_id,
+                holder: caller,
+                lender,
+                shares,
+                maturity,
+                defaulted: false,
+            };
+            self.share_pledges.insert(pledge.id, &pledge);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SharesPledged {
+                pledge_id: pledge.id,
+                holder: caller,
+                lender,
+                event_version: 1,
+                property_id,
+                shares,
+                maturity,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(pledge.id)
+        }
+
+        /// Releases pledged shares back to the holder (lender only)
+        #[ink(message)]
+        pub fn release_pledge(&mut self, pledge_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let pledge = self.lender_pledge(pledge_id)?;
+            self.unlock_pledge(&pledge);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PledgeReleased {
+                pledge_id,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Records that the holder defaulted on a matured pledge (lender only)
+        #[ink(message)]
+        pub fn record_pledge_default(&mut self, pledge_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let mut pledge = self.lender_pledge(pledge_id)?;
+            if self.env().block_timestamp() < pledge.maturity {
+                return Err(Error::PledgeNotMatured);
+            }
+            pledge.defaulted = true;
+            self.share_pledges.insert(pledge_id, &pledge);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PledgeDefaulted {
+                pledge_id,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Takes the shares of a defaulted pledge into the lender's balance (lender only)
+        #[ink(message)]
+        pub fn seize_pledged_shares(&mut self, pledge_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let pledge = self.lender_pledge(pledge_id)?;
+            if !pledge.defaulted {
+                return Err(Error::PledgeNotDefaulted);
+            }
+            self.unlock_pledge(&pledge);
+            self.move_shares(
+                pledge.property_id,
+                pledge.holder,
+                pledge.lender,
+                pledge.shares,
+            )?;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PledgedSharesSeized {
+                pledge_id,
+                lender: pledge.lender,
+                event_version: 1,
+                shares: pledge.shares,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Helper: loads an outstanding pledge, requiring the caller to be its lender
+        fn lender_pledge(&self, pledge_id: u64) -> Result<SharePledge, Error> {
+            let pledge = self
+                .share_pledges
+                .get(pledge_id)
+                .ok_or(Error::PledgeNotFound)?;
+            if pledge.lender != self.env().caller() {
+                return Err(Error::Unauthorized);
+            }
+            Ok(pledge)
+        }
+
+        /// Helper: removes a pledge and frees its shares
+        fn unlock_pledge(&mut self, pledge: &SharePledge) {
+            let key = (pledge.property_id, pledge.holder);
+            let remaining = self
+                .get_pledged_shares(pledge.property_id, pledge.holder)
+                .saturating_sub(pledge.shares);
+            if remaining == 0 {
+                self.pledged_shares.remove(key);
+            } else {
+                self.pledged_shares.insert(key, &remaining);
+            }
+            self.share_pledges.remove(pledge.id);
+        }
+
+        /// Gets an outstanding share pledge by ID
+        #[ink(message)]
+        pub fn get_share_pledge(&self, pledge_id: u64) -> Option<SharePledge> {
+            self.share_pledges.get(pledge_id)
+        }
+
+        /// Gets how many of an account's shares are pledged as collateral
+        #[ink(message)]
+        pub fn get_pledged_shares(&self, property_id: u64, account: AccountId) -> u128 {
+            self.pledged_shares.get((property_id, account)).unwrap_or(0)
+        }
+
+        /// Gets how many of an account's shares are committed to its open share-block escrows
+        #[ink(message)]
+        pub fn get_reserved_shares(&self, property_id: u64, account: AccountId) -> u128 {
+            self.reserved_shares(property_id, account, None)
+        }
+
+        /// Helper: shares committed to the seller's accepted, open share-block escrows,
+        /// optionally leaving out one escrow
+        fn reserved_shares(
+            &self,
+            property_id: u64,
+            seller: AccountId,
+            except_escrow: Option<u64>,
+        ) -> u128 {
+            (0..self.get_escrow_count_by_seller(seller))
+                .filter_map(|position| self.seller_escrows.get((seller, position)))
+                .filter(|&escrow_id| Some(escrow_id) != except_escrow)
+                .filter_map(|escrow_id| {
+                    let shares = self.escrow_shares.get(escrow_id)?;
+                    let escrow = self.escrows.get(escrow_id)?;
+                    (escrow.property_id == property_id
+                        && escrow.seller_accepted
+                        && !escrow.state.is_final())
+                    .then_some(shares)
+                })
+                .fold(0u128, |total, shares| total.saturating_add(shares))
+        }
+
+        /// Sets the amount below which share transfers skip the compliance check (admin only)
+        /// `None` runs every share recipient through the compliance registry. Sanctioned
+        /// accounts are refused whatever the amount.
+        #[ink(message)]
+        pub fn set_share_compliance_exemption(
+            &mut self,
+            exempt_below: Option<u128>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.share_compliance_exempt_below = exempt_below;
+            Ok(())
+        }
+
+        /// Gets the amount below which share transfers skip the compliance check
+        #[ink(message)]
+        pub fn get_share_compliance_exemption(&self) -> Option<u128> {
+            self.share_compliance_exempt_below
+        }
+
+        /// Helper: true if a share transfer of `amount` is small enough to skip compliance
+        fn is_share_transfer_exempt(&self, amount: u128) -> bool {
+            self.share_compliance_exempt_below
+                .map(|threshold| amount < threshold)
+                .unwrap_or(false)
+        }
+
+        /// Sets the code hash PSP22 share tokens are instantiated from (admin only)
+        #[ink(message)]
+        pub fn set_share_token_code_hash(&mut self, code_hash: Option<Hash>) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.share_token_code_hash = code_hash;
+            Ok(())
+        }
+
+        /// Gets the code hash PSP22 share tokens are instantiated from
+        #[ink(message)]
+        pub fn get_share_token_code_hash(&self) -> Option<Hash> {
+            self.share_token_code_hash
+        }
+
+        /// Instantiates a PSP22 share token for a fractionalized property (owner or admin)
+        /// The registry holds the whole token supply; holders obtain tokens by wrapping their
+        /// in-registry shares with `wrap_shares`.
+        #[ink(message)]
+        pub fn deploy_share_token(&mut self, property_id: u64) -> Result<AccountId, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if caller != self.admin && caller != property.owner {
+                return Err(Error::Unauthorized);
+            }
+            let info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            if self.share_tokens.contains(property_id) {
+                return Err(Error::ShareTokenAlreadyDeployed);
+            }
+            let code_hash = self
+                .share_token_code_hash
+                .ok_or(Error::ShareTokenCodeHashNotSet)?;
+
+            let token = propchain_share_token::ShareTokenRef::new(property_id, info.total_shares)
+                .code_hash(code_hash)
+                .endowment(0)
+                .salt_bytes(property_id.to_le_bytes())
+                .try_instantiate()
+                .map_err(|_| Error::ShareTokenDeployFailed)?
+                .map_err(|_| Error::ShareTokenDeployFailed)?;
+            let token = ink::ToAccountId::to_account_id(&token);
+            self.share_tokens.insert(property_id, &token);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ShareTokenDeployed {
+                property_id,
+                token,
+                event_version: 1,
+                total_shares: info.total_shares,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(token)
+        }
+
+        /// Gets the PSP22 share token deployed for a property
+        #[ink(message)]
+        pub fn get_share_token(&self, property_id: u64) -> Option<AccountId> {
+            self.share_tokens.get(property_id)
+        }
+
+        /// Converts in-registry shares into PSP22 share tokens paid to the caller
+        /// The shares are parked on the registry's own balance while the tokens circulate; the
+        /// token screens each recipient through `can_receive_shares`.
+        #[ink(message)]
+        pub fn wrap_shares(&mut self, property_id: u64, amount: u128) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let token = self
+                .share_tokens
+                .get(property_id)
+                .ok_or(Error::ShareTokenNotDeployed)?;
+            self.move_shares(property_id, caller, self.env().account_id(), amount)?;
+            self.transfer_asset(EscrowAsset::Psp22(token), caller, amount)
+        }
+
+        /// Converts PSP22 share tokens back into in-registry shares
+        /// The caller must have approved the registry for at least `amount` tokens.
+        #[ink(message)]
+        pub fn unwrap_shares(&mut self, property_id: u64, amount: u128) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let token = self
+                .share_tokens
+                .get(property_id)
+                .ok_or(Error::ShareTokenNotDeployed)?;
+            self.pull_psp22(token, caller, amount)?;
+            self.move_shares(property_id, self.env().account_id(), caller, amount)
+        }
+
+        /// Helper: writes a holder's share balance and keeps the enumerable holder set in step
+        fn set_share_balance(&mut self, property_id: u64, account: AccountId, balance: u128) {
+            self.record_snapshot_balance(property_id, account);
+            let position = self.shareholder_positions.get((property_id, account));
+            if balance == 0 {
+                self.share_balances.remove((property_id, account));
+                let Some(position) = position else {
+                    return;
+                };
+                // Swap the last holder into the freed position
+                let last = self
+                    .shareholder_count
+                    .get(property_id)
+                    .unwrap_or(0)
+                    .saturating_sub(1);
+                if position != last {
+                    if let Some(moved) = self.shareholders.get((property_id, last)) {
+                        self.shareholders.insert((property_id, position), &moved);
+                        self.shareholder_positions
+                            .insert((property_id, moved), &position);
+                    }
+                }
+                self.shareholders.remove((property_id, last));
+                self.shareholder_positions.remove((property_id, account));
+                self.shareholder_count.insert(property_id, &last);
+                return;
+            }
+
+            self.share_balances.insert((property_id, account), &balance);
+            if position.is_none() {
+                let count = self.shareholder_count.get(property_id).unwrap_or(0);
+                self.shareholders.insert((property_id, count), &account);
+                self.shareholder_positions
+                    .insert((property_id, account), &count);
+                self.shareholder_count
+                    .insert(property_id, &count.saturating_add(1));
+            }
+        }
+
+        /// Freezes a property's current share balances under a new snapshot ID (owner or admin)
+        /// Balances are copied lazily: a holder's balance is only stored once it first changes
+        /// after the snapshot.
+        #[ink(message)]
+        pub fn snapshot(&mut self, property_id: u64) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if caller != self.admin && caller != property.owner {
+                return Err(Error::Unauthorized);
+            }
+            self.take_share_snapshot(property_id)
+        }
+
+        /// Helper: records a new snapshot of a fractionalized property's cap table
+        fn take_share_snapshot(&mut self, property_id: u64) -> Result<u64, Error> {
+            let info = self
+                .fractional
+                .get(property_id)
+                .filter(|info| info.enabled)
+                .ok_or(Error::PropertyNotFound)?;
+            self.share_snapshot_count += 1;
+            let snapshot = ShareSnapshot {
+                id: self.share_snapshot_count,
+                property_id,
+                total_shares: info.total_shares,
+                taken_at: self.env().block_timestamp(),
+            };
+            self.share_snapshots.insert(snapshot.id, &snapshot);
+            self.latest_share_snapshots
+                .insert(property_id, &snapshot.id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ShareSnapshotTaken {
+                property_id,
+                snapshot_id: snapshot.id,
+                event_version: 1,
+                total_shares: info.total_shares,
+                timestamp: snapshot.taken_at,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(snapshot.id)
+        }
+
+        /// Helper: saves a holder's balance for the latest snapshot before it first changes
+        fn record_snapshot_balance(&mut self, property_id: u64, account: AccountId) {
+            let Some(latest) = self.latest_share_snapshots.get(property_id) else {
+                return;
+            };
+            let mut history = self
+                .share_balance_history
+                .get((property_id, account))
+                .unwrap_or_default();
+            if history.last().is_some_and(|(id, _)| *id >= latest) {
+                return;
+            }
+            history.push((latest, self.get_share_balance(property_id, account)));
+            self.share_balance_history
+                .insert((property_id, account), &history);
+        }
+
+        /// Gets a cap-table snapshot by ID
+        #[ink(message)]
+        pub fn get_share_snapshot(&self, snapshot_id: u64) -> Option<ShareSnapshot> {
+            self.share_snapshots.get(snapshot_id)
+        }
+
+        /// Gets an account's share balance as it stood when the snapshot was taken
+        #[ink(message)]
+        pub fn get_share_balance_at(
+            &self,
+            property_id: u64,
+            account: AccountId,
+            snapshot_id: u64,
+        ) -> Result<u128, Error> {
+            let snapshot = self
+                .share_snapshots
+                .get(snapshot_id)
+                .filter(|snapshot| snapshot.property_id == property_id)
+                .ok_or(Error::SnapshotNotFound)?;
+            let history = self
+                .share_balance_history
+                .get((property_id, account))
+                .unwrap_or_default();
+            // The first entry recorded at or after the snapshot holds the balance it froze;
+            // without one, the balance has not changed since
+            let index = history.partition_point(|(id, _)| *id < snapshot.id);
+            Ok(history
+                .get(index)
+                .map(|(_, balance)| *balance)
+                .unwrap_or_else(|| self.get_share_balance(property_id, account)))
+        }
+
+        /// Gets a page of a property's shareholders with their balances, as (holder, balance)
+        /// Removing a holder moves the last holder into its place, so pages are not stable
+        /// across share transfers.
+        #[ink(message)]
+        pub fn get_shareholders(
+            &self,
+            property_id: u64,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<(AccountId, u128)> {
+            let count = self.get_shareholder_count(property_id);
+            let end = offset
+                .saturating_add(limit.min(MAX_SHAREHOLDER_PAGE_SIZE))
+                .min(count);
+            (offset..end)
+                .filter_map(|position| self.shareholders.get((property_id, position)))
+                .map(|holder| (holder, self.get_share_balance(property_id, holder)))
+                .collect()
+        }
+
+        /// Gets the number of accounts holding shares in a property
+        #[ink(message)]
+        pub fn get_shareholder_count(&self, property_id: u64) -> u32 {
+            self.shareholder_count.get(property_id).unwrap_or(0)
+        }
+
+        /// Gets an account's fractional share balance in a property
+        #[ink(message)]
+        pub fn get_share_balance(&self, property_id: u64, account: AccountId) -> u128 {
+            self.share_balances.get((property_id, account)).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_fractional_info(&self, property_id: u64) -> Option<FractionalInfo> {
+            self.fractional.get(property_id)
+        }
+
+        #[ink(message)]
+        pub fn is_fractional(&self, property_id: u64) -> bool {
+            self.fractional
+                .get(property_id)
+                .map(|i: FractionalInfo| i.enabled)
+                .unwrap_or(false)
+        }
+    }
+
+    impl PropertyRegistry {
+        /// Opens a shareholder vote on a restricted action for a fractionalized property
+        /// Any current shareholder may propose; voting stays open for the voting period.
+        #[ink(message)]
+        pub fn create_proposal(
+            &mut self,
+            property_id: u64,
+            action: ProposalAction,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if !self.is_fractional(property_id) {
+                return Err(Error::PropertyNotFound);
+            }
+            if self.get_share_balance(property_id, caller) == 0 {
+                return Err(Error::InsufficientShares);
+            }
+            if let ProposalAction::Sell { price } | ProposalAction::SetListingPrice { price } =
+                action
+            {
+                if price == 0 {
+                    return Err(Error::InvalidListingPrice);
+                }
+            }
+
+            let snapshot_id = self.take_share_snapshot(property_id)?;
+            let now = self.env().block_timestamp();
+            self.proposal_count += 1;
+            let proposal = Proposal {
+                id: self.proposal_count,
+                property_id,
+                proposer: caller,
+                action,
+                votes_for: 0,
+                votes_against: 0,
+                snapshot_id,
+                created_at: now,
+                voting_ends_at: now.saturating_add(self.proposal_voting_period),
+                status: ProposalStatus::Active,
+            };
+            self.proposals.insert(proposal.id, &proposal);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ProposalCreated {
+                proposal_id: proposal.id,
+                property_id,
+                proposer: caller,
+                event_version: 1,
+                action,
+                voting_ends_at: proposal.voting_ends_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(proposal.id)
+        }
+
+        /// Votes on an open proposal with the caller's share balance as voting power
+        /// Balances are read from the snapshot taken when the proposal was created, so shares
+        /// moved afterwards cannot vote twice.
+        #[ink(message)]
+        pub fn vote(&mut self, proposal_id: u64, support: bool) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+            if proposal.status != ProposalStatus::Active
+                || self.env().block_timestamp() >= proposal.voting_ends_at
+            {
+                return Err(Error::ProposalNotActive);
+            }
+            if self.proposal_votes.contains((proposal_id, caller)) {
+                return Err(Error::AlreadyVoted);
+            }
+            let weight =
+                self.get_share_balance_at(proposal.property_id, caller, proposal.snapshot_id)?;
+            if weight == 0 {
+                return Err(Error::InsufficientShares);
+            }
+
+            if support {
+                proposal.votes_for = proposal.votes_for.saturating_add(weight);
+            } else {
+                proposal.votes_against = proposal.votes_against.saturating_add(weight);
+            }
+            self.proposals.insert(proposal_id, &proposal);
+            self.proposal_votes.insert((proposal_id, caller), &support);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(VoteCast {
+                proposal_id,
+                voter: caller,
+                event_version: 1,
+                support,
+                weight,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Carries out a proposal backed by a majority of the property's shares
+        /// Anyone may execute once the majority is reached, even before voting closes.
+        #[ink(message)]
+        pub fn execute_proposal(&mut self, proposal_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let mut proposal = self
+                .proposals
+                .get(proposal_id)
+                .ok_or(Error::ProposalNotFound)?;
+            if proposal.status != ProposalStatus::Active {
+                return Err(Error::ProposalNotActive);
+            }
+            if !self.is_proposal_passed(proposal_id) {
+                return Err(Error::ProposalNotPassed);
+            }
+
+            let property_id = proposal.property_id;
+            match proposal.action {
+                ProposalAction::Sell { price } => {
+                    let property = self
+                        .properties
+                        .get(property_id)
+                        .ok_or(Error::PropertyNotFound)?;
+                    self.ensure_verified_for_sale(property_id, SaleType::Listing)?;
+                    self.ensure_not_on_sale(property_id)?;
+                    self.insert_listing(property_id, property.owner, price, None, None);
+                }
+                ProposalAction::SetListingPrice { price } => {
+                    let listing_id = self
+                        .property_listings
+                        .get(property_id)
+                        .ok_or(Error::ListingNotFound)?;
+                    self.reprice_listing(listing_id, price)?;
+                }
+                ProposalAction::MajorRenovation { description_hash } => {
+                    self.approved_renovations
+                        .insert(property_id, &description_hash);
+                }
+            }
+
+            proposal.status = ProposalStatus::Executed;
+            self.proposals.insert(proposal_id, &proposal);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ProposalExecuted {
+                proposal_id,
+                property_id,
+                event_version: 1,
+                action: proposal.action,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Returns true if more than half of the property's shares voted for the proposal
+        /// Proposals for properties that are no longer fractionalized never pass.
+        #[ink(message)]
+        pub fn is_proposal_passed(&self, proposal_id: u64) -> bool {
+            let Some(proposal) = self.proposals.get(proposal_id) else {
+                return false;
+            };
+            self.fractional
+                .get(proposal.property_id)
+                .filter(|info| info.enabled)
+                .is_some_and(|info| proposal.votes_for.saturating_mul(2) > info.total_shares)
+        }
+
+        /// Gets a shareholder proposal by ID
+        #[ink(message)]
+        pub fn get_proposal(&self, proposal_id: u64) -> Option<Proposal> {
+            self.proposals.get(proposal_id)
+        }
+
+        /// Returns true if the account has voted on the proposal
+        #[ink(message)]
+        pub fn has_voted(&self, proposal_id: u64, account: AccountId) -> bool {
+            self.proposal_votes.contains((proposal_id, account))
+        }
+
+        /// Gets the renovation shareholders approved for a property, if not yet used
+        #[ink(message)]
+        pub fn get_approved_renovation(&self, property_id: u64) -> Option<Hash> {
+            self.approved_renovations.get(property_id)
+        }
+
+        /// Sets how long shareholders can vote on new proposals, in milliseconds (admin only)
+        #[ink(message)]
+        pub fn set_proposal_voting_period(&mut self, period: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if period == 0 {
+                return Err(Error::InvalidAuctionDuration);
+            }
+            self.proposal_voting_period = period;
+            Ok(())
+        }
+
+        /// Gets how long shareholders can vote on new proposals, in milliseconds
+        #[ink(message)]
+        pub fn get_proposal_voting_period(&self) -> u64 {
+            self.proposal_voting_period
+        }
+
+        /// Helper: ends fractional ownership after a shareholder-approved sale
+        /// The proceeds are held for shareholders to collect per share with `claim_buyout`;
+        /// rounding dust accrues to the treasury.
+        fn wind_up_fractional_sale(
+            &mut self,
+            property_id: u64,
+            buyer: AccountId,
+            proceeds: u128,
+        ) -> Result<(), Error> {
+            let mut info = self
+                .fractional
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            info.enabled = false;
+            self.fractional.insert(property_id, &info);
+
+            let price_per_share = proceeds / info.total_shares;
+            let dust = proceeds.saturating_sub(price_per_share.saturating_mul(info.total_shares));
+            self.treasury_balance = self.treasury_balance.saturating_add(dust);
+
+            let now = self.env().block_timestamp();
+            self.buyouts.insert(
+                property_id,
+                &Buyout {
+                    buyer,
+                    price_per_share,
+                    outstanding_shares: info.total_shares,
+                    started_at: now,
+                },
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BuyoutStarted {
+                property_id,
+                buyer,
+                event_version: 1,
+                price_per_share,
+                outstanding_shares: info.total_shares,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+    }
+
+    impl PropertyRegistry {
+        /// Lists a property for sale at a fixed price (owner only)
+        /// A property can have one active listing at a time.
+        #[ink(message)]
+        pub fn list_property(
+            &mut self,
+            property_id: u64,
+            price: u128,
+            expires_at: Option<u64>,
+        ) -> Result<u64, Error> {
+            self.create_listing(property_id, price, expires_at, None)
+        }
+
+        /// Lists a property at a fixed price that only `buyer` can purchase (owner only)
+        /// Used for off-market deals that were negotiated beforehand; the sale is still
+        /// recorded publicly when it settles.
+        #[ink(message)]
+        pub fn list_property_privately(
+            &mut self,
+            property_id: u64,
+            price: u128,
+            buyer: AccountId,
+            expires_at: Option<u64>,
+        ) -> Result<u64, Error> {
+            if buyer == self.env().caller() {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+            self.create_listing(property_id, price, expires_at, Some(buyer))
+        }
+
+        /// Helper: opens a fixed-price listing, optionally reserved for a single buyer
+        fn create_listing(
+            &mut self,
+            property_id: u64,
+            price: u128,
+            expires_at: Option<u64>,
+            private_buyer: Option<AccountId>,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.check_property_compliance(caller, ComplianceOperation::ListForSale, property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Listing)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if price == 0 {
+                return Err(Error::InvalidListingPrice);
+            }
+
+            self.ensure_valid_expiry(expires_at)?;
+            self.ensure_not_on_sale(property_id)?;
+
+            Ok(self.insert_listing(property_id, caller, price, expires_at, private_buyer))
+        }
+
+        /// Helper: stores a new native-priced listing and indexes it against the property
+        fn insert_listing(
+            &mut self,
+            property_id: u64,
+            seller: AccountId,
+            price: u128,
+            expires_at: Option<u64>,
+            private_buyer: Option<AccountId>,
+        ) -> u64 {
+            self.listing_count += 1;
+            let listing_id = self.listing_count;
+            let listing = Listing {
+                id: listing_id,
+                property_id,
+                seller,
+                price,
+                created_at: self.env().block_timestamp(),
+                expires_at,
+                status: ListingStatus::Active,
+                buyer: None,
+                private_buyer,
+                min_down_payment: None,
+                asset: EscrowAsset::Native,
+            };
+            self.listings.insert(listing_id, &listing);
+            // A lapsed listing may still be indexed
+            self.clear_listing_index(property_id);
+            self.property_listings.insert(property_id, &listing_id);
+            self.active_listing_count += 1;
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyListed {
+                listing_id,
+                property_id,
+                seller,
+                event_version: 1,
+                price,
+                expires_at,
+                private: private_buyer.is_some(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            listing_id
+        }
+
+        /// Buys a listed property, paying the exact listing price with the call
+        /// The payment to the seller and the title transfer happen atomically; the buyer must
+        /// pass the compliance check.
+        #[ink(message, payable)]
+        pub fn buy_listing(&mut self, listing_id: u64) -> Result<(), Error> {
+            self.purchase_listing(listing_id, false)
+        }
+
+        /// Buys a listing that offers seller financing, paying part of the price now
+        /// The transferred value must be at least the listing's minimum down payment; the
+        /// title moves immediately with a lien in the seller's favour for the remainder.
+        #[ink(message, payable)]
+        pub fn buy_listing_financed(&mut self, listing_id: u64) -> Result<(), Error> {
+            self.purchase_listing(listing_id, true)
+        }
+
+        /// Offers seller financing on an active listing (seller only)
+        /// Passing `None` withdraws the offer; a down payment must be below the listing price.
+        #[ink(message)]
+        pub fn set_listing_financing(
+            &mut self,
+            listing_id: u64,
+            min_down_payment: Option<u128>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+
+            if listing.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+            if self.is_fractional(listing.property_id) {
+                return Err(Error::PropertyFractionalized);
+            }
+
+            if listing.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            if min_down_payment.is_some_and(|down| down == 0 || down >= listing.price) {
+                return Err(Error::InvalidListingPrice);
+            }
+
+            listing.min_down_payment = min_down_payment;
+            self.listings.insert(listing_id, &listing);
+            Ok(())
+        }
+
+        /// Pays down the lien on a property; the payment goes straight to the creditor
+        /// Anyone may repay on the debtor's behalf. The lien is cleared once nothing is owed.
+        #[ink(message, payable)]
+        pub fn repay_lien(&mut self, property_id: u64) -> Result<u128, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let amount = self.env().transferred_value();
+            let lien = self
+                .property_liens
+                .get(property_id)
+                .ok_or(Error::LienNotFound)?;
+
+            if amount == 0 || amount > lien.outstanding {
+                return Err(Error::IncorrectPayment);
+            }
+
+            self.env()
+                .transfer(lien.creditor, amount)
+                .map_err(|_| Error::EscrowTransferFailed)?;
+            self.update_lien(lien, amount, caller)
+        }
+
+        /// Clears a lien without further payment (creditor only)
+        #[ink(message)]
+        pub fn release_lien(&mut self, property_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let lien = self
+                .property_liens
+                .get(property_id)
+                .ok_or(Error::LienNotFound)?;
+
+            if lien.creditor != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            self.update_lien(lien, 0, caller)?;
+            Ok(())
+        }
+
+        /// Gets the lien recorded against a property, if any
+        #[ink(message)]
+        pub fn get_property_lien(&self, property_id: u64) -> Option<Lien> {
+            self.property_liens.get(property_id)
+        }
+
+        /// Helper: applies a repayment to a lien, clearing it when the balance reaches zero or
+        /// when nothing is repaid (a release); returns the balance still owed
+        fn update_lien(
+            &mut self,
+            mut lien: Lien,
+            repaid: u128,
+            updated_by: AccountId,
+        ) -> Result<u128, Error> {
+            lien.outstanding = if repaid == 0 {
+                0
+            } else {
+                lien.outstanding.saturating_sub(repaid)
+            };
+            if lien.outstanding == 0 {
+                self.property_liens.remove(lien.property_id);
+            } else {
+                self.property_liens.insert(lien.property_id, &lien);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LienUpdated {
+                property_id: lien.property_id,
+                creditor: lien.creditor,
+                debtor: lien.debtor,
+                event_version: 1,
+                repaid,
+                outstanding: lien.outstanding,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                updated_by,
+            });
+
+            Ok(lien.outstanding)
+        }
+
+        /// Helper: buys a listing outright or, when `financed`, with a down payment and a lien
+        fn purchase_listing(&mut self, listing_id: u64, financed: bool) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let transferred = self.env().transferred_value();
+            let mut listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+
+            if listing.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            if self.has_lapsed(listing.expires_at) {
+                return Err(Error::ListingExpired);
+            }
+
+            if listing.seller == caller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+
+            if listing
+                .private_buyer
+                .is_some_and(|private_buyer| private_buyer != caller)
+            {
+                return Err(Error::Unauthorized);
+            }
+
+            let paid = match listing.asset {
+                EscrowAsset::Native if financed => {
+                    let min_down_payment = listing
+                        .min_down_payment
+                        .ok_or(Error::FinancingNotAvailable)?;
+                    if transferred < min_down_payment || transferred >= listing.price {
+                        return Err(Error::IncorrectPayment);
+                    }
+                    transferred
+                }
+                EscrowAsset::Native => {
+                    if transferred != listing.price {
+                        return Err(Error::IncorrectPayment);
+                    }
+                    transferred
+                }
+                // Token-priced listings are paid in full through an allowance
+                EscrowAsset::Psp22(_) if financed => return Err(Error::UnsupportedEscrowAsset),
+                EscrowAsset::Psp22(_) => {
+                    if transferred != 0 {
+                        return Err(Error::IncorrectPayment);
+                    }
+                    listing.price
+                }
+            };
+
+            let mut property = self
+                .properties
+                .get(listing.property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            // The seller may have transferred the property since listing it. A transfer drops
+            // the listing from the index, so it stays dead even if the title comes back.
+            if property.owner != listing.seller
+                || self.property_listings.get(listing.property_id) != Some(listing_id)
+            {
+                return Err(Error::ListingNotActive);
+            }
+            self.check_compliance_in(
+                caller,
+                ComplianceOperation::Purchase,
+                property.metadata.jurisdiction,
+                property.metadata.valuation,
+            )?;
+
+            if let EscrowAsset::Psp22(token) = listing.asset {
+                self.pull_psp22(token, caller, listing.price)?;
+            }
+
+            self.settle_sale_with_payment(
+                SaleType::Listing,
+                listing_id,
+                listing.property_id,
+                listing.seller,
+                caller,
+                listing.price,
+                paid,
+                listing.asset,
+            )?;
+            // Marked sold first so the title move does not cancel the listing
+            listing.status = ListingStatus::Sold;
+            listing.buyer = Some(caller);
+            self.listings.insert(listing_id, &listing);
+            self.move_property(&mut property, caller, caller);
+
+            if paid < listing.price {
+                let lien = Lien {
+                    property_id: listing.property_id,
+                    creditor: listing.seller,
+                    debtor: caller,
+                    outstanding: listing.price - paid,
+                    created_at: self.env().block_timestamp(),
+                };
+                self.property_liens.insert(listing.property_id, &lien);
+
+                let transaction_hash: Hash = [0u8; 32].into();
+                self.env().emit_event(LienRecorded {
+                    property_id: lien.property_id,
+                    creditor: lien.creditor,
+                    debtor: lien.debtor,
+                    event_version: 1,
+                    amount: lien.outstanding,
+                    timestamp: lien.created_at,
+                    block_number: self.env().block_number(),
+                    transaction_hash,
+                });
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ListingPurchased {
+                listing_id,
+                property_id: listing.property_id,
+                buyer: caller,
+                seller: listing.seller,
+                event_version: 1,
+                price: listing.price,
+                private: listing.private_buyer.is_some(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Changes the price of an active listing (seller only)
+        #[ink(message)]
+        pub fn update_listing_price(
+            &mut self,
+            listing_id: u64,
+            new_price: u128,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+
+            if listing.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+            // Shareholders reprice a fractionalized property's listing by vote
+            if self.is_fractional(listing.property_id) {
+                return Err(Error::PropertyFractionalized);
+            }
+
+            self.reprice_listing(listing_id, new_price)
+        }
+
+        /// Helper: changes the price of an active listing
+        fn reprice_listing(&mut self, listing_id: u64, new_price: u128) -> Result<(), Error> {
+            let mut listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+
+            if listing.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            if self.has_lapsed(listing.expires_at) {
+                return Err(Error::ListingExpired);
+            }
+
+            if new_price == 0 {
+                return Err(Error::InvalidListingPrice);
+            }
+
+            let old_price = listing.price;
+            listing.price = new_price;
+            self.listings.insert(listing_id, &listing);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(ListingPriceUpdated {
+                listing_id,
+                property_id: listing.property_id,
+                event_version: 1,
+                old_price,
+                new_price,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Sets the asset an active listing must be paid in (seller only)
+        /// PSP22 prices are pulled from the buyer with `transfer_from`, so the buyer approves the
+        /// contract for the price before buying. The token must be accepted by the admin.
+        #[ink(message)]
+        pub fn set_listing_asset(
+            &mut self,
+            listing_id: u64,
+            asset: EscrowAsset,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+
+            if listing.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+            if self.is_fractional(listing.property_id) {
+                return Err(Error::PropertyFractionalized);
+            }
+
+            if listing.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            self.ensure_escrow_asset_accepted(asset)?;
+
+            listing.asset = asset;
+            self.listings.insert(listing_id, &listing);
+            Ok(())
+        }
+
+        /// Withdraws an active listing (seller only)
+        #[ink(message)]
+        pub fn delist_property(&mut self, listing_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut listing = self
+                .listings
+                .get(listing_id)
+                .ok_or(Error::ListingNotFound)?;
+
+            if listing.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+            if self.is_fractional(listing.property_id) {
+                return Err(Error::PropertyFractionalized);
+            }
+
+            if listing.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            listing.status = ListingStatus::Cancelled;
+            self.listings.insert(listing_id, &listing);
+            // The index may already point elsewhere if the property changed hands
+            if self.property_listings.get(listing.property_id) == Some(listing_id) {
+                self.clear_listing_index(listing.property_id);
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyDelisted {
+                listing_id,
+                property_id: listing.property_id,
+                seller: caller,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Helper: cancels the property's active listing, if any, and drops it from the index
+        /// Used when the title changes hands, so a stale listing cannot be bought should the
+        /// property return to its seller.
+        fn cancel_property_listing(&mut self, property_id: u64) {
+            let listing = self
+                .property_listings
+                .get(property_id)
+                .and_then(|listing_id| self.listings.get(listing_id));
+            self.clear_listing_index(property_id);
+            let Some(mut listing) = listing else {
+                return;
+            };
+            if listing.status != ListingStatus::Active {
+                return;
+            }
+            listing.status = ListingStatus::Cancelled;
+            self.listings.insert(listing.id, &listing);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PropertyDelisted {
+                listing_id: listing.id,
+                property_id,
+                seller: listing.seller,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+        }
+
+        /// Gets a listing by ID
+        #[ink(message)]
+        pub fn get_listing(&self, listing_id: u64) -> Option<Listing> {
+            self.listings.get(listing_id)
+        }
+
+        /// Gets the active listing for a property, if any
+        #[ink(message)]
+        pub fn get_property_listing(&self, property_id: u64) -> Option<Listing> {
+            self.property_listings
+                .get(property_id)
+                .and_then(|listing_id| self.listings.get(listing_id))
+        }
+
+        /// Gets the number of listings created so far
+        #[ink(message)]
+        pub fn listing_count(&self) -> u64 {
+            self.listing_count
+        }
+
+        /// Gets the number of fixed-price listings currently open for purchase
+        #[ink(message)]
+        pub fn active_listing_count(&self) -> u64 {
+            self.active_listing_count
+        }
+
+        /// Gets the total native value of all settled sales
+        #[ink(message)]
+        pub fn total_sales_volume(&self) -> u128 {
+            self.total_sales_volume
+        }
+
+        /// Gets the number of sales an account has settled as the seller
+        #[ink(message)]
+        pub fn get_seller_sales_count(&self, seller: AccountId) -> u64 {
+            self.seller_sale_counts.get(seller).unwrap_or(0)
+        }
+
+        /// Gets the average price of the most recent native-asset sales
+        /// At most `MAX_SALE_HISTORY` sales are considered; returns 0 if there are none.
+        #[ink(message)]
+        pub fn average_sale_price(&self, last_n: u32) -> u128 {
+            let mut total: u128 = 0;
+            let mut counted: u128 = 0;
+            let first = self
+                .sale_count
+                .saturating_sub(last_n.min(MAX_SALE_HISTORY) as u64);
+            for sale_id in (first + 1)..=self.sale_count {
+                if let Some(sale) = self.sale_records.get(sale_id) {
+                    if sale.asset == EscrowAsset::Native {
+                        total = total.saturating_add(sale.price);
+                        counted += 1;
+                    }
+                }
+            }
+            total.checked_div(counted).unwrap_or(0)
+        }
+
+        /// Helper: drops a property from the active-listing index, keeping the count in step
+        fn clear_listing_index(&mut self, property_id: u64) {
+            if self.property_listings.contains(property_id) {
+                self.property_listings.remove(property_id);
+                self.active_listing_count = self.active_listing_count.saturating_sub(1);
+            }
+        }
+
+        /// Lists several properties for sale together at one price (owner only)
+        /// The bundle must hold between two and `MAX_BUNDLE_SIZE` distinct properties, none of
+        /// which may already be on sale.
+        #[ink(message)]
+        pub fn list_bundle(&mut self, property_ids: Vec<u64>, price: u128) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            self.check_compliance(caller, ComplianceOperation::ListForSale)?;
+
+            if property_ids.len() < 2 || property_ids.len() as u32 > MAX_BUNDLE_SIZE {
+                return Err(Error::InvalidBundle);
+            }
+
+            if price == 0 {
+                return Err(Error::InvalidListingPrice);
+            }
+
+            for (index, &property_id) in property_ids.iter().enumerate() {
+                if property_ids[..index].contains(&property_id) {
+                    return Err(Error::InvalidBundle);
+                }
+                let property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+                if property.owner != caller {
+                    return Err(Error::Unauthorized);
+                }
+                self.ensure_whole_title_transferable(property_id)?;
+                self.ensure_verified_for_sale(property_id, SaleType::Listing)?;
+                self.ensure_not_on_sale(property_id)?;
+            }
+
+            self.bundle_count += 1;
+            let bundle_id = self.bundle_count;
+            for &property_id in property_ids.iter() {
+                self.property_bundles.insert(property_id, &bundle_id);
+            }
+            self.bundles.insert(
+                bundle_id,
+                &BundleListing {
+                    id: bundle_id,
+                    seller: caller,
+                    property_ids: property_ids.clone(),
+                    price,
+                    created_at: self.env().block_timestamp(),
+                    status: ListingStatus::Active,
+                    buyer: None,
+                },
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BundleListed {
+                bundle_id,
+                seller: caller,
+                event_version: 1,
+                property_ids,
+                price,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(bundle_id)
+        }
+
+        /// Buys a bundle, paying the exact bundle price with the call
+        /// Every title moves to the buyer in the same call, or none do.
+        #[ink(message, payable)]
+        pub fn buy_bundle(&mut self, bundle_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let transferred = self.env().transferred_value();
+            let mut bundle = self.bundles.get(bundle_id).ok_or(Error::BundleNotFound)?;
+
+            if bundle.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            if bundle.seller == caller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+
+            if transferred != bundle.price {
+                return Err(Error::IncorrectPayment);
+            }
+
+            // Check every title before moving any of them
+            let mut properties = Vec::new();
+            for &property_id in bundle.property_ids.iter() {
+                let property = self
+                    .properties
+                    .get(property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+                if property.owner != bundle.seller {
+                    return Err(Error::ListingNotActive);
+                }
+                self.ensure_whole_title_transferable(property_id)?;
+                properties.push(property);
+            }
+            for property in properties.iter() {
+                self.check_compliance_in(
+                    caller,
+                    ComplianceOperation::Purchase,
+                    property.metadata.jurisdiction,
+                    property.metadata.valuation,
+                )?;
+            }
+
+            // Royalties are charged on an even split of the bundle price
+            let count = bundle.property_ids.len() as u128;
+            let share = bundle.price / count;
+            let remainder = bundle.price % count;
+            for (index, &property_id) in bundle.property_ids.iter().enumerate() {
+                let amount = if index == 0 { share + remainder } else { share };
+                self.settle_sale(
+                    SaleType::Listing,
+                    bundle_id,
+                    property_id,
+                    bundle.seller,
+                    caller,
+                    amount,
+                )?;
+            }
+            for property in properties.iter_mut() {
+                self.move_property(property, caller, caller);
+            }
+
+            bundle.status = ListingStatus::Sold;
+            bundle.buyer = Some(caller);
+            self.close_bundle(&bundle);
+
+            Ok(())
+        }
+
+        /// Withdraws an active bundle listing (seller only)
+        #[ink(message)]
+        pub fn cancel_bundle(&mut self, bundle_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut bundle = self.bundles.get(bundle_id).ok_or(Error::BundleNotFound)?;
+
+            if bundle.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if bundle.status != ListingStatus::Active {
+                return Err(Error::ListingNotActive);
+            }
+
+            bundle.status = ListingStatus::Cancelled;
+            self.close_bundle(&bundle);
+
+            Ok(())
+        }
+
+        /// Gets a bundle listing by ID
+        #[ink(message)]
+        pub fn get_bundle(&self, bundle_id: u64) -> Option<BundleListing> {
+            self.bundles.get(bundle_id)
+        }
+
+        /// Helper: stores a closed bundle, releases its properties from the index and emits
+        /// the event
+        fn close_bundle(&mut self, bundle: &BundleListing) {
+            self.bundles.insert(bundle.id, bundle);
+            for &property_id in bundle.property_ids.iter() {
+                // Properties transferred since listing may already be indexed elsewhere
+                if self.property_bundles.get(property_id) == Some(bundle.id) {
+                    self.property_bundles.remove(property_id);
+                }
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BundleClosed {
+                bundle_id: bundle.id,
+                seller: bundle.seller,
+                buyer: bundle.buyer,
+                event_version: 1,
+                status: bundle.status,
+                property_ids: bundle.property_ids.clone(),
+                price: bundle.price,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+        }
+
+        /// Starts an ascending-price auction for a property (owner only)
+        /// Bids are accepted for `duration` milliseconds and must meet the reserve price.
+        #[ink(message)]
+        pub fn start_auction(
+            &mut self,
+            property_id: u64,
+            reserve_price: u128,
+            duration: u64,
+        ) -> Result<u64, Error> {
+            self.start_auction_with_reserve(property_id, reserve_price, reserve_price, duration)
+        }
+
+        /// Starts an ascending-price auction whose bidding opens below the reserve (owner only)
+        /// Bids from `opening_bid` upwards are accepted, but if the highest bid is still below
+        /// `reserve_price` at the end the auction fails and the seller keeps the property.
+        #[ink(message)]
+        pub fn start_auction_with_reserve(
+            &mut self,
+            property_id: u64,
+            opening_bid: u128,
+            reserve_price: u128,
+            duration: u64,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.check_property_compliance(caller, ComplianceOperation::ListForSale, property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Auction)?;
+
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if duration == 0 {
+                return Err(Error::InvalidAuctionDuration);
+            }
+
+            if opening_bid > reserve_price {
+                return Err(Error::InvalidAuctionPrice);
+            }
+
+            self.ensure_not_on_sale(property_id)?;
+
+            let now = self.env().block_timestamp();
+            self.auction_count += 1;
+            let auction_id = self.auction_count;
+            let auction = Auction {
+                id: auction_id,
+                property_id,
+                seller: caller,
+                reserve_price,
+                opening_bid,
+                highest_bid: 0,
+                highest_bidder: None,
+                started_at: now,
+                ends_at: now.saturating_add(duration),
+                status: AuctionStatus::Active,
+                min_bid_increment: 0,
+                extension_window: 0,
+            };
+            self.auctions.insert(auction_id, &auction);
+            self.property_auctions.insert(property_id, &auction_id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(AuctionStarted {
+                auction_id,
+                property_id,
+                seller: caller,
+                event_version: 1,
+                reserve_price,
+                ends_at: auction.ends_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(auction_id)
+        }
+
+        /// Bids on a running auction with the transferred value
+        /// The bid must meet the opening bid and beat the current highest bid; the previous
+        /// highest bid becomes withdrawable by its bidder through `withdraw_bid`.
+        #[ink(message, payable)]
+        pub fn bid(&mut self, auction_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let amount = self.env().transferred_value();
+            let mut auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
+            }
+
+            if self.env().block_timestamp() >= auction.ends_at {
+                return Err(Error::AuctionEnded);
+            }
+
+            if auction.seller == caller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
+
+            let min_bid = if auction.highest_bidder.is_some() {
+                auction
+                    .highest_bid
+                    .saturating_add(auction.min_bid_increment)
+            } else {
+                auction.opening_bid
+            };
+            if amount < min_bid || amount <= auction.highest_bid {
+                return Err(Error::BidTooLow);
+            }
+
+            self.check_property_compliance(
+                caller,
+                ComplianceOperation::Purchase,
+                auction.property_id,
+            )?;
+
+            let outbid = auction.highest_bidder;
+            if let Some(previous) = outbid {
+                self.credit_auction_refund(auction_id, previous, auction.highest_bid);
+            }
+
+            // Late bids extend the auction so other bidders have time to respond
+            let now = self.env().block_timestamp();
+            if auction.ends_at.saturating_sub(now) < auction.extension_window {
+                auction.ends_at = now.saturating_add(auction.extension_window);
+            }
+
+            auction.highest_bid = amount;
+            auction.highest_bidder = Some(caller);
+            self.auctions.insert(auction_id, &auction);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(BidPlaced {
+                auction_id,
+                bidder: caller,
+                event_version: 1,
+                amount,
+                outbid,
+                ends_at: auction.ends_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Sets an auction's minimum bid increment and anti-sniping extension window (seller only)
+        /// Rules can only change before the first bid.
+        #[ink(message)]
+        pub fn set_auction_bidding_rules(
+            &mut self,
+            auction_id: u64,
+            min_bid_increment: u128,
+            extension_window: u64,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+
+            if auction.seller != caller {
+                return Err(Error::Unauthorized);
+            }
+
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
+            }
+
+            if auction.highest_bidder.is_some() {
+                return Err(Error::AuctionHasBids);
+            }
+
+            auction.min_bid_increment = min_bid_increment;
+            auction.extension_window = extension_window;
+            self.auctions.insert(auction_id, &auction);
+            Ok(())
+        }
+
+        /// Closes an auction after its deadline (callable by anyone)
+        /// The highest bidder receives the title and the seller the winning bid. If the bid is
+        /// below the reserve, the seller no longer owns the property or its whole title can no
+        /// longer be sold, the sale fails and the highest bid becomes withdrawable instead.
+        #[ink(message)]
+        pub fn finalize_auction(&mut self, auction_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
+            }
+
+            if self.env().block_timestamp() < auction.ends_at {
+                return Err(Error::AuctionNotEnded);
+            }
+
+            // Unindexed before the title moves, so the transfer does not cancel this auction
+            self.property_auctions.remove(auction.property_id);
+            let mut winner = None;
+            auction.status = AuctionStatus::Finalized;
+            if let Some(bidder) = auction.highest_bidder {
+                let mut property = self
+                    .properties
+                    .get(auction.property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+                if auction.highest_bid < auction.reserve_price {
+                    auction.status = AuctionStatus::ReserveNotMet;
+                    self.credit_auction_refund(auction_id, bidder, auction.highest_bid);
+                } else if property.owner == auction.seller
+                    && self
+                        .ensure_whole_title_transferable(auction.property_id)
+                        .is_ok()
+                    && self
+                        .check_settlement_compliance(
+                            bidder,
+                            ComplianceOperation::Purchase,
+                            auction.property_id,
+                        )
+                        .is_ok()
+                {
+                    self.settle_sale(
+                        SaleType::Auction,
+                        auction_id,
+                        auction.property_id,
+                        auction.seller,
+                        bidder,
+                        auction.highest_bid,
+                    )?;
+                    self.move_property(&mut property, bidder, caller);
+                    winner = Some(bidder);
+                } else {
+                    self.credit_auction_refund(auction_id, bidder, auction.highest_bid);
+                }
+            }
+
+            self.auctions.insert(auction_id, &auction);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(AuctionFinalized {
+                auction_id,
+                property_id: auction.property_id,
+                winner,
+                event_version: 1,
+                amount: if winner.is_some() {
+                    auction.highest_bid
+                } else {
+                    0
+                },
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                finalized_by: caller,
             });
 
-            contract
+            Ok(())
         }
 
-        /// Returns the contract version
+        /// Withdraws the caller's outbid or unsuccessful bids on an auction
+        /// Returns the amount withdrawn.
         #[ink(message)]
-        pub fn version(&self) -> u32 {
-            self.version
+        pub fn withdraw_bid(&mut self, auction_id: u64) -> Result<u128, Error> {
+            let caller = self.env().caller();
+            let amount = self
+                .auction_refunds
+                .get((auction_id, caller))
+                .ok_or(Error::BidNotFound)?;
+
+            self.auction_refunds.remove((auction_id, caller));
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| Error::EscrowTransferFailed)?;
+
+            Ok(amount)
         }
 
-        /// Returns the admin account
+        /// Gets the amount a bidder can withdraw from an auction
         #[ink(message)]
-        pub fn admin(&self) -> AccountId {
-            self.admin
+        pub fn get_withdrawable_bid(&self, auction_id: u64, bidder: AccountId) -> u128 {
+            self.auction_refunds.get((auction_id, bidder)).unwrap_or(0)
         }
 
-        /// Set the oracle contract address
-        #[ink(message)]
-        pub fn set_oracle(&mut self, oracle: AccountId) -> Result<(), Error> {
-            let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::Unauthorized);
-            }
-            self.oracle = Some(oracle);
-            Ok(())
+        /// Helper: makes a returned bid withdrawable by its bidder
+        fn credit_auction_refund(&mut self, auction_id: u64, bidder: AccountId, amount: u128) {
+            let pending = self.get_withdrawable_bid(auction_id, bidder);
+            self.auction_refunds
+                .insert((auction_id, bidder), &pending.saturating_add(amount));
         }
 
-        /// Returns the oracle contract address
-        #[ink(message)]
-        pub fn oracle(&self) -> Option<AccountId> {
-            self.oracle
+        /// Helper: cancels the property's running English and sealed-bid auctions, if any
+        /// Used when the title changes hands outside the auction. The highest English bid
+        /// becomes withdrawable, and sealed-bid deposits are returned in full through
+        /// `withdraw_sealed_deposit`.
+        fn cancel_property_auctions(&mut self, property_id: u64, by: AccountId) {
+            let transaction_hash: Hash = [0u8; 32].into();
+            if let Some(auction_id) = self.property_auctions.get(property_id) {
+                self.property_auctions.remove(property_id);
+                if let Some(mut auction) = self
+                    .auctions
+                    .get(auction_id)
+                    .filter(|auction| auction.status == AuctionStatus::Active)
+                {
+                    auction.status = AuctionStatus::Cancelled;
+                    if let Some(bidder) = auction.highest_bidder {
+                        self.credit_auction_refund(auction_id, bidder, auction.highest_bid);
+                    }
+                    self.auctions.insert(auction_id, &auction);
+
+                    self.env().emit_event(AuctionFinalized {
+                        auction_id,
+                        property_id,
+                        winner: None,
+                        event_version: 1,
+                        amount: 0,
+                        timestamp: self.env().block_timestamp(),
+                        block_number: self.env().block_number(),
+                        transaction_hash,
+                        finalized_by: by,
+                    });
+                }
+            }
+
+            if let Some(auction_id) = self.property_sealed_auctions.get(property_id) {
+                self.property_sealed_auctions.remove(property_id);
+                if let Some(mut auction) = self
+                    .sealed_auctions
+                    .get(auction_id)
+                    .filter(|auction| auction.status == AuctionStatus::Active)
+                {
+                    auction.status = AuctionStatus::Cancelled;
+                    self.sealed_auctions.insert(auction_id, &auction);
+
+                    self.env().emit_event(SealedAuctionFinalized {
+                        auction_id,
+                        property_id,
+                        winner: None,
+                        event_version: 1,
+                        amount: 0,
+                        timestamp: self.env().block_timestamp(),
+                        block_number: self.env().block_number(),
+                        transaction_hash,
+                        finalized_by: by,
+                    });
+                }
+            }
         }
 
-        /// Set the fee manager contract address (admin only)
+        /// Gets an auction by ID
         #[ink(message)]
-        pub fn set_fee_manager(&mut self, fee_manager: Option<AccountId>) -> Result<(), Error> {
-            let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::Unauthorized);
-            }
-            self.fee_manager = fee_manager;
-            Ok(())
+        pub fn get_auction(&self, auction_id: u64) -> Option<Auction> {
+            self.auctions.get(auction_id)
         }
 
-        /// Returns the fee manager contract address
+        /// Gets the running auction for a property, if any
         #[ink(message)]
-        pub fn get_fee_manager(&self) -> Option<AccountId> {
-            self.fee_manager
+        pub fn get_property_auction(&self, property_id: u64) -> Option<Auction> {
+            self.property_auctions
+                .get(property_id)
+                .and_then(|auction_id| self.auctions.get(auction_id))
         }
 
-        /// Get dynamic fee for an operation (calls fee manager if set; otherwise returns 0)
+        /// Gets the number of auctions started so far
         #[ink(message)]
-        pub fn get_dynamic_fee(&self, operation: FeeOperation) -> u128 {
-            let fee_manager_addr = match self.fee_manager {
-                Some(addr) => addr,
-                None => return 0,
-            };
-            use ink::env::call::FromAccountId;
-            let fee_manager: ink::contract_ref!(DynamicFeeProvider) =
-                FromAccountId::from_account_id(fee_manager_addr);
-            fee_manager.get_recommended_fee(operation)
+        pub fn auction_count(&self) -> u64 {
+            self.auction_count
         }
 
-        /// Update property valuation using the oracle
+        /// Starts a declining-price auction for a property (owner only)
+        /// The price decays from `start_price` to `floor_price` over `duration` milliseconds.
         #[ink(message)]
-        pub fn update_valuation_from_oracle(&mut self, property_id: u64) -> Result<(), Error> {
-            let oracle_addr = self.oracle.ok_or(Error::OracleError)?;
+        pub fn start_dutch_auction(
+            &mut self,
+            property_id: u64,
+            start_price: u128,
+            floor_price: u128,
+            duration: u64,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.check_property_compliance(caller, ComplianceOperation::ListForSale, property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Auction)?;
 
-            // Use the Oracle trait to perform the cross-contract call
-            use ink::env::call::FromAccountId;
-            let oracle: ink::contract_ref!(Oracle) = FromAccountId::from_account_id(oracle_addr);
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
 
-            // Fetch valuation from oracle
-            let valuation = oracle
-                .get_valuation(property_id)
-                .map_err(|_| Error::OracleError)?;
+            if duration == 0 {
+                return Err(Error::InvalidAuctionDuration);
+            }
 
-            // Update the property's recorded valuation in its metadata
-            if let Some(mut property) = self.properties.get(&property_id) {
-                property.metadata.valuation = valuation.valuation;
-                self.properties.insert(&property_id, &property);
-            } else {
-                return Err(Error::PropertyNotFound);
+            if floor_price == 0 || floor_price > start_price {
+                return Err(Error::InvalidAuctionPrice);
             }
 
-            Ok(())
+            self.ensure_not_on_sale(property_id)?;
+
+            let now = self.env().block_timestamp();
+            self.dutch_auction_count += 1;
+            let auction_id = self.dutch_auction_count;
+            let auction = DutchAuction {
+                id: auction_id,
+                property_id,
+                seller: caller,
+                start_price,
+                floor_price,
+                started_at: now,
+                ends_at: now.saturating_add(duration),
+                status: AuctionStatus::Active,
+                buyer: None,
+                sold_price: 0,
+            };
+            self.dutch_auctions.insert(auction_id, &auction);
+            self.property_dutch_auctions
+                .insert(property_id, &auction_id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(DutchAuctionStarted {
+                auction_id,
+                property_id,
+                seller: caller,
+                event_version: 1,
+                start_price,
+                floor_price,
+                ends_at: auction.ends_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+
+            Ok(auction_id)
         }
 
-        /// Changes the admin account (only callable by current admin)
-        #[ink(message)]
-        pub fn change_admin(&mut self, new_admin: AccountId) -> Result<(), Error> {
+        /// Buys a declining-price auction at its current price
+        /// The transferred value must cover the current price; any surplus is returned.
+        #[ink(message, payable)]
+        pub fn buy_dutch_auction(&mut self, auction_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
             let caller = self.env().caller();
-            if caller != self.admin {
-                return Err(Error::Unauthorized);
+            let transferred = self.env().transferred_value();
+            let mut auction = self
+                .dutch_auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
             }
 
-            let old_admin = self.admin;
-            self.admin = new_admin;
+            if auction.seller == caller {
+                return Err(Error::CannotBuyOwnProperty);
+            }
 
-            // Emit enhanced admin changed event
+            let price = self.dutch_price(&auction);
+            if transferred < price {
+                return Err(Error::IncorrectPayment);
+            }
+
+            let mut property = self
+                .properties
+                .get(auction.property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            if property.owner != auction.seller {
+                return Err(Error::AuctionNotActive);
+            }
+            self.check_compliance_in(
+                caller,
+                ComplianceOperation::Purchase,
+                property.metadata.jurisdiction,
+                property.metadata.valuation,
+            )?;
+
+            self.settle_sale(
+                SaleType::Auction,
+                auction_id,
+                auction.property_id,
+                auction.seller,
+                caller,
+                price,
+            )?;
+            if transferred > price {
+                self.env()
+                    .transfer(caller, transferred - price)
+                    .map_err(|_| Error::EscrowTransferFailed)?;
+            }
+            self.move_property(&mut property, caller, caller);
+
+            auction.status = AuctionStatus::Finalized;
+            auction.buyer = Some(caller);
+            auction.sold_price = price;
+            self.dutch_auctions.insert(auction_id, &auction);
+            self.property_dutch_auctions.remove(auction.property_id);
 
             let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(AdminChanged {
-                old_admin,
-                new_admin,
+            self.env().emit_event(DutchAuctionClosed {
+                auction_id,
+                property_id: auction.property_id,
+                buyer: Some(caller),
                 event_version: 1,
+                price,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
                 transaction_hash,
-                changed_by: caller,
             });
 
             Ok(())
         }
 
-        /// Sets the compliance registry contract address (admin only)
+        /// Cancels an unsold declining-price auction (seller only)
         #[ink(message)]
-        pub fn set_compliance_registry(
-            &mut self,
-            registry: Option<AccountId>,
-        ) -> Result<(), Error> {
+        pub fn cancel_dutch_auction(&mut self, auction_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
-            if caller != self.admin {
+            let mut auction = self
+                .dutch_auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+
+            if auction.seller != caller {
                 return Err(Error::Unauthorized);
             }
-            self.compliance_registry = registry;
-            Ok(())
-        }
 
-        /// Gets the compliance registry address
-        #[ink(message)]
-        pub fn get_compliance_registry(&self) -> Option<AccountId> {
-            self.compliance_registry
-        }
-
-        /// Helper: Check compliance for an account via the compliance registry (Issue #45).
-        /// Returns Ok if compliant or no registry set, Err(NotCompliant) or Err(ComplianceCheckFailed) otherwise.
-        fn check_compliance(&self, account: AccountId) -> Result<(), Error> {
-            let registry_addr = match self.compliance_registry {
-                Some(addr) => addr,
-                None => return Ok(()),
-            };
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
+            }
 
-            use ink::env::call::FromAccountId;
-            let registry: ink::contract_ref!(ComplianceChecker) =
-                FromAccountId::from_account_id(registry_addr);
+            auction.status = AuctionStatus::Cancelled;
+            self.dutch_auctions.insert(auction_id, &auction);
+            if self.property_dutch_auctions.get(auction.property_id) == Some(auction_id) {
+                self.property_dutch_auctions.remove(auction.property_id);
+            }
 
-            let is_compliant = registry.is_compliant(account);
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(DutchAuctionClosed {
+                auction_id,
+                property_id: auction.property_id,
+                buyer: None,
+                event_version: 1,
+                price: 0,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
 
-            if !is_compliant {
-                return Err(Error::NotCompliant);
-            }
             Ok(())
         }
 
-        /// Check if an account is compliant (delegates to registry when set). For use by frontends.
+        /// Gets the current price of an active declining-price auction
         #[ink(message)]
-        pub fn check_account_compliance(&self, account: AccountId) -> Result<bool, Error> {
-            if self.compliance_registry.is_none() {
-                return Ok(true);
-            }
-            let registry_addr = self.compliance_registry.unwrap();
-            use ink::env::call::FromAccountId;
-            let registry: ink::contract_ref!(ComplianceChecker) =
-                FromAccountId::from_account_id(registry_addr);
-            Ok(registry.is_compliant(account))
+        pub fn get_dutch_auction_price(&self, auction_id: u64) -> Option<u128> {
+            self.dutch_auctions
+                .get(auction_id)
+                .filter(|auction| auction.status == AuctionStatus::Active)
+                .map(|auction| self.dutch_price(&auction))
         }
 
-        /// Helper to check if contract is paused
-        pub fn ensure_not_paused(&self) -> Result<(), Error> {
-            if self.pause_info.paused {
-                // Check for auto-resume
-                if let Some(resume_time) = self.pause_info.auto_resume_at {
-                    if self.env().block_timestamp() >= resume_time {
-                        // In a real scenario we might want to auto-resume here or require a trigger.
-                        // For safety, we usually require explicit resume even if time passed,
-                        // purely to update the state, OR we treat it as not paused.
-                        // However, since state mutability is needed to update 'paused' flag,
-                        // and this is a read-only check often, we'll return Error::ContractPaused
-                        // unless someone triggers the resume.
-                        // But requirements say "Time-based automatic resume".
-                        // Use a separate method or assume logic handles it.
-                        // For strict safety:
-                        return Err(Error::ContractPaused);
-                    }
-                }
-                return Err(Error::ContractPaused);
-            }
-            Ok(())
+        /// Gets a declining-price auction by ID
+        #[ink(message)]
+        pub fn get_dutch_auction(&self, auction_id: u64) -> Option<DutchAuction> {
+            self.dutch_auctions.get(auction_id)
         }
 
-        // --- Pause/Resume Functionality ---
-
-        /// Pauses the contract. Can be called by admin or pause guardians.
+        /// Starts a sealed-bid auction for a property (owner only)
+        /// Bids are committed for `commit_duration` milliseconds and then revealed for
+        /// `reveal_duration` milliseconds; the highest valid revealed bid wins.
         #[ink(message)]
-        pub fn pause_contract(
+        pub fn start_sealed_auction(
             &mut self,
-            reason: String,
-            duration_seconds: Option<u64>,
-        ) -> Result<(), Error> {
+            property_id: u64,
+            reserve_price: u128,
+            commit_duration: u64,
+            reveal_duration: u64,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
             let caller = self.env().caller();
-            let is_admin = caller == self.admin;
-            let is_guardian = self.pause_guardians.get(caller).unwrap_or(false);
+            let property = self
+                .properties
+                .get(property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.check_property_compliance(caller, ComplianceOperation::ListForSale, property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Auction)?;
 
-            if !is_admin && !is_guardian {
-                return Err(Error::NotAuthorizedToPause);
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
             }
 
-            if self.pause_info.paused {
-                return Err(Error::AlreadyPaused);
+            if commit_duration == 0 || reveal_duration == 0 {
+                return Err(Error::InvalidAuctionDuration);
             }
 
-            let timestamp = self.env().block_timestamp();
-            let auto_resume_at = duration_seconds.map(|d| timestamp + d);
-
-            self.pause_info.paused = true;
-            self.pause_info.paused_at = Some(timestamp);
-            self.pause_info.paused_by = Some(caller);
-            self.pause_info.reason = Some(reason.clone());
-            self.pause_info.auto_resume_at = auto_resume_at;
+            self.ensure_not_on_sale(property_id)?;
 
-            // Clear any previous resume requests
-            self.pause_info.resume_request_active = false;
-            self.pause_info.resume_approvals.clear();
+            let now = self.env().block_timestamp();
+            let commit_ends_at = now.saturating_add(commit_duration);
+            self.sealed_auction_count += 1;
+            let auction_id = self.sealed_auction_count;
+            let auction = SealedAuction {
+                id: auction_id,
+                property_id,
+                seller: caller,
+                reserve_price,
+                commit_ends_at,
+                reveal_ends_at: commit_ends_at.saturating_add(reveal_duration),
+                highest_bid: 0,
+                highest_bidder: None,
+                status: AuctionStatus::Active,
+            };
+            self.sealed_auctions.insert(auction_id, &auction);
+            self.property_sealed_auctions
+                .insert(property_id, &auction_id);
 
-            self.env().emit_event(ContractPaused {
-                by: caller,
-                reason,
-                timestamp,
-                auto_resume_at,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SealedAuctionStarted {
+                auction_id,
+                property_id,
+                seller: caller,
+                event_version: 1,
+                reserve_price,
+                commit_ends_at,
+                reveal_ends_at: auction.reveal_ends_at,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
 
-            Ok(())
-        }
-
-        /// Emergency pause - same as pause but implies critical severity
-        #[ink(message)]
-        pub fn emergency_pause(&mut self, reason: String) -> Result<(), Error> {
-            self.pause_contract(reason, None)
+            Ok(auction_id)
         }
 
-        /// Provide a mechanism to try auto-resume if time passed
-        #[ink(message)]
-        pub fn try_auto_resume(&mut self) -> Result<(), Error> {
-            if !self.pause_info.paused {
-                return Err(Error::NotPaused);
-            }
-
-            if let Some(resume_time) = self.pause_info.auto_resume_at {
-                if self.env().block_timestamp() >= resume_time {
-                    self.pause_info.paused = false;
-                    self.pause_info.reason = None;
-
-                    self.env().emit_event(ContractResumed {
-                        by: self.env().caller(), // triggered by
-                        timestamp: self.env().block_timestamp(),
-                    });
-                    return Ok(());
-                }
+        /// Commits a sealed bid during the commit window, locking the transferred deposit
+        /// The commitment is `compute_bid_commitment(auction_id, caller, amount, salt)`; the
+        /// deposit should be at least the bid and may exceed it to hide the amount.
+        #[ink(message, payable)]
+        pub fn commit_sealed_bid(
+            &mut self,
+            auction_id: u64,
+            commitment: Hash,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let deposit = self.env().transferred_value();
+            let auction = self
+                .sealed_auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
             }
-            Err(Error::ContractPaused)
-        }
 
-        /// Request to resume the contract. Requires multi-sig approval.
-        #[ink(message)]
-        pub fn request_resume(&mut self) -> Result<(), Error> {
-            let caller = self.env().caller();
-            // Only admin or guardians can request resume
-            let is_admin = caller == self.admin;
-            let is_guardian = self.pause_guardians.get(caller).unwrap_or(false);
+            if self.env().block_timestamp() >= auction.commit_ends_at {
+                return Err(Error::AuctionEnded);
+            }
 
-            if !is_admin && !is_guardian {
-                return Err(Error::Unauthorized);
+            if auction.seller == caller {
+                return Err(Error::CannotBuyOwnProperty);
             }
 
-            if !self.pause_info.paused {
-                return Err(Error::NotPaused);
+            if deposit == 0 {
+                return Err(Error::IncorrectPayment);
             }
 
-            if self.pause_info.resume_request_active {
-                return Err(Error::ResumeRequestAlreadyActive);
+            if self.sealed_bids.contains((auction_id, caller)) {
+                return Err(Error::BidAlreadyCommitted);
             }
 
-            self.pause_info.resume_request_active = true;
-            self.pause_info.resume_requester = Some(caller);
-            self.pause_info.resume_approvals.clear();
-            // Auto-approve by requester? Usually yes, let's say yes.
-            self.pause_info.resume_approvals.push(caller);
+            self.check_property_compliance(
+                caller,
+                ComplianceOperation::Purchase,
+                auction.property_id,
+            )?;
+
+            self.sealed_bids.insert(
+                (auction_id, caller),
+                &SealedBid {
+                    commitment,
+                    deposit,
+                    revealed_amount: None,
+                },
+            );
 
-            self.env().emit_event(ResumeRequested {
-                requester: caller,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SealedBidSubmitted {
+                auction_id,
+                bidder: caller,
+                event_version: 1,
+                deposit,
+                revealed_amount: None,
                 timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
 
-            // If only 1 approval required (e.g. dev mode), check immediately
-            if self.pause_info.required_approvals <= 1 {
-                self._execute_resume()?;
-            }
-
             Ok(())
         }
 
-        /// Approve the pending resume request
+        /// Reveals a committed bid during the reveal window
+        /// The bid must match the commitment, be covered by the deposit and meet the reserve.
         #[ink(message)]
-        pub fn approve_resume(&mut self) -> Result<(), Error> {
+        pub fn reveal_sealed_bid(
+            &mut self,
+            auction_id: u64,
+            amount: u128,
+            salt: Hash,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
             let caller = self.env().caller();
-            let is_admin = caller == self.admin;
-            let is_guardian = self.pause_guardians.get(caller).unwrap_or(false);
+            let mut auction = self
+                .sealed_auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+            let mut bid = self
+                .sealed_bids
+                .get((auction_id, caller))
+                .ok_or(Error::BidNotFound)?;
+
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
+            }
 
-            if !is_admin && !is_guardian {
-                return Err(Error::Unauthorized);
+            let now = self.env().block_timestamp();
+            if now < auction.commit_ends_at {
+                return Err(Error::RevealNotOpen);
+            }
+            if now >= auction.reveal_ends_at {
+                return Err(Error::AuctionEnded);
             }
 
-            if !self.pause_info.resume_request_active {
-                return Err(Error::ResumeRequestNotFound);
+            if bid.revealed_amount.is_some()
+                || self.compute_bid_commitment(auction_id, caller, amount, salt) != bid.commitment
+                || amount > bid.deposit
+            {
+                return Err(Error::InvalidBidReveal);
             }
 
-            if self.pause_info.resume_approvals.contains(&caller) {
-                return Err(Error::AlreadyApproved);
+            if amount < auction.reserve_price {
+                return Err(Error::BidTooLow);
             }
 
-            self.pause_info.resume_approvals.push(caller);
+            bid.revealed_amount = Some(amount);
+            self.sealed_bids.insert((auction_id, caller), &bid);
 
-            let approvals_count = self.pause_info.resume_approvals.len() as u32;
+            // Ties go to the earlier reveal
+            if amount > auction.highest_bid {
+                auction.highest_bid = amount;
+                auction.highest_bidder = Some(caller);
+                self.sealed_auctions.insert(auction_id, &auction);
+            }
 
-            self.env().emit_event(ResumeApproved {
-                approver: caller,
-                current_approvals: approvals_count,
-                required_approvals: self.pause_info.required_approvals,
-                timestamp: self.env().block_timestamp(),
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SealedBidSubmitted {
+                auction_id,
+                bidder: caller,
+                event_version: 1,
+                deposit: bid.deposit,
+                revealed_amount: Some(amount),
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
 
-            if approvals_count >= self.pause_info.required_approvals {
-                self._execute_resume()?;
-            }
-
             Ok(())
         }
 
-        fn _execute_resume(&mut self) -> Result<(), Error> {
-            self.pause_info.paused = false;
-            self.pause_info.resume_request_active = false;
-            self.pause_info.reason = None;
+        /// Closes a sealed-bid auction after the reveal window (callable by anyone)
+        /// The highest revealed bidder receives the title and the seller the winning bid; if the
+        /// sale cannot go through, the winner keeps their full deposit.
+        #[ink(message)]
+        pub fn finalize_sealed_auction(&mut self, auction_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut auction = self
+                .sealed_auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
 
-            self.env().emit_event(ContractResumed {
-                by: self.env().caller(),
+            if auction.status != AuctionStatus::Active {
+                return Err(Error::AuctionNotActive);
+            }
+
+            if self.env().block_timestamp() < auction.reveal_ends_at {
+                return Err(Error::AuctionNotEnded);
+            }
+
+            // Unindexed before the title moves, so the transfer does not cancel this auction
+            self.property_sealed_auctions.remove(auction.property_id);
+            let mut winner = None;
+            if let Some(bidder) = auction.highest_bidder {
+                let mut property = self
+                    .properties
+                    .get(auction.property_id)
+                    .ok_or(Error::PropertyNotFound)?;
+                let mut bid = self
+                    .sealed_bids
+                    .get((auction_id, bidder))
+                    .ok_or(Error::BidNotFound)?;
+                if property.owner == auction.seller
+                    && self
+                        .ensure_whole_title_transferable(auction.property_id)
+                        .is_ok()
+                    && self
+                        .check_settlement_compliance(
+                            bidder,
+                            ComplianceOperation::Purchase,
+                            auction.property_id,
+                        )
+                        .is_ok()
+                {
+                    self.settle_sale(
+                        SaleType::Auction,
+                        auction_id,
+                        auction.property_id,
+                        auction.seller,
+                        bidder,
+                        auction.highest_bid,
+                    )?;
+                    bid.deposit = bid.deposit.saturating_sub(auction.highest_bid);
+                    self.sealed_bids.insert((auction_id, bidder), &bid);
+                    self.move_property(&mut property, bidder, caller);
+                    winner = Some(bidder);
+                }
+            }
+
+            auction.status = AuctionStatus::Finalized;
+            self.sealed_auctions.insert(auction_id, &auction);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SealedAuctionFinalized {
+                auction_id,
+                property_id: auction.property_id,
+                winner,
+                event_version: 1,
+                amount: if winner.is_some() {
+                    auction.highest_bid
+                } else {
+                    0
+                },
                 timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                finalized_by: caller,
             });
+
             Ok(())
         }
 
-        /// Manage pause guardians
+        /// Withdraws the caller's remaining deposit from a finalized sealed-bid auction
+        /// Losing and unrevealed bids get their whole deposit back; the winner gets back
+        /// whatever exceeded the winning bid. Returns the amount withdrawn.
         #[ink(message)]
-        pub fn set_pause_guardian(
-            &mut self,
-            guardian: AccountId,
-            is_enabled: bool,
-        ) -> Result<(), Error> {
-            if self.env().caller() != self.admin {
-                return Err(Error::Unauthorized);
+        pub fn withdraw_sealed_deposit(&mut self, auction_id: u64) -> Result<u128, Error> {
+            let caller = self.env().caller();
+            let auction = self
+                .sealed_auctions
+                .get(auction_id)
+                .ok_or(Error::AuctionNotFound)?;
+            let bid = self
+                .sealed_bids
+                .get((auction_id, caller))
+                .ok_or(Error::BidNotFound)?;
+
+            if auction.status == AuctionStatus::Active {
+                return Err(Error::AuctionNotEnded);
             }
-            self.pause_guardians.insert(guardian, &is_enabled);
 
-            self.env().emit_event(PauseGuardianUpdated {
-                guardian,
-                is_guardian: is_enabled,
-                updated_by: self.env().caller(),
-            });
-            Ok(())
+            self.sealed_bids.remove((auction_id, caller));
+            if bid.deposit > 0 {
+                self.env()
+                    .transfer(caller, bid.deposit)
+                    .map_err(|_| Error::EscrowTransferFailed)?;
+            }
+
+            Ok(bid.deposit)
         }
 
-        /// Get pause state
+        /// Computes the commitment for `bidder`'s sealed bid of `amount` with their secret `salt`
+        /// The auction and bidder are bound in, so a commitment cannot be copied by another
+        /// bidder or replayed on another auction. Intended for off-chain queries; calling it in
+        /// a transaction reveals the bid.
         #[ink(message)]
-        pub fn get_pause_state(&self) -> PauseInfo {
-            self.pause_info.clone()
+        pub fn compute_bid_commitment(
+            &self,
+            auction_id: u64,
+            bidder: AccountId,
+            amount: u128,
+            salt: Hash,
+        ) -> Hash {
+            use ink::env::hash::{Blake2x256, HashOutput};
+            let mut output = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(
+                &(auction_id, bidder, amount, salt),
+                &mut output,
+            );
+            Hash::from(output)
         }
 
-        /// Registers a new property
-        /// Optionally checks compliance if compliance registry is set
+        /// Gets a sealed-bid auction by ID
         #[ink(message)]
-        pub fn register_property(&mut self, metadata: PropertyMetadata) -> Result<u64, Error> {
-            self.ensure_not_paused()?;
-            let caller = self.env().caller();
-
-            // Check compliance for property registration (optional but recommended)
-            self.check_compliance(caller)?;
-
-            self.property_count += 1;
-            let property_id = self.property_count;
-
-            let property_info = PropertyInfo {
-                id: property_id,
-                owner: caller,
-                metadata,
-                registered_at: self.env().block_timestamp(),
-            };
-
-            self.properties.insert(property_id, &property_info);
-            // Optimized: Also store reverse mapping for faster owner lookups
-            self.property_owners.insert(property_id, &caller);
-
-            let mut owner_props = self.owner_properties.get(caller).unwrap_or_default();
-            owner_props.push(property_id);
-            self.owner_properties.insert(caller, &owner_props);
-
-            // Track gas usage
-            self.track_gas_usage("register_property".as_bytes());
-
-            // Emit enhanced property registration event
+        pub fn get_sealed_auction(&self, auction_id: u64) -> Option<SealedAuction> {
+            self.sealed_auctions.get(auction_id)
+        }
 
-            let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(PropertyRegistered {
-                property_id,
-                owner: caller,
-                event_version: 1,
-                location: property_info.metadata.location.clone(),
-                size: property_info.metadata.size,
-                valuation: property_info.metadata.valuation,
-                timestamp: property_info.registered_at,
-                block_number: self.env().block_number(),
-                transaction_hash,
-            });
+        /// Gets a bidder's sealed bid on an auction, if any
+        #[ink(message)]
+        pub fn get_sealed_bid(&self, auction_id: u64, bidder: AccountId) -> Option<SealedBid> {
+            self.sealed_bids.get((auction_id, bidder))
+        }
 
-            Ok(property_id)
+        /// Makes a purchase offer on a property, locking the offered amount sent with the call
+        /// The owner can accept or reject the offer; the offeror can withdraw it while pending.
+        #[ink(message, payable)]
+        pub fn make_offer(
+            &mut self,
+            property_id: u64,
+            amount: u128,
+            expires_at: Option<u64>,
+        ) -> Result<u64, Error> {
+            if self.env().transferred_value() != amount {
+                return Err(Error::IncorrectPayment);
+            }
+            self.create_offer(property_id, amount, EscrowAsset::Native, expires_at)
         }
 
-        /// Transfers property ownership
-        /// Requires recipient to be compliant if compliance registry is set
+        /// Makes a purchase offer denominated in an accepted PSP22 token
+        /// The amount is pulled from the caller with `transfer_from`, so the caller approves the
+        /// contract beforehand. Refunds and the sale payout are made in the same token.
         #[ink(message)]
-        pub fn transfer_property(&mut self, property_id: u64, to: AccountId) -> Result<(), Error> {
+        pub fn make_offer_psp22(
+            &mut self,
+            property_id: u64,
+            token: AccountId,
+            amount: u128,
+            expires_at: Option<u64>,
+        ) -> Result<u64, Error> {
+            if self.env().transferred_value() != 0 {
+                return Err(Error::IncorrectPayment);
+            }
+            self.create_offer(property_id, amount, EscrowAsset::Psp22(token), expires_at)
+        }
+
+        /// Helper: validates and records a new pending offer, taking custody of its deposit
+        fn create_offer(
+            &mut self,
+            property_id: u64,
+            amount: u128,
+            asset: EscrowAsset,
+            expires_at: Option<u64>,
+        ) -> Result<u64, Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-            let mut property = self
+            let property = self
                 .properties
                 .get(property_id)
                 .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(property_id)?;
+            self.ensure_verified_for_sale(property_id, SaleType::Offer)?;
 
-            let approved = self.approvals.get(property_id);
-            if property.owner != caller && Some(caller) != approved {
-                return Err(Error::Unauthorized);
+            if property.owner == caller {
+                return Err(Error::CannotBuyOwnProperty);
             }
 
-            // Check compliance for recipient
-            self.check_compliance(to)?;
-
-            let from = property.owner;
-
-            // Remove from current owner's properties
-            let mut current_owner_props = self.owner_properties.get(from).unwrap_or_default();
-            current_owner_props.retain(|&id| id != property_id);
-            self.owner_properties.insert(from, &current_owner_props);
-
-            // Add to new owner's properties
-            let mut new_owner_props = self.owner_properties.get(to).unwrap_or_default();
-            new_owner_props.push(property_id);
-            self.owner_properties.insert(to, &new_owner_props);
+            if amount == 0 {
+                return Err(Error::IncorrectPayment);
+            }
 
-            // Update property owner
-            property.owner = to;
-            self.properties.insert(property_id, &property);
-            // Optimized: Update reverse mapping
-            self.property_owners.insert(property_id, &to);
+            self.ensure_valid_expiry(expires_at)?;
 
-            // Clear approval
-            self.approvals.remove(property_id);
+            let mut pending = self.property_offers.get(property_id).unwrap_or_default();
+            if pending.len() as u32 >= MAX_PENDING_OFFERS {
+                return Err(Error::TooManyOffers);
+            }
 
-            // Track gas usage
-            self.track_gas_usage("transfer_property".as_bytes());
+            if let EscrowAsset::Psp22(token) = asset {
+                self.ensure_escrow_asset_accepted(asset)?;
+                self.check_property_compliance(caller, ComplianceOperation::Purchase, property_id)?;
+                self.pull_psp22(token, caller, amount)?;
+            } else {
+                self.check_property_compliance(caller, ComplianceOperation::Purchase, property_id)?;
+            }
 
-            // Emit enhanced property transfer event
+            self.offer_count += 1;
+            let offer_id = self.offer_count;
+            let now = self.env().block_timestamp();
+            self.offers.insert(
+                offer_id,
+                &Offer {
+                    id: offer_id,
+                    property_id,
+                    offeror: caller,
+                    amount,
+                    asset,
+                    created_at: now,
+                    expires_at,
+                    status: OfferStatus::Pending,
+                    counter: None,
+                },
+            );
+            pending.push(offer_id);
+            self.property_offers.insert(property_id, &pending);
 
             let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(PropertyTransferred {
+            self.env().emit_event(OfferMade {
+                offer_id,
                 property_id,
-                from,
-                to,
+                offeror: caller,
                 event_version: 1,
-                timestamp: self.env().block_timestamp(),
+                amount,
+                expires_at,
+                timestamp: now,
                 block_number: self.env().block_number(),
                 transaction_hash,
-                transferred_by: caller,
             });
 
-            Ok(())
+            Ok(offer_id)
         }
 
-        /// Gets property information
+        /// Accepts a pending offer (property owner only)
+        /// The offered amount is paid to the owner and the title moves to the offeror atomically.
         #[ink(message)]
-        pub fn get_property(&self, property_id: u64) -> Option<PropertyInfo> {
-            self.properties.get(property_id)
-        }
+        pub fn accept_offer(&mut self, offer_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut offer = self.live_offer(offer_id)?;
+            let mut property = self
+                .properties
+                .get(offer.property_id)
+                .ok_or(Error::PropertyNotFound)?;
 
-        /// Gets properties owned by an account
-        #[ink(message)]
-        pub fn get_owner_properties(&self, owner: AccountId) -> Vec<u64> {
-            self.owner_properties.get(owner).unwrap_or_default()
-        }
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
+            }
 
-        /// Gets total property count
-        #[ink(message)]
-        pub fn property_count(&self) -> u64 {
-            self.property_count
-        }
+            self.ensure_whole_title_transferable(offer.property_id)?;
+            self.check_settlement_compliance(
+                offer.offeror,
+                ComplianceOperation::Purchase,
+                offer.property_id,
+            )?;
+
+            self.settle_sale_with_payment(
+                SaleType::Offer,
+                offer_id,
+                offer.property_id,
+                caller,
+                offer.offeror,
+                offer.amount,
+                offer.amount,
+                offer.asset,
+            )?;
+            self.move_property(&mut property, offer.offeror, caller);
+
+            offer.status = OfferStatus::Accepted;
+            self.close_offer(&offer, caller);
 
-        /// Updates property metadata
-        #[ink(message)]
-        pub fn update_metadata(
-            &mut self,
-            property_id: u64,
-            metadata: PropertyMetadata,
-        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        /// Rejects a pending offer and refunds its deposit (property owner only)
+        #[ink(message)]
+        pub fn reject_offer(&mut self, offer_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-            let mut property = self
+            let mut offer = self.pending_offer(offer_id)?;
+            let property = self
                 .properties
-                .get(property_id)
+                .get(offer.property_id)
                 .ok_or(Error::PropertyNotFound)?;
 
             if property.owner != caller {
                 return Err(Error::Unauthorized);
             }
 
-            // check if metadata is valid (basic check)
-            if metadata.location.is_empty() {
-                return Err(Error::InvalidMetadata);
-            }
-
-            // Store old metadata for event
-            let old_location = property.metadata.location.clone();
-            let old_valuation = property.metadata.valuation;
-
-            property.metadata = metadata.clone();
-            self.properties.insert(property_id, &property);
-
-            // Emit enhanced metadata update event
+            self.transfer_asset(offer.asset, offer.offeror, offer.amount)?;
 
-            let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(PropertyMetadataUpdated {
-                property_id,
-                owner: caller,
-                event_version: 1,
-                old_location,
-                new_location: metadata.location,
-                old_valuation,
-                new_valuation: metadata.valuation,
-                timestamp: self.env().block_timestamp(),
-                block_number: self.env().block_number(),
-                transaction_hash,
-            });
+            offer.status = OfferStatus::Rejected;
+            self.close_offer(&offer, caller);
 
             Ok(())
         }
 
-        /// Batch registers multiple properties in a single transaction
+        /// Withdraws a pending offer and refunds its deposit (offeror only)
         #[ink(message)]
-        pub fn batch_register_properties(
-            &mut self,
-            properties: Vec<PropertyMetadata>,
-        ) -> Result<Vec<u64>, Error> {
-            self.ensure_not_paused()?;
-            let mut results = Vec::new();
+        pub fn withdraw_offer(&mut self, offer_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
+            let mut offer = self.pending_offer(offer_id)?;
 
-            // Pre-calculate all property IDs to avoid repeated storage reads
-            let start_id = self.property_count + 1;
-            let end_id = start_id + properties.len() as u64 - 1;
-            self.property_count = end_id;
+            if offer.offeror != caller {
+                return Err(Error::Unauthorized);
+            }
 
-            // Get existing owner properties to avoid repeated storage reads
-            let mut owner_props = self.owner_properties.get(caller).unwrap_or_default();
+            self.transfer_asset(offer.asset, caller, offer.amount)?;
 
-            for (i, metadata) in properties.into_iter().enumerate() {
-                let property_id = start_id + i as u64;
+            offer.status = OfferStatus::Withdrawn;
+            self.close_offer(&offer, caller);
 
-                let property_info = PropertyInfo {
-                    id: property_id,
-                    owner: caller,
-                    metadata,
-                    registered_at: self.env().block_timestamp(),
-                };
+            Ok(())
+        }
 
-                self.properties.insert(property_id, &property_info);
-                owner_props.push(property_id);
+        /// Responds to a pending offer with a higher counter-offer price (property owner only)
+        /// A new counter replaces the previous one; the offer itself stays pending and can still
+        /// be accepted or rejected at its original amount.
+        #[ink(message)]
+        pub fn counter_offer(&mut self, offer_id: u64, price: u128) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut offer = self.live_offer(offer_id)?;
+            let property = self
+                .properties
+                .get(offer.property_id)
+                .ok_or(Error::PropertyNotFound)?;
 
-                results.push(property_id);
+            if property.owner != caller {
+                return Err(Error::Unauthorized);
             }
 
-            // Update owner properties once at the end
-            self.owner_properties.insert(caller, &owner_props);
+            if price <= offer.amount {
+                return Err(Error::InvalidCounterOffer);
+            }
 
-            // Emit enhanced batch registration event
+            let now = self.env().block_timestamp();
+            offer.counter = Some(CounterOffer {
+                price,
+                seller: caller,
+                countered_at: now,
+            });
+            self.offers.insert(offer_id, &offer);
 
             let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(BatchPropertyRegistered {
-                owner: caller,
+            self.env().emit_event(OfferCountered {
+                offer_id,
+                property_id: offer.property_id,
+                seller: caller,
                 event_version: 1,
-                property_ids: results.clone(),
-                count: results.len() as u64,
-                timestamp: self.env().block_timestamp(),
+                offered_amount: offer.amount,
+                counter_price: price,
+                timestamp: now,
                 block_number: self.env().block_number(),
                 transaction_hash,
             });
 
-            // Track gas usage
-            self.track_gas_usage("batch_register_properties".as_bytes());
-
-            Ok(results)
+            Ok(())
         }
 
-        /// Batch transfers multiple properties to the same recipient
-        #[ink(message)]
-        pub fn batch_transfer_properties(
-            &mut self,
-            property_ids: Vec<u64>,
-            to: AccountId,
-        ) -> Result<(), Error> {
+        /// Accepts the owner's counter-offer by topping up the deposit (offeror only)
+        /// The transferred value must equal the difference between the counter price and the
+        /// original offer; the owner is paid and the title moves atomically. For PSP22 offers
+        /// nothing is transferred and the difference is pulled from the offeror's allowance.
+        #[ink(message, payable)]
+        pub fn accept_counter_offer(&mut self, offer_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
+            let transferred = self.env().transferred_value();
+            let mut offer = self.live_offer(offer_id)?;
 
-            // Validate all properties first to avoid partial transfers
-            for &property_id in &property_ids {
-                let property = self
-                    .properties
-                    .get(property_id)
-                    .ok_or(Error::PropertyNotFound)?;
+            if offer.offeror != caller {
+                return Err(Error::Unauthorized);
+            }
 
-                let approved = self.approvals.get(property_id);
-                if property.owner != caller && Some(caller) != approved {
-                    return Err(Error::Unauthorized);
-                }
+            let counter = offer.counter.clone().ok_or(Error::CounterOfferNotFound)?;
+            let mut property = self
+                .properties
+                .get(offer.property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            // A counter from a previous owner no longer binds the property
+            if property.owner != counter.seller {
+                return Err(Error::CounterOfferNotFound);
             }
 
-            // Capture the original owner before transfers (fix for bug)
-            let from = if !property_ids.is_empty() {
-                let first_property = self
-                    .properties
-                    .get(property_ids[0])
-                    .ok_or(Error::PropertyNotFound)?;
-                first_property.owner
-            } else {
-                return Ok(()); // No properties to transfer
+            let top_up = match offer.asset {
+                EscrowAsset::Native => transferred,
+                EscrowAsset::Psp22(_) if transferred != 0 => return Err(Error::IncorrectPayment),
+                EscrowAsset::Psp22(_) => counter.price.saturating_sub(offer.amount),
             };
-
-            // Perform all transfers
-            for property_id in &property_ids {
-                let mut property = self
-                    .properties
-                    .get(property_id)
-                    .ok_or(Error::PropertyNotFound)?;
-                let current_from = property.owner;
-
-                // Remove from current owner's properties
-                let mut current_owner_props =
-                    self.owner_properties.get(current_from).unwrap_or_default();
-                current_owner_props.retain(|&id| id != *property_id);
-                self.owner_properties
-                    .insert(current_from, &current_owner_props);
-
-                // Add to new owner's properties
-                let mut new_owner_props = self.owner_properties.get(to).unwrap_or_default();
-                new_owner_props.push(*property_id);
-                self.owner_properties.insert(to, &new_owner_props);
-
-                // Update property owner
-                property.owner = to;
-                self.properties.insert(property_id, &property);
-                // Optimized: Update reverse mapping
-                self.property_owners.insert(property_id, &to);
-
-                // Clear approval
-                self.approvals.remove(property_id);
+            if offer.amount.saturating_add(top_up) != counter.price {
+                return Err(Error::IncorrectPayment);
             }
 
-            // Emit enhanced batch transfer event
-            if !property_ids.is_empty() {
-                let transaction_hash: Hash = [0u8; 32].into();
-                self.env().emit_event(BatchPropertyTransferred {
-                    from,
-                    to,
-                    event_version: 1,
-                    property_ids: property_ids.clone(),
-                    count: property_ids.len() as u64,
-                    timestamp: self.env().block_timestamp(),
-                    block_number: self.env().block_number(),
-                    transaction_hash,
-                    transferred_by: caller,
-                });
+            self.ensure_whole_title_transferable(offer.property_id)?;
+            self.check_property_compliance(
+                caller,
+                ComplianceOperation::Purchase,
+                offer.property_id,
+            )?;
+
+            if let EscrowAsset::Psp22(token) = offer.asset {
+                self.pull_psp22(token, caller, top_up)?;
             }
 
-            // Track gas usage
-            self.track_gas_usage("batch_transfer_properties".as_bytes());
+            self.settle_sale_with_payment(
+                SaleType::Offer,
+                offer_id,
+                offer.property_id,
+                counter.seller,
+                caller,
+                counter.price,
+                counter.price,
+                offer.asset,
+            )?;
+            self.move_property(&mut property, caller, caller);
+
+            offer.amount = counter.price;
+            offer.status = OfferStatus::Accepted;
+            self.close_offer(&offer, caller);
 
             Ok(())
         }
 
-        /// Batch updates metadata for multiple properties
+        /// Gets an offer by ID
         #[ink(message)]
-        pub fn batch_update_metadata(
-            &mut self,
-            updates: Vec<(u64, PropertyMetadata)>,
-        ) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            let caller = self.env().caller();
+        pub fn get_offer(&self, offer_id: u64) -> Option<Offer> {
+            self.offers.get(offer_id)
+        }
 
-            // Validate all properties first to avoid partial updates
-            for (property_id, ref metadata) in &updates {
-                let property = self
-                    .properties
-                    .get(property_id)
-                    .ok_or(Error::PropertyNotFound)?;
+        /// Gets the pending offers on a property
+        #[ink(message)]
+        pub fn get_property_offers(&self, property_id: u64) -> Vec<Offer> {
+            self.property_offers
+                .get(property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|offer_id| self.offers.get(offer_id))
+                .collect()
+        }
 
-                if property.owner != caller {
-                    return Err(Error::Unauthorized);
-                }
+        /// Helper: loads an offer that is still pending
+        fn pending_offer(&self, offer_id: u64) -> Result<Offer, Error> {
+            let offer = self.offers.get(offer_id).ok_or(Error::OfferNotFound)?;
+            if offer.status != OfferStatus::Pending {
+                return Err(Error::OfferNotPending);
+            }
+            Ok(offer)
+        }
 
-                // Check if metadata is valid (basic check)
-                if metadata.location.is_empty() {
-                    return Err(Error::InvalidMetadata);
-                }
+        /// Helper: loads a pending offer that has not yet expired
+        fn live_offer(&self, offer_id: u64) -> Result<Offer, Error> {
+            let offer = self.pending_offer(offer_id)?;
+            if self.has_lapsed(offer.expires_at) {
+                return Err(Error::OfferExpired);
             }
+            Ok(offer)
+        }
 
-            // Perform all updates
-            let mut updated_property_ids = Vec::new();
-            for (property_id, metadata) in updates {
-                let mut property = self
-                    .properties
-                    .get(property_id)
-                    .ok_or(Error::PropertyNotFound)?;
+        /// Expires a property's lapsed listing and refunds its lapsed offers (callable by anyone)
+        /// Returns the number of listings and offers pruned.
+        #[ink(message)]
+        pub fn prune_expired_sales(&mut self, property_id: u64) -> Result<u32, Error> {
+            let caller = self.env().caller();
+            let mut pruned = 0u32;
 
-                property.metadata = metadata.clone();
-                self.properties.insert(property_id, &property);
-                updated_property_ids.push(property_id);
+            if let Some(mut listing) = self
+                .property_listings
+                .get(property_id)
+                .and_then(|listing_id| self.listings.get(listing_id))
+            {
+                if listing.status == ListingStatus::Active && self.has_lapsed(listing.expires_at) {
+                    listing.status = ListingStatus::Expired;
+                    self.listings.insert(listing.id, &listing);
+                    self.clear_listing_index(property_id);
+                    pruned += 1;
+
+                    let transaction_hash: Hash = [0u8; 32].into();
+                    self.env().emit_event(PropertyDelisted {
+                        listing_id: listing.id,
+                        property_id,
+                        seller: listing.seller,
+                        event_version: 1,
+                        timestamp: self.env().block_timestamp(),
+                        block_number: self.env().block_number(),
+                        transaction_hash,
+                    });
+                }
             }
 
-            // Emit enhanced batch metadata update event
-            if !updated_property_ids.is_empty() {
-                let count = updated_property_ids.len() as u64;
-
-                let transaction_hash: Hash = [0u8; 32].into();
-                self.env().emit_event(BatchMetadataUpdated {
-                    owner: caller,
-                    event_version: 1,
-                    property_ids: updated_property_ids,
-                    count,
-                    timestamp: self.env().block_timestamp(),
-                    block_number: self.env().block_number(),
-                    transaction_hash,
-                });
+            for mut offer in self.get_property_offers(property_id) {
+                if !self.has_lapsed(offer.expires_at) {
+                    continue;
+                }
+                self.transfer_asset(offer.asset, offer.offeror, offer.amount)?;
+                offer.status = OfferStatus::Expired;
+                self.close_offer(&offer, caller);
+                pruned += 1;
             }
 
-            // Track gas usage
-            self.track_gas_usage("batch_update_metadata".as_bytes());
+            Ok(pruned)
+        }
 
+        /// Helper: rejects expiry timestamps that are not in the future
+        fn ensure_valid_expiry(&self, expires_at: Option<u64>) -> Result<(), Error> {
+            if expires_at.is_some_and(|expiry| expiry <= self.env().block_timestamp()) {
+                return Err(Error::InvalidExpiry);
+            }
             Ok(())
         }
 
-        /// Transfers multiple properties to different recipients
-        #[ink(message)]
-        pub fn batch_transfer_properties_to_multiple(
-            &mut self,
-            transfers: Vec<(u64, AccountId)>,
-        ) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            let caller = self.env().caller();
-
-            // Validate all properties first to avoid partial transfers
-            for (property_id, _) in &transfers {
-                let property = self
-                    .properties
-                    .get(property_id)
-                    .ok_or(Error::PropertyNotFound)?;
-
-                let approved = self.approvals.get(property_id);
-                if property.owner != caller && Some(caller) != approved {
-                    return Err(Error::Unauthorized);
-                }
-            }
+        /// Helper: returns true once an optional expiry timestamp has passed
+        fn has_lapsed(&self, expires_at: Option<u64>) -> bool {
+            expires_at.is_some_and(|expiry| self.env().block_timestamp() >= expiry)
+        }
 
-            // Perform all transfers
-            let mut transferred_property_ids = Vec::new();
-            for (property_id, to) in &transfers {
-                let mut property = self
-                    .properties
-                    .get(property_id)
-                    .ok_or(Error::PropertyNotFound)?;
-                let from = property.owner;
+        /// Helper: stores a resolved offer, drops it from the pending index and emits the event
+        fn close_offer(&mut self, offer: &Offer, resolved_by: AccountId) {
+            self.offers.insert(offer.id, offer);
+            let mut pending = self
+                .property_offers
+                .get(offer.property_id)
+                .unwrap_or_default();
+            pending.retain(|&id| id != offer.id);
+            self.property_offers.insert(offer.property_id, &pending);
 
-                // Remove from current owner's properties
-                let mut current_owner_props = self.owner_properties.get(from).unwrap_or_default();
-                current_owner_props.retain(|&id| id != *property_id);
-                self.owner_properties.insert(from, &current_owner_props);
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(OfferResolved {
+                offer_id: offer.id,
+                property_id: offer.property_id,
+                offeror: offer.offeror,
+                event_version: 1,
+                status: offer.status,
+                amount: offer.amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+                resolved_by,
+            });
+        }
 
-                // Add to new owner's properties
-                let mut new_owner_props = self.owner_properties.get(to).unwrap_or_default();
-                new_owner_props.push(*property_id);
-                self.owner_properties.insert(to, &new_owner_props);
+        /// Helper: computes the price of a declining-price auction at the current block time
+        fn dutch_price(&self, auction: &DutchAuction) -> u128 {
+            let now = self.env().block_timestamp();
+            if now >= auction.ends_at {
+                return auction.floor_price;
+            }
+            let elapsed = now.saturating_sub(auction.started_at) as u128;
+            let duration = auction.ends_at.saturating_sub(auction.started_at) as u128;
+            let decay = auction
+                .start_price
+                .saturating_sub(auction.floor_price)
+                .saturating_mul(elapsed)
+                .checked_div(duration)
+                .unwrap_or(0);
+            auction.start_price.saturating_sub(decay)
+        }
 
-                // Update property owner
-                property.owner = *to;
-                self.properties.insert(property_id, &property);
-                // Optimized: Update reverse mapping
-                self.property_owners.insert(property_id, to);
+        /// Helper: rejects a new sale while the property is already listed or at auction
+        fn ensure_not_on_sale(&self, property_id: u64) -> Result<(), Error> {
+            // A lapsed listing no longer blocks a new sale, even before it is pruned
+            if self
+                .get_property_listing(property_id)
+                .is_some_and(|listing| !self.has_lapsed(listing.expires_at))
+            {
+                return Err(Error::ListingAlreadyExists);
+            }
+            if self.property_bundles.contains(property_id) {
+                return Err(Error::ListingAlreadyExists);
+            }
+            if self.property_auctions.contains(property_id)
+                || self.property_dutch_auctions.contains(property_id)
+                || self.property_sealed_auctions.contains(property_id)
+            {
+                return Err(Error::AuctionAlreadyExists);
+            }
+            Ok(())
+        }
 
-                // Clear approval
-                self.approvals.remove(property_id);
-                transferred_property_ids.push(*property_id);
+        /// Helper: royalty owed to the property's royalty receiver out of a sale price
+        /// Nothing is owed when the receiver is the one selling.
+        fn royalty_due(
+            &self,
+            property_id: u64,
+            seller: AccountId,
+            price: u128,
+        ) -> Option<(AccountId, u128)> {
+            let royalty = self.property_royalties.get(property_id)?;
+            if royalty.receiver == seller {
+                return None;
             }
+            let amount = price
+                .saturating_mul(royalty.bps as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            (amount > 0).then_some((royalty.receiver, amount))
+        }
+
+        /// Helper: pays the native proceeds of a marketplace sale to the seller after the
+        /// platform fee, which accrues to the treasury, and any royalty, then records the sale
+        fn settle_sale(
+            &mut self,
+            sale_type: SaleType,
+            source_id: u64,
+            property_id: u64,
+            seller: AccountId,
+            buyer: AccountId,
+            price: u128,
+        ) -> Result<(), Error> {
+            self.settle_sale_with_payment(
+                sale_type,
+                source_id,
+                property_id,
+                seller,
+                buyer,
+                price,
+                price,
+                EscrowAsset::Native,
+            )
+        }
 
-            // Emit enhanced batch transfer to multiple recipients event
-            if !transferred_property_ids.is_empty() {
-                let first_property = self
-                    .properties
-                    .get(transferred_property_ids[0])
-                    .ok_or(Error::PropertyNotFound)?;
-                let from = first_property.owner;
+        /// Helper: settles a sale where only `paid` of the `price` changed hands now
+        /// The fee and royalty are charged on the full price and must be covered by the payment.
+        /// Everything is paid out in `asset`; token fees go straight to the treasury account.
+        #[allow(clippy::too_many_arguments)]
+        fn settle_sale_with_payment(
+            &mut self,
+            sale_type: SaleType,
+            source_id: u64,
+            property_id: u64,
+            seller: AccountId,
+            buyer: AccountId,
+            price: u128,
+            paid: u128,
+            asset: EscrowAsset,
+        ) -> Result<(), Error> {
+            let fee = price
+                .saturating_mul(self.get_marketplace_fee_bps(sale_type) as u128)
+                .checked_div(10_000)
+                .unwrap_or(0);
+            let royalty = self.royalty_due(property_id, seller, price);
+            let royalty_amount = royalty.map(|(_, amount)| amount).unwrap_or(0);
+            let payout = paid
+                .checked_sub(fee.saturating_add(royalty_amount))
+                .ok_or(Error::IncorrectPayment)?;
+            if fee > 0 {
+                match asset {
+                    EscrowAsset::Native => {
+                        self.treasury_balance = self.treasury_balance.saturating_add(fee);
+                    }
+                    EscrowAsset::Psp22(_) => self.transfer_asset(asset, self.treasury, fee)?,
+                }
 
                 let transaction_hash: Hash = [0u8; 32].into();
-                self.env().emit_event(BatchPropertyTransferredToMultiple {
-                    from,
+                self.env().emit_event(MarketplaceFeeCharged {
+                    property_id,
+                    seller,
                     event_version: 1,
-                    transfers: transfers.clone(),
-                    count: transfers.len() as u64,
+                    sale_type,
+                    asset,
+                    sale_price: price,
+                    fee,
                     timestamp: self.env().block_timestamp(),
                     block_number: self.env().block_number(),
                     transaction_hash,
-                    transferred_by: caller,
                 });
             }
+            if let Some((receiver, amount)) = royalty {
+                self.transfer_asset(asset, receiver, amount)?;
+                self.emit_royalty_paid(property_id, seller, receiver, price, amount);
+            }
+            // A shareholder-approved sale pays the shareholders rather than the title holder
+            if self.is_fractional(property_id) && asset == EscrowAsset::Native {
+                self.wind_up_fractional_sale(property_id, buyer, payout)?;
+            } else {
+                self.transfer_asset(asset, seller, payout)?;
+            }
 
-            // Track gas usage
-            self.track_gas_usage("batch_transfer_properties_to_multiple".as_bytes());
-
+            self.store_sale(SaleRecord {
+                id: 0,
+                sale_type,
+                source_id,
+                property_id,
+                buyer,
+                seller,
+                price,
+                asset,
+                terms_hash: None,
+                timestamp: self.env().block_timestamp(),
+            });
             Ok(())
         }
 
-        /// Approves an account to transfer a specific property
+        /// Helper: records a royalty payment in the event log
+        fn emit_royalty_paid(
+            &self,
+            property_id: u64,
+            seller: AccountId,
+            receiver: AccountId,
+            sale_price: u128,
+            amount: u128,
+        ) {
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(RoyaltyPaid {
+                property_id,
+                receiver,
+                seller,
+                event_version: 1,
+                sale_price,
+                amount,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+        }
+    }
+
+    impl PropertyRegistry {
+        /// Offers a lease on a property to `tenant` (owner only)
+        /// A property can have one pending or active lease at a time.
         #[ink(message)]
-        pub fn approve(&mut self, property_id: u64, to: Option<AccountId>) -> Result<(), Error> {
+        pub fn create_lease(
+            &mut self,
+            property_id: u64,
+            tenant: AccountId,
+            rent: u128,
+            period: u64,
+            term_end: u64,
+            deposit: u128,
+        ) -> Result<u64, Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
             let property = self
                 .properties
                 .get(property_id)
                 .ok_or(Error::PropertyNotFound)?;
-
             if property.owner != caller {
                 return Err(Error::Unauthorized);
             }
-
-            let transaction_hash: Hash = [0u8; 32].into();
-
-            if let Some(account) = to {
-                self.approvals.insert(property_id, &account);
-                // Emit enhanced approval granted event
-                self.env().emit_event(ApprovalGranted {
-                    property_id,
-                    owner: caller,
-                    approved: account,
-                    event_version: 1,
-                    timestamp: self.env().block_timestamp(),
-                    block_number: self.env().block_number(),
-                    transaction_hash,
-                });
-            } else {
-                self.approvals.remove(property_id);
-                // Emit enhanced approval cleared event
-                self.env().emit_event(ApprovalCleared {
-                    property_id,
-                    owner: caller,
-                    event_version: 1,
-                    timestamp: self.env().block_timestamp(),
-                    block_number: self.env().block_number(),
-                    transaction_hash,
-                });
+            let now = self.env().block_timestamp();
+            if tenant == caller || rent == 0 || period == 0 || term_end <= now {
+                return Err(Error::InvalidLeaseTerms);
             }
-
-            Ok(())
+            if self.property_active_leases.contains(property_id) {
+                return Err(Error::PropertyAlreadyLeased);
+            }
+            let lease = self.insert_lease(property_id, tenant, rent, period, term_end, deposit);
+            self.property_active_leases.insert(property_id, &lease.id);
+            Ok(lease.id)
         }
 
-        /// Gets the approved account for a property
+        /// Proposes subletting an active lease to `subtenant` (tenant only)
+        /// The sublease can be accepted once the property owner approves it, and must end by
+        /// the parent lease's term. The parent tenant collects its rent.
         #[ink(message)]
-        pub fn get_approved(&self, property_id: u64) -> Option<AccountId> {
-            self.approvals.get(property_id)
+        pub fn propose_sublease(
+            &mut self,
+            lease_id: u64,
+            subtenant: AccountId,
+            rent: u128,
+            period: u64,
+            term_end: u64,
+            deposit: u128,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let parent = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if parent.tenant != caller {
+                return Err(Error::Unauthorized);
+            }
+            if parent.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
+            }
+            if subtenant == caller
+                || rent == 0
+                || period == 0
+                || term_end <= self.env().block_timestamp()
+                || term_end > parent.term_end
+            {
+                return Err(Error::InvalidLeaseTerms);
+            }
+            if self.lease_subleases.contains(lease_id) {
+                return Err(Error::SubleaseExists);
+            }
+            let mut sublease = self.insert_lease(
+                parent.property_id,
+                subtenant,
+                rent,
+                period,
+                term_end,
+                deposit,
+            );
+            sublease.parent_lease = Some(lease_id);
+            self.leases.insert(sublease.id, &sublease);
+            self.lease_subleases.insert(lease_id, &sublease.id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SubleaseProposed {
+                sublease_id: sublease.id,
+                parent_lease_id: lease_id,
+                event_version: 1,
+                subtenant,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(sublease.id)
         }
 
-        /// Creates a new escrow for property transfer
-        /// Seller creates escrow and specifies the buyer
+        /// Approves a proposed sublease so the subtenant can accept it (property owner only)
         #[ink(message)]
-        pub fn create_escrow(
-            &mut self,
-            property_id: u64,
-            buyer: AccountId,
-            amount: u128,
-        ) -> Result<u64, Error> {
+        pub fn approve_sublease(&mut self, sublease_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
+            let mut sublease = self.leases.get(sublease_id).ok_or(Error::LeaseNotFound)?;
+            if sublease.parent_lease.is_none() {
+                return Err(Error::LeaseNotFound);
+            }
             let property = self
                 .properties
-                .get(property_id)
+                .get(sublease.property_id)
                 .ok_or(Error::PropertyNotFound)?;
-
-            // Only property owner (seller) can create escrow
             if property.owner != caller {
                 return Err(Error::Unauthorized);
             }
+            if sublease.status != LeaseStatus::Pending {
+                return Err(Error::LeaseNotPending);
+            }
+            sublease.sublease_approved = true;
+            self.leases.insert(sublease_id, &sublease);
 
-            self.escrow_count += 1;
-            let escrow_id = self.escrow_count;
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(SubleaseApproved {
+                sublease_id,
+                event_version: 1,
+                approved_by: caller,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
 
-            let escrow_info = EscrowInfo {
-                id: escrow_id,
+        /// Gets the pending or active sublease of a lease
+        #[ink(message)]
+        pub fn get_sublease(&self, lease_id: u64) -> Option<u64> {
+            self.lease_subleases.get(lease_id)
+        }
+
+        /// Helper: stores a new pending lease and indexes it by property and tenant
+        fn insert_lease(
+            &mut self,
+            property_id: u64,
+            tenant: AccountId,
+            rent: u128,
+            period: u64,
+            term_end: u64,
+            deposit: u128,
+        ) -> Lease {
+            let now = self.env().block_timestamp();
+            self.lease_count += 1;
+            let lease = Lease {
+                id: self.lease_count,
                 property_id,
-                buyer,
-                seller: property.owner,
-                amount,
-                released: false,
+                tenant,
+                rent,
+                period,
+                term_end,
+                status: LeaseStatus::Pending,
+                created_at: now,
+                started_at: None,
+                paid_through: 0,
+                late_fees_paid: 0,
+                deposit,
+                ended_at: None,
+                notice_period: 0,
+                early_termination_penalty: 0,
+                notice_given_by: None,
+                parent_lease: None,
+                sublease_approved: false,
+                sublease_rent_received: 0,
+                purchase_option: None,
+                purchase_credit: 0,
+                renewals: 0,
+                previous_tenants: Vec::new(),
+                document_hash: None,
             };
-
-            self.escrows.insert(escrow_id, &escrow_info);
-
-            // Emit enhanced escrow created event
+            self.leases.insert(lease.id, &lease);
+            let position = self.get_property_lease_count(property_id);
+            self.property_leases
+                .insert((property_id, position), &lease.id);
+            self.property_lease_count
+                .insert(property_id, &(position + 1));
+            self.index_tenant_lease(tenant, lease.id);
 
             let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(EscrowCreated {
-                escrow_id,
+            self.env().emit_event(LeaseCreated {
+                lease_id: lease.id,
                 property_id,
-                buyer,
-                seller: property.owner,
+                tenant,
                 event_version: 1,
-                amount,
-                timestamp: self.env().block_timestamp(),
+                rent,
+                period,
+                term_end,
+                timestamp: now,
                 block_number: self.env().block_number(),
                 transaction_hash,
             });
-
-            Ok(escrow_id)
+            lease
         }
 
-        /// Releases escrow funds and transfers property
+        /// Attaches the hash of the signed lease document to a pending lease (owner only)
+        /// The tenant has to acknowledge the same hash to accept.
         #[ink(message)]
-        pub fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            let caller = self.env().caller();
-            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
-
-            if escrow.released {
-                return Err(Error::EscrowAlreadyReleased);
+        pub fn set_lease_document(
+            &mut self,
+            lease_id: u64,
+            document_hash: Hash,
+        ) -> Result<(), Error> {
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
+            }
+            if lease.status != LeaseStatus::Pending {
+                return Err(Error::LeaseNotPending);
             }
+            lease.document_hash = Some(document_hash);
+            self.leases.insert(lease_id, &lease);
+            Ok(())
+        }
 
-            // Only buyer can release
-            if escrow.buyer != caller {
+        /// Accepts a pending lease, starting it now (tenant only)
+        /// `document_hash` must match the document the owner attached, or be `None` if there
+        /// is none. The security deposit must be transferred with the call and is held by the
+        /// contract. The tenant must pass the compliance check unless tenant checks are
+        /// switched off.
+        #[ink(message, payable)]
+        pub fn accept_lease(
+            &mut self,
+            lease_id: u64,
+            document_hash: Option<Hash>,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.tenant != caller {
                 return Err(Error::Unauthorized);
             }
-
-            // Transfer property
-            self.transfer_property(escrow.property_id, escrow.buyer)?;
-
-            escrow.released = true;
-            self.escrows.insert(escrow_id, &escrow);
-
-            // Emit enhanced escrow released event
+            if lease.status != LeaseStatus::Pending {
+                return Err(Error::LeaseNotPending);
+            }
+            if lease.parent_lease.is_some() && !lease.sublease_approved {
+                return Err(Error::SubleaseNotApproved);
+            }
+            if document_hash != lease.document_hash {
+                return Err(Error::DocumentHashMismatch);
+            }
+            let now = self.env().block_timestamp();
+            if now >= lease.term_end {
+                return Err(Error::InvalidLeaseTerms);
+            }
+            if self.env().transferred_value() != lease.deposit {
+                return Err(Error::IncorrectPayment);
+            }
+            self.check_property_compliance(caller, ComplianceOperation::Lease, lease.property_id)?;
+            if lease.deposit > 0 {
+                self.lease_deposits.insert(
+                    lease_id,
+                    &SecurityDeposit {
+                        lease_id,
+                        amount: lease.deposit,
+                        deduction: 0,
+                        evidence: None,
+                        claimed_at: None,
+                        status: DepositStatus::Held,
+                    },
+                );
+            }
+            lease.status = LeaseStatus::Active;
+            lease.started_at = Some(now);
+            lease.paid_through = now;
+            self.leases.insert(lease_id, &lease);
 
             let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(EscrowReleased {
-                escrow_id,
-                property_id: escrow.property_id,
-                buyer: escrow.buyer,
+            self.env().emit_event(LeaseAccepted {
+                lease_id,
+                tenant: caller,
                 event_version: 1,
-                amount: escrow.amount,
-                timestamp: self.env().block_timestamp(),
+                document_hash,
+                timestamp: now,
                 block_number: self.env().block_number(),
                 transaction_hash,
-                released_by: caller,
             });
-
             Ok(())
         }
 
-        /// Refunds escrow funds
+        /// Ends a lease (owner or tenant)
+        /// A pending lease can be withdrawn or declined at any time; an active one only once
+        /// its term is over.
         #[ink(message)]
-        pub fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), Error> {
+        pub fn end_lease(&mut self, lease_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-            let mut escrow = self.escrows.get(escrow_id).ok_or(Error::EscrowNotFound)?;
-
-            if escrow.released {
-                return Err(Error::EscrowAlreadyReleased);
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if caller != lease.tenant && caller != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
+            }
+            match lease.status {
+                LeaseStatus::Pending => {}
+                LeaseStatus::Active => {
+                    if self.env().block_timestamp() < lease.term_end {
+                        return Err(Error::LeaseTermNotOver);
+                    }
+                }
+                LeaseStatus::Ended => return Err(Error::LeaseNotActive),
             }
+            self.close_lease(&mut lease, caller);
+            Ok(())
+        }
 
-            // Only seller can refund
-            if escrow.seller != caller {
+        /// Pays the next period of rent on an active lease (tenant only)
+        /// The transferred value must equal `get_rent_due`: the rent, plus the late fee once
+        /// the period it covers has ended. Paying ahead of the due date is allowed. Rent on a
+        /// fractionalized property goes to its shareholders' income pool, and rent paid during a
+        /// habitability dispute is held until the arbitrator releases it.
+        #[ink(message, payable)]
+        pub fn pay_rent(&mut self, lease_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.tenant != caller {
                 return Err(Error::Unauthorized);
             }
+            let late_fee = self.late_fee_due(&lease)?;
+            let due = lease.rent.saturating_add(late_fee);
+            let payment = self.env().transferred_value();
+            if payment != due {
+                return Err(Error::IncorrectPayment);
+            }
 
-            escrow.released = true;
-            self.escrows.insert(escrow_id, &escrow);
-
-            // Emit enhanced escrow refunded event
+            lease.paid_through = lease.paid_through.saturating_add(lease.period);
+            lease.late_fees_paid = lease.late_fees_paid.saturating_add(late_fee);
+            if let Some(option) = &lease.purchase_option {
+                let credit = lease.rent.saturating_mul(u128::from(option.credit_bps)) / 10_000;
+                lease.purchase_credit = lease.purchase_credit.saturating_add(credit);
+            }
+            self.leases.insert(lease_id, &lease);
+            if let Some(mut dispute) = self.rent_disputes.get(lease_id) {
+                dispute.held = dispute.held.saturating_add(payment);
+                self.rent_disputes.insert(lease_id, &dispute);
+            } else {
+                self.pay_lease_owner(&lease, caller, payment)?;
+            }
 
             let transaction_hash: Hash = [0u8; 32].into();
-            self.env().emit_event(EscrowRefunded {
-                escrow_id,
-                property_id: escrow.property_id,
-                seller: escrow.seller,
+            self.env().emit_event(RentPaid {
+                lease_id,
+                tenant: caller,
                 event_version: 1,
-                amount: escrow.amount,
+                rent: lease.rent,
+                late_fee,
+                paid_through: lease.paid_through,
                 timestamp: self.env().block_timestamp(),
                 block_number: self.env().block_number(),
                 transaction_hash,
-                refunded_by: caller,
             });
-
             Ok(())
         }
 
-        /// Gets escrow information
-        #[ink(message)]
-        pub fn get_escrow(&self, escrow_id: u64) -> Option<EscrowInfo> {
-            self.escrows.get(escrow_id)
+        /// Helper: hands rent to whoever the lease pays: the shareholders' income pool of a
+        /// fractionalized property, otherwise the owner, or the parent tenant of a sublease
+        fn pay_lease_owner(
+            &mut self,
+            lease: &Lease,
+            payer: AccountId,
+            amount: u128,
+        ) -> Result<(), Error> {
+            if let Some(mut parent) = lease.parent_lease.and_then(|id| self.leases.get(id)) {
+                parent.sublease_rent_received =
+                    parent.sublease_rent_received.saturating_add(amount);
+                self.leases.insert(parent.id, &parent);
+            }
+            if lease.parent_lease.is_none() && self.is_fractional(lease.property_id) {
+                return self.credit_income(lease.property_id, payer, amount);
+            }
+            let owner = self.lease_owner(lease)?;
+            self.transfer_asset(EscrowAsset::Native, owner, amount)
         }
 
-        /// Portfolio Management: Gets summary statistics for properties owned by an account
+        /// Disputes habitability of an active lease (tenant only)
+        /// From now on rent paid with `pay_rent` is held by the contract, keeping the tenant
+        /// current, until the rent arbitrator releases it with `release_rent_escrow`.
         #[ink(message)]
-        pub fn get_portfolio_summary(&self, owner: AccountId) -> PortfolioSummary {
-            let property_ids = self.owner_properties.get(owner).unwrap_or_default();
-            let mut total_valuation = 0u128;
-            let mut total_size = 0u64;
-            let mut property_count = 0u64;
-
-            // Optimized loop with iterator for better performance
-            let iter = property_ids.iter();
-            for &property_id in iter {
-                if let Some(property) = self.properties.get(property_id) {
-                    // Unrolled additions for better performance
-                    total_valuation = total_valuation.wrapping_add(property.metadata.valuation);
-                    total_size = total_size.wrapping_add(property.metadata.size);
-                    property_count += 1;
-                }
+        pub fn dispute_habitability(&mut self, lease_id: u64, evidence: Hash) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != lease.tenant {
+                return Err(Error::Unauthorized);
             }
-
-            PortfolioSummary {
-                property_count,
-                total_valuation,
-                average_valuation: if property_count > 0 {
-                    total_valuation
-                        .checked_div(property_count as u128)
-                        .unwrap_or(0)
-                } else {
-                    0
-                },
-                total_size,
-                average_size: if property_count > 0 {
-                    total_size.checked_div(property_count).unwrap_or(0)
-                } else {
-                    0
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
+            }
+            if self.rent_arbitrator.is_none() {
+                return Err(Error::ArbitratorNotSet);
+            }
+            if self.rent_disputes.contains(lease_id) {
+                return Err(Error::RentDisputeExists);
+            }
+            let now = self.env().block_timestamp();
+            self.rent_disputes.insert(
+                lease_id,
+                &RentDispute {
+                    lease_id,
+                    evidence,
+                    opened_at: now,
+                    held: 0,
                 },
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(RentDisputeOpened {
+                lease_id,
+                event_version: 1,
+                evidence,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Closes a habitability dispute, paying `to_owner` of the held rent to the owner and
+        /// the rest back to the tenant (rent arbitrator only)
+        #[ink(message)]
+        pub fn release_rent_escrow(&mut self, lease_id: u64, to_owner: u128) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            if self.rent_arbitrator != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            let dispute = self
+                .rent_disputes
+                .get(lease_id)
+                .ok_or(Error::NoRentDispute)?;
+            if to_owner > dispute.held {
+                return Err(Error::ExceedsHeldRent);
+            }
+            let to_tenant = dispute.held - to_owner;
+            self.rent_disputes.remove(lease_id);
+            if to_owner > 0 {
+                self.pay_lease_owner(&lease, lease.tenant, to_owner)?;
             }
+            if to_tenant > 0 {
+                self.transfer_asset(EscrowAsset::Native, lease.tenant, to_tenant)?;
+            }
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(RentEscrowReleased {
+                lease_id,
+                event_version: 1,
+                to_owner,
+                to_tenant,
+                arbitrator: caller,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
         }
 
-        /// Portfolio Management: Gets detailed portfolio information for an owner
+        /// Gets the open habitability dispute on a lease
         #[ink(message)]
-        pub fn get_portfolio_details(&self, owner: AccountId) -> PortfolioDetails {
-            let property_ids = self.owner_properties.get(owner).unwrap_or_default();
-            let mut properties = Vec::with_capacity(property_ids.len());
-
-            let iter = property_ids.iter();
-            for &property_id in iter {
-                if let Some(property) = self.properties.get(property_id) {
-                    // Direct construction to avoid intermediate allocations
-                    let portfolio_property = PortfolioProperty {
-                        id: property.id,
-                        location: property.metadata.location.clone(),
-                        size: property.metadata.size,
-                        valuation: property.metadata.valuation,
-                        registered_at: property.registered_at,
-                    };
-                    properties.push(portfolio_property);
-                }
-            }
+        pub fn get_rent_dispute(&self, lease_id: u64) -> Option<RentDispute> {
+            self.rent_disputes.get(lease_id)
+        }
 
-            PortfolioDetails {
-                owner,
-                total_count: properties.len() as u64,
-                properties,
+        /// Designates the account that releases rent held over habitability disputes
+        /// (admin only)
+        #[ink(message)]
+        pub fn set_rent_arbitrator(&mut self, arbitrator: Option<AccountId>) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
             }
+            self.rent_arbitrator = arbitrator;
+            Ok(())
         }
 
-        /// Analytics: Gets aggregated statistics across all properties
-        /// WARNING: This is expensive for large datasets. Consider off-chain indexing.
+        /// Gets the account that releases rent held over habitability disputes
         #[ink(message)]
-        pub fn get_global_analytics(&self) -> GlobalAnalytics {
-            let mut total_valuation = 0u128;
-            let mut total_size = 0u64;
-            let mut property_count = 0u64;
-            let mut owners = Vec::new();
+        pub fn get_rent_arbitrator(&self) -> Option<AccountId> {
+            self.rent_arbitrator
+        }
 
-            // Optimized loop with early termination possibility
-            // Note: This is expensive for large datasets. Consider off-chain indexing.
-            let mut i = 1u64;
-            while i <= self.property_count {
-                if let Some(property) = self.properties.get(i) {
-                    total_valuation += property.metadata.valuation;
-                    total_size += property.metadata.size;
-                    property_count += 1;
+        /// Gets the amount `pay_rent` currently expects for a lease, including any late fee
+        #[ink(message)]
+        pub fn get_rent_due(&self, lease_id: u64) -> Result<u128, Error> {
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            Ok(lease.rent.saturating_add(self.late_fee_due(&lease)?))
+        }
 
-                    // Add owner if not already in list (manual deduplication)
-                    if !owners.contains(&property.owner) {
-                        owners.push(property.owner);
-                    }
-                }
-                i += 1;
-            }
+        /// Gets the unpaid rent for periods that have already fallen due, excluding late fees
+        #[ink(message)]
+        pub fn get_rent_arrears(&self, lease_id: u64) -> u128 {
+            self.leases
+                .get(lease_id)
+                .map(|lease| {
+                    lease
+                        .rent
+                        .saturating_mul(u128::from(self.overdue_periods(&lease)))
+                })
+                .unwrap_or(0)
+        }
 
-            GlobalAnalytics {
-                total_properties: property_count,
-                total_valuation,
-                average_valuation: if property_count > 0 {
-                    total_valuation
-                        .checked_div(property_count as u128)
-                        .unwrap_or(0)
-                } else {
-                    0
-                },
-                total_size,
-                average_size: if property_count > 0 {
-                    total_size.checked_div(property_count).unwrap_or(0)
-                } else {
-                    0
-                },
-                unique_owners: owners.len() as u64,
-            }
+        /// Gets the number of consecutive rent periods that have ended without payment
+        #[ink(message)]
+        pub fn get_missed_rent_periods(&self, lease_id: u64) -> u64 {
+            self.leases
+                .get(lease_id)
+                .map(|lease| self.overdue_periods(&lease))
+                .unwrap_or(0)
         }
 
-        /// Analytics: Gets properties within a price range
+        /// Returns true once a lease has missed at least the eviction threshold of rent periods
         #[ink(message)]
-        pub fn get_properties_by_price_range(&self, min_price: u128, max_price: u128) -> Vec<u64> {
-            let mut result = Vec::new();
+        pub fn is_eviction_eligible(&self, lease_id: u64) -> bool {
+            self.get_missed_rent_periods(lease_id) >= self.eviction_threshold_periods
+        }
 
-            // Optimized loop with pre-check to reduce iterations
-            let mut i = 1u64;
-            while i <= self.property_count {
-                if let Some(property) = self.properties.get(i) {
-                    // Unrolled condition check for better performance
-                    let valuation = property.metadata.valuation;
-                    if valuation >= min_price && valuation <= max_price {
-                        result.push(property.id);
-                    }
-                }
-                i += 1;
+        /// Ends a lease whose tenant has missed the eviction threshold of rent periods
+        /// (owner only)
+        /// The security deposit can then be claimed against as for any ended lease.
+        #[ink(message)]
+        pub fn terminate_for_default(&mut self, lease_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if caller != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
             }
+            if !self.is_eviction_eligible(lease_id) {
+                return Err(Error::NotEvictionEligible);
+            }
+            let missed_periods = self.overdue_periods(&lease);
+            let arrears = self.get_rent_arrears(lease_id);
+            self.close_lease(&mut lease, caller);
 
-            result
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseTerminatedForDefault {
+                lease_id,
+                tenant: lease.tenant,
+                event_version: 1,
+                missed_periods,
+                arrears,
+                terminated_by: caller,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
         }
 
-        /// Analytics: Gets properties by size range
+        /// Sets how many consecutive unpaid rent periods make a lease eligible for
+        /// termination (admin only)
         #[ink(message)]
-        pub fn get_properties_by_size_range(&self, min_size: u64, max_size: u64) -> Vec<u64> {
-            let mut result = Vec::new();
-
-            // Optimized loop with pre-check to reduce iterations
-            let mut i = 1u64;
-            while i <= self.property_count {
-                if let Some(property) = self.properties.get(i) {
-                    // Unrolled condition check for better performance
-                    let size = property.metadata.size;
-                    if size >= min_size && size <= max_size {
-                        result.push(property.id);
-                    }
-                }
-                i += 1;
+        pub fn set_eviction_threshold(&mut self, periods: u64) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
             }
-
-            result
+            if periods == 0 {
+                return Err(Error::InvalidLeaseTerms);
+            }
+            self.eviction_threshold_periods = periods;
+            Ok(())
         }
 
-        /// Helper method to track gas usage
-        fn track_gas_usage(&mut self, _operation: &[u8]) {
-            // In a real implementation, this would measure actual gas consumption
-            // For demonstration purposes, we increment counters
-            let gas_used = 10000; // Placeholder value
-            self.gas_tracker.operation_count += 1;
-            self.gas_tracker.last_operation_gas = gas_used;
-            self.gas_tracker.total_gas_used += gas_used;
+        /// Gets how many consecutive unpaid rent periods make a lease eligible for termination
+        #[ink(message)]
+        pub fn get_eviction_threshold(&self) -> u64 {
+            self.eviction_threshold_periods
+        }
 
-            // Track min/max gas usage
-            if gas_used < self.gas_tracker.min_gas_used {
-                self.gas_tracker.min_gas_used = gas_used;
+        /// Sets the surcharge on late rent, in basis points of the rent (admin only)
+        #[ink(message)]
+        pub fn set_rent_late_fee(&mut self, late_fee_bps: u32) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
             }
-            if gas_used > self.gas_tracker.max_gas_used {
-                self.gas_tracker.max_gas_used = gas_used;
+            if late_fee_bps > 10_000 {
+                return Err(Error::InvalidBasisPoints);
             }
+            self.rent_late_fee_bps = late_fee_bps;
+            Ok(())
         }
 
-        /// Gas Monitoring: Tracks gas usage for operations
+        /// Gets the surcharge on late rent, in basis points of the rent
         #[ink(message)]
-        pub fn get_gas_metrics(&self) -> GasMetrics {
-            GasMetrics {
-                last_operation_gas: self.gas_tracker.last_operation_gas,
-                average_operation_gas: if self.gas_tracker.operation_count > 0 {
-                    self.gas_tracker
-                        .total_gas_used
-                        .checked_div(self.gas_tracker.operation_count)
-                        .unwrap_or(0)
-                } else {
-                    0
-                },
-                total_operations: self.gas_tracker.operation_count,
-                min_gas_used: if self.gas_tracker.min_gas_used == u64::MAX {
-                    0
-                } else {
-                    self.gas_tracker.min_gas_used
-                },
-                max_gas_used: self.gas_tracker.max_gas_used,
+        pub fn get_rent_late_fee(&self) -> u32 {
+            self.rent_late_fee_bps
+        }
+
+        /// Helper: late fee owed with the next rent payment, failing if no rent is left to pay
+        fn late_fee_due(&self, lease: &Lease) -> Result<u128, Error> {
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
             }
+            if lease.paid_through >= lease.term_end {
+                return Err(Error::NoRentDue);
+            }
+            if self.overdue_periods(lease) == 0 {
+                return Ok(0);
+            }
+            Ok(lease
+                .rent
+                .saturating_mul(u128::from(self.rent_late_fee_bps))
+                / 10_000)
         }
 
-        /// Performance Monitoring: Gets optimization recommendations
+        /// Helper: rent periods within the term that have run their full length without being
+        /// paid for
+        fn overdue_periods(&self, lease: &Lease) -> u64 {
+            if lease.status != LeaseStatus::Active || lease.paid_through >= lease.term_end {
+                return 0;
+            }
+            let elapsed = self
+                .env()
+                .block_timestamp()
+                .saturating_sub(lease.paid_through);
+            let remaining = (lease.term_end - lease.paid_through).saturating_add(lease.period - 1)
+                / lease.period;
+            (elapsed / lease.period).min(remaining)
+        }
+
+        /// Claims deductions from the security deposit of an ended lease (owner only)
+        /// `evidence` is the hash of the documentation backing the claim. The tenant can accept
+        /// by releasing the deposit or dispute within the claim period.
         #[ink(message)]
-        pub fn get_performance_recommendations(&self) -> Vec<String> {
-            let mut recommendations = Vec::new();
+        pub fn claim_deposit_deduction(
+            &mut self,
+            lease_id: u64,
+            deduction: u128,
+            evidence: Hash,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
+            }
+            let ended_at = lease.ended_at.ok_or(Error::LeaseTermNotOver)?;
+            let mut deposit = self
+                .lease_deposits
+                .get(lease_id)
+                .ok_or(Error::DepositNotFound)?;
+            if deposit.status != DepositStatus::Held {
+                return Err(Error::InvalidDepositTransition);
+            }
+            let now = self.env().block_timestamp();
+            if now >= ended_at.saturating_add(self.deposit_claim_period) {
+                return Err(Error::InvalidDepositTransition);
+            }
+            if deduction == 0 || deduction > deposit.amount {
+                return Err(Error::InvalidDepositDeduction);
+            }
+            deposit.deduction = deduction;
+            deposit.evidence = Some(evidence);
+            deposit.claimed_at = Some(now);
+            deposit.status = DepositStatus::Claimed;
+            self.lease_deposits.insert(lease_id, &deposit);
 
-            // Check for high gas usage operations
-            let avg_gas = if self.gas_tracker.operation_count > 0 {
-                self.gas_tracker
-                    .total_gas_used
-                    .checked_div(self.gas_tracker.operation_count)
-                    .unwrap_or(0)
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(DepositDeductionClaimed {
+                lease_id,
+                event_version: 1,
+                deduction,
+                evidence,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
+
+        /// Pays out the security deposit of an ended lease: any claimed deduction to the owner
+        /// and the remainder to the tenant
+        /// The tenant may release straight after a claim, accepting it, or once the claim period
+        /// has passed without one. The owner may release a claim the tenant has not disputed
+        /// within the claim period.
+        #[ink(message)]
+        pub fn release_deposit(&mut self, lease_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            let owner = self.lease_owner(&lease)?;
+            let ended_at = lease.ended_at.ok_or(Error::LeaseTermNotOver)?;
+            let deposit = self
+                .lease_deposits
+                .get(lease_id)
+                .ok_or(Error::DepositNotFound)?;
+            let now = self.env().block_timestamp();
+            let window_from = match deposit.status {
+                DepositStatus::Held => ended_at,
+                DepositStatus::Claimed => deposit.claimed_at.unwrap_or(ended_at),
+                _ => return Err(Error::InvalidDepositTransition),
+            };
+            let window_over = now >= window_from.saturating_add(self.deposit_claim_period);
+            let allowed = if caller == lease.tenant {
+                deposit.status == DepositStatus::Claimed || window_over
+            } else if caller == owner {
+                deposit.status == DepositStatus::Claimed && window_over
             } else {
-                0
+                return Err(Error::Unauthorized);
             };
-            if avg_gas > 50000 {
-                recommendations
-                    .push("Consider using batch operations for multiple properties".to_string());
+            if !allowed {
+                return Err(Error::DepositClaimPeriodActive);
             }
+            self.settle_deposit(deposit, lease.tenant, owner)
+        }
 
-            // Check for many small operations
-            if self.gas_tracker.operation_count > 100 && avg_gas < 10000 {
-                recommendations.push(
-                    "Operations are efficient but consider consolidating related operations"
-                        .to_string(),
-                );
+        /// Disputes the deductions claimed from a security deposit (tenant only)
+        /// The admin then decides the deduction with `resolve_deposit_dispute`.
+        #[ink(message)]
+        pub fn dispute_deposit(&mut self, lease_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != lease.tenant {
+                return Err(Error::Unauthorized);
             }
-
-            // Check for inconsistent gas usage
-            if self.gas_tracker.max_gas_used > self.gas_tracker.min_gas_used * 10 {
-                recommendations
-                    .push("Gas usage varies significantly - review operation patterns".to_string());
+            let mut deposit = self
+                .lease_deposits
+                .get(lease_id)
+                .ok_or(Error::DepositNotFound)?;
+            if deposit.status != DepositStatus::Claimed {
+                return Err(Error::InvalidDepositTransition);
             }
+            deposit.status = DepositStatus::Disputed;
+            self.lease_deposits.insert(lease_id, &deposit);
 
-            // General recommendations
-            recommendations
-                .push("Use batch operations for multiple property transfers".to_string());
-            recommendations
-                .push("Prefer portfolio analytics over individual property queries".to_string());
-            recommendations.push("Consider off-chain indexing for complex analytics".to_string());
-
-            recommendations
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(DepositDisputed {
+                lease_id,
+                event_version: 1,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
         }
 
-        // ============================================================================
-        // BADGE MANAGEMENT SYSTEM
-        // ============================================================================
-
-        /// Adds or removes a badge verifier (admin only)
+        /// Settles a disputed security deposit with the deduction the admin decides on
         #[ink(message)]
-        pub fn set_verifier(&mut self, verifier: AccountId, authorized: bool) -> Result<(), Error> {
-            let caller = self.env().caller();
-            if caller != self.admin {
+        pub fn resolve_deposit_dispute(
+            &mut self,
+            lease_id: u64,
+            deduction: u128,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
                 return Err(Error::Unauthorized);
             }
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            let mut deposit = self
+                .lease_deposits
+                .get(lease_id)
+                .ok_or(Error::DepositNotFound)?;
+            if deposit.status != DepositStatus::Disputed {
+                return Err(Error::InvalidDepositTransition);
+            }
+            if deduction > deposit.amount {
+                return Err(Error::InvalidDepositDeduction);
+            }
+            deposit.deduction = deduction;
+            let owner = self.lease_owner(&lease)?;
+            self.settle_deposit(deposit, lease.tenant, owner)
+        }
 
-            self.badge_verifiers.insert(verifier, &authorized);
+        /// Helper: pays a deposit's deduction to the owner and the rest back to the tenant
+        fn settle_deposit(
+            &mut self,
+            mut deposit: SecurityDeposit,
+            tenant: AccountId,
+            owner: AccountId,
+        ) -> Result<(), Error> {
+            let deduction = deposit.deduction.min(deposit.amount);
+            let refund = deposit.amount - deduction;
+            deposit.status = DepositStatus::Settled;
+            deposit.amount = 0;
+            self.lease_deposits.insert(deposit.lease_id, &deposit);
+            if deduction > 0 {
+                self.transfer_asset(EscrowAsset::Native, owner, deduction)?;
+            }
+            if refund > 0 {
+                self.transfer_asset(EscrowAsset::Native, tenant, refund)?;
+            }
 
-            // Emit verifier updated event
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(VerifierUpdated {
-                verifier,
-                authorized,
-                updated_by: caller,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(DepositSettled {
+                lease_id: deposit.lease_id,
                 event_version: 1,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                deduction,
+                refund,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
+            Ok(())
+        }
 
+        /// Gets the security deposit held for a lease
+        #[ink(message)]
+        pub fn get_lease_deposit(&self, lease_id: u64) -> Option<SecurityDeposit> {
+            self.lease_deposits.get(lease_id)
+        }
+
+        /// Sets how long owners have to claim deposit deductions and tenants to dispute them
+        /// (admin only)
+        #[ink(message)]
+        pub fn set_deposit_claim_period(&mut self, period: u64) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.deposit_claim_period = period;
             Ok(())
         }
 
-        /// Checks if an account is an authorized verifier
+        /// Gets how long owners have to claim deposit deductions and tenants to dispute them
         #[ink(message)]
-        pub fn is_verifier(&self, account: AccountId) -> bool {
-            self.badge_verifiers.get(account).unwrap_or(false)
+        pub fn get_deposit_claim_period(&self) -> u64 {
+            self.deposit_claim_period
         }
 
-        /// Issues a badge to a property (verifier only)
+        /// Sets the notice period and early termination penalty of a pending lease (owner only)
         #[ink(message)]
-        pub fn issue_badge(
+        pub fn set_lease_termination_terms(
             &mut self,
-            property_id: u64,
-            badge_type: BadgeType,
-            expires_at: Option<u64>,
-            metadata_url: String,
+            lease_id: u64,
+            notice_period: u64,
+            early_termination_penalty: u128,
         ) -> Result<(), Error> {
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
+            }
+            if lease.status != LeaseStatus::Pending {
+                return Err(Error::LeaseNotPending);
+            }
+            lease.notice_period = notice_period;
+            lease.early_termination_penalty = early_termination_penalty;
+            self.leases.insert(lease_id, &lease);
+            Ok(())
+        }
+
+        /// Gives notice to end an active lease (owner or tenant)
+        /// The term is brought forward to the end of the notice period. If that cuts the agreed
+        /// term short, the party giving notice owes the early termination penalty: a tenant's is
+        /// taken from their deposit first, and any remainder, like an owner's, must be
+        /// transferred with the call. The penalty goes to the other party.
+        #[ink(message, payable)]
+        pub fn give_lease_notice(&mut self, lease_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-
-            // Only verifiers can issue badges
-            if !self.is_verifier(caller) && caller != self.admin {
-                return Err(Error::NotVerifier);
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            let owner = self.lease_owner(&lease)?;
+            let counterparty = if caller == lease.tenant {
+                owner
+            } else if caller == owner {
+                lease.tenant
+            } else {
+                return Err(Error::Unauthorized);
+            };
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
             }
-
-            // Check if property exists
-            self.properties
-                .get(property_id)
-                .ok_or(Error::PropertyNotFound)?;
-
-            // Check if badge already exists and is not revoked
-            if let Some(existing_badge) = self.property_badges.get((property_id, badge_type)) {
-                if !existing_badge.revoked {
-                    return Err(Error::BadgeAlreadyIssued);
-                }
+            if lease.notice_given_by.is_some() {
+                return Err(Error::NoticeAlreadyGiven);
             }
 
-            let badge = Badge {
-                badge_type,
-                issued_at: self.env().block_timestamp(),
-                issued_by: caller,
-                expires_at,
-                metadata_url: metadata_url.clone(),
-                revoked: false,
-                revoked_at: None,
-                revocation_reason: String::new(),
+            let now = self.env().block_timestamp();
+            let term_end = now.saturating_add(lease.notice_period);
+            let penalty = if term_end < lease.term_end {
+                lease.early_termination_penalty
+            } else {
+                0
             };
+            let held_deposit = self
+                .lease_deposits
+                .get(lease_id)
+                .filter(|deposit| caller == lease.tenant && deposit.status == DepositStatus::Held);
+            let from_deposit = held_deposit
+                .as_ref()
+                .map(|deposit| penalty.min(deposit.amount))
+                .unwrap_or(0);
+            if self.env().transferred_value() != penalty - from_deposit {
+                return Err(Error::IncorrectPayment);
+            }
+            if let Some(mut deposit) = held_deposit {
+                deposit.amount -= from_deposit;
+                self.lease_deposits.insert(lease_id, &deposit);
+            }
 
-            self.property_badges
-                .insert((property_id, badge_type), &badge);
+            lease.term_end = lease.term_end.min(term_end);
+            lease.notice_given_by = Some(caller);
+            self.leases.insert(lease_id, &lease);
+            if penalty > 0 {
+                self.transfer_asset(EscrowAsset::Native, counterparty, penalty)?;
+            }
 
-            // Emit badge issued event
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(BadgeIssued {
-                property_id,
-                badge_type,
-                issued_by: caller,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseNoticeGiven {
+                lease_id,
+                given_by: caller,
                 event_version: 1,
-                expires_at,
-                metadata_url,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                term_end: lease.term_end,
+                penalty,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
-
             Ok(())
         }
 
-        /// Revokes a badge from a property (verifier or admin only)
+        /// Makes a pending lease rent-to-own (owner only)
+        /// `credit_bps` of every rent payment accrues toward `price`; see
+        /// `exercise_purchase_option`.
         #[ink(message)]
-        pub fn revoke_badge(
+        pub fn set_purchase_option(
             &mut self,
-            property_id: u64,
-            badge_type: BadgeType,
-            reason: String,
+            lease_id: u64,
+            price: u128,
+            credit_bps: u32,
         ) -> Result<(), Error> {
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.parent_lease.is_some() {
+                return Err(Error::InvalidLeaseTerms);
+            }
+            if self.env().caller() != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
+            }
+            if lease.status != LeaseStatus::Pending {
+                return Err(Error::LeaseNotPending);
+            }
+            if credit_bps > 10_000 {
+                return Err(Error::InvalidBasisPoints);
+            }
+            lease.purchase_option = Some(PurchaseOption { price, credit_bps });
+            self.leases.insert(lease_id, &lease);
+            Ok(())
+        }
+
+        /// Buys the leased property under its rent-to-own option (tenant only)
+        /// Exercisable once the accrued credit covers the price, or from the end of the term.
+        /// Any price the credit does not cover must be transferred with the call and goes to
+        /// the owner. The lease ends and a held deposit is refunded in full.
+        #[ink(message, payable)]
+        pub fn exercise_purchase_option(&mut self, lease_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-
-            // Only verifiers or admin can revoke badges
-            if !self.is_verifier(caller) && caller != self.admin {
-                return Err(Error::NotVerifier);
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.tenant != caller {
+                return Err(Error::Unauthorized);
             }
-
-            let mut badge = self
-                .property_badges
-                .get((property_id, badge_type))
-                .ok_or(Error::BadgeNotFound)?;
-
-            if badge.revoked {
-                return Err(Error::BadgeNotFound);
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
+            }
+            let option = lease
+                .purchase_option
+                .clone()
+                .ok_or(Error::NoPurchaseOption)?;
+            let now = self.env().block_timestamp();
+            if lease.purchase_credit < option.price && now < lease.term_end {
+                return Err(Error::PurchaseOptionNotExercisable);
+            }
+            let owed = option.price.saturating_sub(lease.purchase_credit);
+            if self.env().transferred_value() != owed {
+                return Err(Error::IncorrectPayment);
+            }
+            let mut property = self
+                .properties
+                .get(lease.property_id)
+                .ok_or(Error::PropertyNotFound)?;
+            self.ensure_whole_title_transferable(lease.property_id)?;
+            self.check_compliance_in(
+                caller,
+                ComplianceOperation::Purchase,
+                property.metadata.jurisdiction,
+                property.metadata.valuation,
+            )?;
+
+            let owner = property.owner;
+            self.move_property(&mut property, caller, caller);
+            if owed > 0 {
+                self.transfer_asset(EscrowAsset::Native, owner, owed)?;
+            }
+            self.close_lease(&mut lease, caller);
+            if let Some(mut deposit) = self
+                .lease_deposits
+                .get(lease_id)
+                .filter(|deposit| deposit.status == DepositStatus::Held)
+            {
+                deposit.deduction = 0;
+                self.settle_deposit(deposit, caller, owner)?;
             }
 
-            badge.revoked = true;
-            badge.revoked_at = Some(self.env().block_timestamp());
-            badge.revocation_reason = reason.clone();
-
-            self.property_badges
-                .insert((property_id, badge_type), &badge);
-
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(BadgeRevoked {
-                property_id,
-                badge_type,
-                revoked_by: caller,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(PurchaseOptionExercised {
+                lease_id,
+                property_id: lease.property_id,
+                tenant: caller,
                 event_version: 1,
-                reason,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                credit_applied: option.price.min(lease.purchase_credit),
+                paid: owed,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
-
             Ok(())
         }
 
+        /// Offers to extend an active lease to `term_end` at a new rent (owner only)
+        /// Must be proposed before the lease expires and replaces any earlier offer.
         #[ink(message)]
-        pub fn request_verification(
+        pub fn propose_lease_renewal(
             &mut self,
-            property_id: u64,
-            badge_type: BadgeType,
-            evidence_url: String,
-        ) -> Result<u64, Error> {
+            lease_id: u64,
+            rent: u128,
+            term_end: u64,
+        ) -> Result<(), Error> {
             self.ensure_not_paused()?;
-            let caller = self.env().caller();
-            let property = self
-                .properties
-                .get(property_id)
-                .ok_or(Error::PropertyNotFound)?;
-
-            if property.owner != caller {
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != self.lease_owner(&lease)? {
                 return Err(Error::Unauthorized);
             }
+            self.ensure_renewable(&lease)?;
+            if rent == 0 || term_end <= lease.term_end {
+                return Err(Error::InvalidLeaseTerms);
+            }
+            if let Some(parent) = lease.parent_lease.and_then(|id| self.leases.get(id)) {
+                if term_end > parent.term_end {
+                    return Err(Error::InvalidLeaseTerms);
+                }
+            }
+            let now = self.env().block_timestamp();
+            self.lease_renewals.insert(
+                lease_id,
+                &LeaseRenewal {
+                    rent,
+                    term_end,
+                    proposed_at: now,
+                },
+            );
 
-            self.verification_count += 1;
-            let request_id = self.verification_count;
-
-            let request = VerificationRequest {
-                id: request_id,
-                property_id,
-                badge_type,
-                requester: caller,
-                requested_at: self.env().block_timestamp(),
-                evidence_url: evidence_url.clone(),
-                status: VerificationStatus::Pending,
-                reviewed_by: None,
-                reviewed_at: None,
-            };
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseRenewalProposed {
+                lease_id,
+                event_version: 1,
+                rent,
+                term_end,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
+        }
 
-            self.verification_requests.insert(request_id, &request);
+        /// Accepts the offered renewal terms, extending the lease in place (tenant only)
+        #[ink(message)]
+        pub fn accept_lease_renewal(&mut self, lease_id: u64) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.tenant != caller {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_renewable(&lease)?;
+            let renewal = self
+                .lease_renewals
+                .get(lease_id)
+                .ok_or(Error::RenewalNotFound)?;
+            lease.rent = renewal.rent;
+            lease.term_end = renewal.term_end;
+            lease.renewals = lease.renewals.saturating_add(1);
+            self.leases.insert(lease_id, &lease);
+            self.lease_renewals.remove(lease_id);
 
-            // Emit verification requested event
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(VerificationRequested {
-                request_id,
-                property_id,
-                badge_type,
-                requester: caller,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseRenewed {
+                lease_id,
+                tenant: caller,
                 event_version: 1,
-                evidence_url,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                rent: renewal.rent,
+                term_end: renewal.term_end,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
+            Ok(())
+        }
 
-            Ok(request_id)
+        /// Gets the renewal terms offered on a lease
+        #[ink(message)]
+        pub fn get_lease_renewal(&self, lease_id: u64) -> Option<LeaseRenewal> {
+            self.lease_renewals.get(lease_id)
         }
 
+        /// Proposes handing an active lease over to `assignee` (tenant only)
+        /// Takes effect when the owner has approved and the assignee accepts. Replaces any
+        /// earlier proposal.
         #[ink(message)]
-        pub fn review_verification(
+        pub fn propose_lease_assignment(
             &mut self,
-            request_id: u64,
-            approved: bool,
-            expires_at: Option<u64>,
-            metadata_url: String,
+            lease_id: u64,
+            assignee: AccountId,
         ) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-
-            if !self.is_verifier(caller) && caller != self.admin {
-                return Err(Error::NotVerifier);
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.tenant != caller {
+                return Err(Error::Unauthorized);
             }
-
-            let mut request = self
-                .verification_requests
-                .get(request_id)
-                .ok_or(Error::BadgeNotFound)?;
-
-            request.status = if approved {
-                VerificationStatus::Approved
-            } else {
-                VerificationStatus::Rejected
-            };
-            request.reviewed_by = Some(caller);
-            request.reviewed_at = Some(self.env().block_timestamp());
-
-            self.verification_requests.insert(request_id, &request);
-
-            if approved {
-                self.issue_badge(
-                    request.property_id,
-                    request.badge_type,
-                    expires_at,
-                    metadata_url,
-                )?;
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
             }
-
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(VerificationReviewed {
-                request_id,
-                property_id: request.property_id,
-                reviewer: caller,
-                approved,
+            if assignee == caller || assignee == self.lease_owner(&lease)? {
+                return Err(Error::InvalidLeaseTerms);
+            }
+            let now = self.env().block_timestamp();
+            self.lease_assignments.insert(
+                lease_id,
+                &LeaseAssignment {
+                    assignee,
+                    approved: false,
+                    proposed_at: now,
+                },
+            );
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseAssignmentProposed {
+                lease_id,
+                assignee,
                 event_version: 1,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                tenant: caller,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
-
             Ok(())
         }
 
+        /// Approves the proposed assignment of a lease (owner only)
         #[ink(message)]
-        pub fn submit_appeal(
-            &mut self,
-            property_id: u64,
-            badge_type: BadgeType,
-            reason: String,
-        ) -> Result<u64, Error> {
+        pub fn approve_lease_assignment(&mut self, lease_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-            let property = self
-                .properties
-                .get(property_id)
-                .ok_or(Error::PropertyNotFound)?;
-
-            if property.owner != caller {
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if caller != self.lease_owner(&lease)? {
                 return Err(Error::Unauthorized);
             }
+            let mut assignment = self
+                .lease_assignments
+                .get(lease_id)
+                .ok_or(Error::AssignmentNotFound)?;
+            assignment.approved = true;
+            self.lease_assignments.insert(lease_id, &assignment);
 
-            let badge = self
-                .property_badges
-                .get((property_id, badge_type))
-                .ok_or(Error::BadgeNotFound)?;
-
-            if !badge.revoked {
-                return Err(Error::InvalidAppealStatus);
-            }
-
-            self.appeal_count += 1;
-            let appeal_id = self.appeal_count;
-
-            let appeal = Appeal {
-                id: appeal_id,
-                property_id,
-                badge_type,
-                appellant: caller,
-                reason: reason.clone(),
-                submitted_at: self.env().block_timestamp(),
-                status: AppealStatus::Pending,
-                resolved_by: None,
-                resolved_at: None,
-                resolution: String::new(),
-            };
-
-            self.appeals.insert(appeal_id, &appeal);
-
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(AppealSubmitted {
-                appeal_id,
-                property_id,
-                badge_type,
-                appellant: caller,
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseAssignmentApproved {
+                lease_id,
                 event_version: 1,
-                reason,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                approved_by: caller,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
-
-            Ok(appeal_id)
+            Ok(())
         }
 
+        /// Takes over an approved lease assignment (assignee only)
+        /// The assignee steps into the lease as it stands: its payment record, deposit and any
+        /// liability against the deposit.
         #[ink(message)]
-        pub fn resolve_appeal(
-            &mut self,
-            appeal_id: u64,
-            approved: bool,
-            resolution: String,
-        ) -> Result<(), Error> {
+        pub fn accept_lease_assignment(&mut self, lease_id: u64) -> Result<(), Error> {
             self.ensure_not_paused()?;
             let caller = self.env().caller();
-
-            if caller != self.admin {
+            let mut lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            let assignment = self
+                .lease_assignments
+                .get(lease_id)
+                .ok_or(Error::AssignmentNotFound)?;
+            if assignment.assignee != caller {
                 return Err(Error::Unauthorized);
             }
-
-            let mut appeal = self.appeals.get(appeal_id).ok_or(Error::AppealNotFound)?;
-
-            appeal.status = if approved {
-                AppealStatus::Approved
-            } else {
-                AppealStatus::Rejected
-            };
-            appeal.resolved_by = Some(caller);
-            appeal.resolved_at = Some(self.env().block_timestamp());
-            appeal.resolution = resolution.clone();
-
-            self.appeals.insert(appeal_id, &appeal);
-
-            // If approved, reinstate the badge
-            if approved {
-                if let Some(mut badge) = self
-                    .property_badges
-                    .get((appeal.property_id, appeal.badge_type))
-                {
-                    badge.revoked = false;
-                    badge.revoked_at = None;
-                    badge.revocation_reason = String::new();
-                    self.property_badges
-                        .insert((appeal.property_id, appeal.badge_type), &badge);
-                }
+            if !assignment.approved {
+                return Err(Error::AssignmentNotApproved);
+            }
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
             }
+            self.check_property_compliance(caller, ComplianceOperation::Lease, lease.property_id)?;
 
-            // Emit appeal resolved event
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-            self.env().emit_event(AppealResolved {
-                appeal_id,
-                property_id: appeal.property_id,
-                resolved_by: caller,
-                approved,
+            let from = lease.tenant;
+            lease.previous_tenants.push(from);
+            lease.tenant = caller;
+            self.leases.insert(lease_id, &lease);
+            self.lease_assignments.remove(lease_id);
+            self.index_tenant_lease(caller, lease_id);
+
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseAssigned {
+                lease_id,
+                from,
+                to: caller,
                 event_version: 1,
-                resolution,
-                timestamp,
-                block_number,
-                transaction_hash: [0u8; 32].into(),
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
             });
-
             Ok(())
         }
 
-        /// Gets all badges for a property
+        /// Gets the proposed assignment of a lease
         #[ink(message)]
-        pub fn get_property_badges(&self, property_id: u64) -> Vec<(BadgeType, Badge)> {
-            let mut badges = Vec::new();
-
-            // Check all badge types
-            let badge_types = [
-                BadgeType::OwnerVerification,
-                BadgeType::DocumentVerification,
-                BadgeType::LegalCompliance,
-                BadgeType::PremiumListing,
-            ];
+        pub fn get_lease_assignment(&self, lease_id: u64) -> Option<LeaseAssignment> {
+            self.lease_assignments.get(lease_id)
+        }
 
-            for badge_type in badge_types.iter() {
-                if let Some(badge) = self.property_badges.get((property_id, *badge_type)) {
-                    if !badge.revoked {
-                        badges.push((*badge_type, badge));
-                    }
-                }
+        /// Files a maintenance request on an active lease (tenant only)
+        /// `description_hash` is the hash of the off-chain description and photos.
+        #[ink(message)]
+        pub fn file_maintenance_request(
+            &mut self,
+            lease_id: u64,
+            description_hash: Hash,
+        ) -> Result<u64, Error> {
+            self.ensure_not_paused()?;
+            let caller = self.env().caller();
+            let lease = self.leases.get(lease_id).ok_or(Error::LeaseNotFound)?;
+            if lease.tenant != caller {
+                return Err(Error::Unauthorized);
+            }
+            if lease.status != LeaseStatus::Active {
+                return Err(Error::LeaseNotActive);
             }
+            let now = self.env().block_timestamp();
+            self.maintenance_request_count += 1;
+            let request = MaintenanceRequest {
+                id: self.maintenance_request_count,
+                lease_id,
+                filed_by: caller,
+                description_hash,
+                filed_at: now,
+                resolved_at: None,
+                resolution_hash: None,
+            };
+            self.maintenance_requests.insert(request.id, &request);
+            let mut requests = self
+                .lease_maintenance_requests
+                .get(lease_id)
+                .unwrap_or_default();
+            requests.push(request.id);
+            self.lease_maintenance_requests.insert(lease_id, &requests);
 
-            badges
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(MaintenanceRequested {
+                request_id: request.id,
+                lease_id,
+                event_version: 1,
+                description_hash,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(request.id)
         }
 
+        /// Marks a maintenance request resolved (owner only)
+        /// `resolution_hash` is the hash of the off-chain account of the fix.
         #[ink(message)]
-        pub fn has_badge(&self, property_id: u64, badge_type: BadgeType) -> bool {
-            if let Some(badge) = self.property_badges.get((property_id, badge_type)) {
-                !badge.revoked
-            } else {
-                false
+        pub fn resolve_maintenance_request(
+            &mut self,
+            request_id: u64,
+            resolution_hash: Hash,
+        ) -> Result<(), Error> {
+            self.ensure_not_paused()?;
+            let mut request = self
+                .maintenance_requests
+                .get(request_id)
+                .ok_or(Error::MaintenanceRequestNotFound)?;
+            let lease = self
+                .leases
+                .get(request.lease_id)
+                .ok_or(Error::LeaseNotFound)?;
+            if self.env().caller() != self.lease_owner(&lease)? {
+                return Err(Error::Unauthorized);
             }
-        }
+            if request.resolved_at.is_some() {
+                return Err(Error::MaintenanceAlreadyResolved);
+            }
+            let now = self.env().block_timestamp();
+            request.resolved_at = Some(now);
+            request.resolution_hash = Some(resolution_hash);
+            self.maintenance_requests.insert(request_id, &request);
 
-        #[ink(message)]
-        pub fn get_badge(&self, property_id: u64, badge_type: BadgeType) -> Option<Badge> {
-            self.property_badges.get((property_id, badge_type))
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(MaintenanceResolved {
+                request_id,
+                lease_id: request.lease_id,
+                event_version: 1,
+                resolution_hash,
+                timestamp: now,
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+            Ok(())
         }
 
+        /// Gets a maintenance request by ID
         #[ink(message)]
-        pub fn get_verification_request(&self, request_id: u64) -> Option<VerificationRequest> {
-            self.verification_requests.get(request_id)
+        pub fn get_maintenance_request(&self, request_id: u64) -> Option<MaintenanceRequest> {
+            self.maintenance_requests.get(request_id)
         }
 
+        /// Gets the maintenance requests filed on a lease, oldest first
         #[ink(message)]
-        pub fn get_appeal(&self, appeal_id: u64) -> Option<Appeal> {
-            self.appeals.get(appeal_id)
+        pub fn get_lease_maintenance_requests(&self, lease_id: u64) -> Vec<u64> {
+            self.lease_maintenance_requests
+                .get(lease_id)
+                .unwrap_or_default()
         }
-    }
-
-    #[cfg(kani)]
-    mod verification {
-        use super::*;
 
-        #[kani::proof]
-        fn verify_arithmetic_overflow() {
-            let a: u64 = kani::any();
-            let b: u64 = kani::any();
-            // Verify that addition is safe
-            if a < 100 && b < 100 {
-                assert!(a + b < 200);
+        /// Helper: a lease can only be renewed while active, unexpired and not under notice
+        fn ensure_renewable(&self, lease: &Lease) -> Result<(), Error> {
+            if lease.status != LeaseStatus::Active || self.env().block_timestamp() >= lease.term_end
+            {
+                return Err(Error::LeaseNotActive);
             }
+            if lease.notice_given_by.is_some() {
+                return Err(Error::NoticeAlreadyGiven);
+            }
+            Ok(())
         }
 
-        #[kani::proof]
-        fn verify_property_info_struct() {
-            let id: u64 = kani::any();
-            // Verify PropertyInfo layout/safety if needed
-            // This is a placeholder for checking structural invariants
-            if id > 0 {
-                assert!(id > 0);
+        /// Helper: the current owner of a leased property, or the parent tenant for a sublease
+        fn lease_owner(&self, lease: &Lease) -> Result<AccountId, Error> {
+            if let Some(parent_id) = lease.parent_lease {
+                return self
+                    .leases
+                    .get(parent_id)
+                    .map(|parent| parent.tenant)
+                    .ok_or(Error::LeaseNotFound);
             }
+            self.properties
+                .get(lease.property_id)
+                .map(|property| property.owner)
+                .ok_or(Error::PropertyNotFound)
         }
-    }
 
-    impl Default for PropertyRegistry {
-        fn default() -> Self {
-            Self::new()
-        }
-    }
+        /// Helper: marks a lease ended and frees the property, or the parent lease, for a new
+        /// one; an open sublease ends with its parent
+        fn close_lease(&mut self, lease: &mut Lease, ended_by: AccountId) {
+            lease.status = LeaseStatus::Ended;
+            lease.ended_at = Some(self.env().block_timestamp());
+            self.leases.insert(lease.id, lease);
+            match lease.parent_lease {
+                Some(parent_id) => self.lease_subleases.remove(parent_id),
+                None => self.property_active_leases.remove(lease.property_id),
+            }
+            if let Some(mut sublease) = self
+                .lease_subleases
+                .get(lease.id)
+                .and_then(|sublease_id| self.leases.get(sublease_id))
+            {
+                self.close_lease(&mut sublease, ended_by);
+            }
 
-    impl Escrow for PropertyRegistry {
-        type Error = Error;
+            let transaction_hash: Hash = [0u8; 32].into();
+            self.env().emit_event(LeaseEnded {
+                lease_id: lease.id,
+                property_id: lease.property_id,
+                event_version: 1,
+                ended_by,
+                timestamp: self.env().block_timestamp(),
+                block_number: self.env().block_number(),
+                transaction_hash,
+            });
+        }
 
-        fn create_escrow(&mut self, property_id: u64, amount: u128) -> Result<u64, Self::Error> {
-            // For trait compatibility, use caller as buyer
-            // In production, use the direct create_escrow method with explicit buyer
-            use ink::codegen::Env;
-            let caller = self.env().caller();
-            self.create_escrow(property_id, caller, amount)
+        /// Gets a lease by ID
+        #[ink(message)]
+        pub fn get_lease(&self, lease_id: u64) -> Option<Lease> {
+            self.leases.get(lease_id)
         }
 
-        fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Self::Error> {
-            self.release_escrow(escrow_id)
+        /// Gets the pending or active lease on a property
+        #[ink(message)]
+        pub fn get_active_lease(&self, property_id: u64) -> Option<u64> {
+            self.property_active_leases.get(property_id)
         }
 
-        fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), Self::Error> {
-            self.refund_escrow(escrow_id)
+        /// Gets a page of the leases created on a property, oldest first
+        /// Covers pending, active and ended leases, including subleases; check `status` to
+        /// tell them apart.
+        #[ink(message)]
+        pub fn get_property_leases(&self, property_id: u64, offset: u32, limit: u32) -> Vec<Lease> {
+            let count = self.get_property_lease_count(property_id);
+            let end = offset
+                .saturating_add(limit.min(MAX_LEASE_PAGE_SIZE))
+                .min(count);
+            (offset..end)
+                .filter_map(|position| self.property_leases.get((property_id, position)))
+                .filter_map(|lease_id| self.leases.get(lease_id))
+                .collect()
         }
-    }
 
-    impl PropertyRegistry {
+        /// Gets the number of leases ever created on a property
         #[ink(message)]
-        pub fn enable_fractional(
-            &mut self,
-            property_id: u64,
-            total_shares: u128,
-        ) -> Result<(), Error> {
-            let caller = self.env().caller();
-            let property = self
-                .properties
-                .get(property_id)
-                .ok_or(Error::PropertyNotFound)?;
-            if caller != self.admin && caller != property.owner {
-                return Err(Error::Unauthorized);
-            }
-            if total_shares == 0 {
-                return Err(Error::InvalidMetadata);
-            }
-            let info = FractionalInfo {
-                total_shares,
-                enabled: true,
-                created_at: self.env().block_timestamp(),
-            };
-            self.fractional.insert(property_id, &info);
-            Ok(())
+        pub fn get_property_lease_count(&self, property_id: u64) -> u32 {
+            self.property_lease_count.get(property_id).unwrap_or(0)
         }
 
+        /// Gets a page of the leases an account has been offered or assigned, oldest first
+        /// Leases the account has since assigned on are included; their `tenant` is the
+        /// current one.
         #[ink(message)]
-        pub fn get_fractional_info(&self, property_id: u64) -> Option<FractionalInfo> {
-            self.fractional.get(property_id)
+        pub fn get_tenant_leases(&self, tenant: AccountId, offset: u32, limit: u32) -> Vec<Lease> {
+            let count = self.get_tenant_lease_count(tenant);
+            let end = offset
+                .saturating_add(limit.min(MAX_LEASE_PAGE_SIZE))
+                .min(count);
+            (offset..end)
+                .filter_map(|position| self.tenant_leases.get((tenant, position)))
+                .filter_map(|lease_id| self.leases.get(lease_id))
+                .collect()
         }
 
+        /// Gets the number of leases an account has been offered or assigned
         #[ink(message)]
-        pub fn is_fractional(&self, property_id: u64) -> bool {
-            self.fractional
-                .get(property_id)
-                .map(|i: FractionalInfo| i.enabled)
-                .unwrap_or(false)
+        pub fn get_tenant_lease_count(&self, tenant: AccountId) -> u32 {
+            self.tenant_lease_count.get(tenant).unwrap_or(0)
+        }
+
+        /// Helper: appends a lease to a tenant's lease index
+        fn index_tenant_lease(&mut self, tenant: AccountId, lease_id: u64) {
+            let position = self.get_tenant_lease_count(tenant);
+            self.tenant_leases.insert((tenant, position), &lease_id);
+            self.tenant_lease_count.insert(tenant, &(position + 1));
         }
     }
 }
@@ -2608,6 +14562,7 @@ mod tests_pause {
             legal_description: "Test Description".into(),
             valuation: 1000,
             documents_url: "http://test.com".into(),
+            jurisdiction: None,
         };
         assert_eq!(
             contract.register_property(metadata.clone()),
@@ -1,8 +1,23 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
+    use crate::propchain_contracts::AttestationSignature;
+    use crate::propchain_contracts::AuctionStatus;
+    use crate::propchain_contracts::ComplianceAttestation;
+    use crate::propchain_contracts::CompliancePolicy;
+    use crate::propchain_contracts::ComplianceSigner;
+    use crate::propchain_contracts::DepositStatus;
     use crate::propchain_contracts::Error;
+    use crate::propchain_contracts::EscrowAsset;
+    use crate::propchain_contracts::EscrowInfo;
+    use crate::propchain_contracts::EscrowState;
+    use crate::propchain_contracts::LeaseStatus;
+    use crate::propchain_contracts::ListingStatus;
+    use crate::propchain_contracts::OfferStatus;
     use crate::propchain_contracts::PropertyRegistry;
+    use crate::propchain_contracts::ProposalAction;
+    use crate::propchain_contracts::SaleType;
+    use crate::propchain_contracts::YieldLoss;
     use ink::primitives::AccountId;
     use propchain_traits::*;
 
@@ -16,6 +31,30 @@ mod tests {
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(sender);
     }
 
+    /// Helper function to point the contract at its own account for balance checks
+    fn set_contract_account() -> AccountId {
+        let contract = AccountId::from([0xFF; 32]);
+        ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+        ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 0);
+        contract
+    }
+
+    /// Helper function to transfer value from the caller with the next contract call
+    fn transfer_in(amount: u128) {
+        ink::env::test::transfer_in::<ink::env::DefaultEnvironment>(amount);
+    }
+
+    /// Helper function to read an account balance
+    fn balance_of(account: AccountId) -> u128 {
+        ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+            .unwrap_or_default()
+    }
+
+    /// Helper function to set an account's native balance
+    fn set_balance(account: AccountId, amount: u128) {
+        ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account, amount);
+    }
+
     /// Helper function to create a sample property metadata
     fn create_sample_metadata() -> PropertyMetadata {
         PropertyMetadata {
@@ -24,6 +63,7 @@ mod tests {
             legal_description: "Test property legal description".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         }
     }
 
@@ -41,9 +81,39 @@ mod tests {
             legal_description: legal_description.to_string(),
             valuation,
             documents_url: documents_url.to_string(),
+            jurisdiction: None,
         }
     }
 
+    /// Helper function to sign an attestation for the current contract with a dev ECDSA key
+    fn sign_attestation_ecdsa(
+        attestation: &ComplianceAttestation,
+    ) -> (ComplianceSigner, AttestationSignature) {
+        let keypair = ink_e2e::subxt_signer::ecdsa::dev::alice();
+        let contract = ink::env::account_id::<ink::env::DefaultEnvironment>();
+        // The key hashes the payload with Blake2x256 before signing, as the contract does
+        let signature = keypair.sign(&scale::Encode::encode(&(contract, attestation)));
+        (
+            ComplianceSigner::Ecdsa(keypair.public_key().0),
+            AttestationSignature::Ecdsa(signature.0),
+        )
+    }
+
+    /// Helper function to sign an attestation for the current contract with a dev sr25519 key
+    fn sign_attestation_sr25519(
+        attestation: &ComplianceAttestation,
+    ) -> (ComplianceSigner, AttestationSignature) {
+        use ink::env::hash::{Blake2x256, HashOutput};
+        let keypair = ink_e2e::subxt_signer::sr25519::dev::alice();
+        let contract = ink::env::account_id::<ink::env::DefaultEnvironment>();
+        let mut message = <Blake2x256 as HashOutput>::Type::default();
+        ink::env::hash_encoded::<Blake2x256, _>(&(contract, attestation), &mut message);
+        (
+            ComplianceSigner::Sr25519(keypair.public_key().0),
+            AttestationSignature::Sr25519(keypair.sign(&message).0),
+        )
+    }
+
     // ============================================================================
     // CORE FUNCTIONALITY TESTS
     // ============================================================================
@@ -926,6 +996,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = contract
@@ -938,6 +1009,7 @@ mod tests {
             legal_description: "Test property updated".to_string(),
             valuation: 1100000,
             documents_url: "https://example.com/docs/new".to_string(),
+            jurisdiction: None,
         };
 
         assert!(contract
@@ -964,6 +1036,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         };
         let property_id = contract
             .register_property(metadata)
@@ -976,6 +1049,7 @@ mod tests {
             legal_description: "Test property updated".to_string(),
             valuation: 1100000,
             documents_url: "https://example.com/docs/new".to_string(),
+            jurisdiction: None,
         };
         assert_eq!(
             contract.update_metadata(property_id, new_metadata),
@@ -995,6 +1069,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 1000000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         };
         let property_id = contract
             .register_property(metadata)
@@ -1032,6 +1107,7 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1039,6 +1115,7 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 3".to_string(),
@@ -1046,6 +1123,7 @@ mod tests {
                 legal_description: "Test property 3".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs3".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1086,6 +1164,7 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1093,6 +1172,7 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1136,6 +1216,7 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1143,6 +1224,7 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1160,6 +1242,7 @@ mod tests {
                     legal_description: "Updated test property 1".to_string(),
                     valuation: 120000,
                     documents_url: "https://example.com/docs1_updated".to_string(),
+                    jurisdiction: None,
                 },
             ),
             (
@@ -1170,6 +1253,7 @@ mod tests {
                     legal_description: "Updated test property 2".to_string(),
                     valuation: 170000,
                     documents_url: "https://example.com/docs2_updated".to_string(),
+                    jurisdiction: None,
                 },
             ),
         ];
@@ -1202,6 +1286,7 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1209,6 +1294,7 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 3".to_string(),
@@ -1216,6 +1302,7 @@ mod tests {
                 legal_description: "Test property 3".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs3".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1265,6 +1352,7 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1272,6 +1360,7 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1302,6 +1391,7 @@ mod tests {
                 legal_description: "Test property 1".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs1".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Property 2".to_string(),
@@ -1309,6 +1399,7 @@ mod tests {
                 legal_description: "Test property 2".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs2".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1351,6 +1442,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         }];
         contract
             .batch_register_properties(alice_properties)
@@ -1365,6 +1457,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Bob Property 2".to_string(),
@@ -1372,6 +1465,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
         ];
         contract
@@ -1402,6 +1496,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 50000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Medium Property".to_string(),
@@ -1409,6 +1504,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Expensive Property".to_string(),
@@ -1416,6 +1512,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 250000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1455,6 +1552,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 100000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Medium Property".to_string(),
@@ -1462,6 +1560,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 150000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
             PropertyMetadata {
                 location: "Large Property".to_string(),
@@ -1469,6 +1568,7 @@ mod tests {
                 legal_description: "Test property".to_string(),
                 valuation: 200000,
                 documents_url: "https://example.com/docs".to_string(),
+                jurisdiction: None,
             },
         ];
 
@@ -1509,6 +1609,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         };
 
         contract
@@ -1537,6 +1638,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         };
 
         // Register multiple properties
@@ -1577,6 +1679,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         }];
 
         let property_ids = contract
@@ -1604,6 +1707,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 100000,
             documents_url: "https://example.com/docs".to_string(),
+            jurisdiction: None,
         }];
 
         let property_ids = contract
@@ -1620,6 +1724,7 @@ mod tests {
                 legal_description: "Updated test property".to_string(),
                 valuation: 120000,
                 documents_url: "https://example.com/docs_updated".to_string(),
+                jurisdiction: None,
             },
         )];
 
@@ -1860,4 +1965,5602 @@ mod tests {
         assert_eq!(contract.check_account_compliance(accounts.alice), Ok(true));
         assert_eq!(contract.check_account_compliance(accounts.bob), Ok(true));
     }
+
+    // ============================================================================
+    // ESCROW FUNDS
+    // ============================================================================
+
+    #[ink::test]
+    fn test_fund_and_release_escrow_pays_seller() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let contract_account = set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        contract
+            .approve(property_id, Some(accounts.bob))
+            .expect("Failed to approve buyer");
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(contract.total_escrowed(), 500);
+        assert_eq!(balance_of(contract_account), 500);
+        assert_eq!(contract.get_escrow(escrow_id).unwrap().funded_amount, 500);
+
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(balance_of(accounts.alice), seller_balance + 500);
+        assert_eq!(balance_of(contract_account), 0);
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[ink::test]
+    fn test_fund_escrow_in_installments() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(!contract.is_escrow_funded(escrow_id));
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowNotFunded)
+        );
+
+        // Overshooting the agreed amount is rejected
+        transfer_in(400);
+        assert_eq!(
+            contract.fund_escrow(escrow_id),
+            Err(Error::InvalidEscrowDeposit)
+        );
+
+        transfer_in(300);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.is_escrow_funded(escrow_id));
+        assert_eq!(contract.get_escrow(escrow_id).unwrap().funded_amount, 500);
+
+        transfer_in(1);
+        assert_eq!(
+            contract.fund_escrow(escrow_id),
+            Err(Error::EscrowAlreadyFunded)
+        );
+    }
+
+    #[ink::test]
+    fn test_refund_escrow_returns_funds_to_buyer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        let buyer_balance = balance_of(accounts.bob);
+        transfer_in(500);
+        contract
+            .fund_escrow(escrow_id)
+            .expect("Failed to fund escrow");
+        assert_eq!(balance_of(accounts.bob), buyer_balance - 500);
+
+        set_caller(accounts.alice);
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance);
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[ink::test]
+    fn test_buyer_opens_escrow_and_seller_accepts() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        contract
+            .approve(property_id, Some(accounts.bob))
+            .expect("Failed to approve buyer");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        let escrow_id = contract
+            .open_escrow(property_id, 500, None)
+            .expect("Failed to open escrow");
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.buyer, accounts.bob);
+        assert_eq!(escrow.seller, accounts.alice);
+        assert_eq!(escrow.funded_amount, 500);
+        assert!(!escrow.seller_accepted);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowNotAccepted)
+        );
+
+        set_caller(accounts.charlie);
+        assert_eq!(contract.accept_escrow(escrow_id), Err(Error::Unauthorized));
+
+        set_caller(accounts.alice);
+        assert!(contract.accept_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.accept_escrow(escrow_id),
+            Err(Error::EscrowAlreadyAccepted)
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn test_open_escrow_rejects_own_property() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert_eq!(
+            contract.open_escrow(property_id, 500, None),
+            Err(Error::CannotBuyOwnProperty)
+        );
+    }
+
+    #[ink::test]
+    fn test_claim_expired_escrow_refunds_buyer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        let buyer_balance = balance_of(accounts.bob);
+        transfer_in(500);
+        let escrow_id = contract
+            .open_escrow(property_id, 500, Some(5000))
+            .expect("Failed to open escrow");
+        assert_eq!(
+            contract.claim_expired_escrow(escrow_id),
+            Err(Error::EscrowNotExpired)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5000);
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.claim_expired_escrow(escrow_id),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract.claim_expired_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance);
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Expired
+        );
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[ink::test]
+    fn test_escrow_deadline_must_be_in_future() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 500, Some(1000)),
+            Err(Error::InvalidEscrowDeadline)
+        );
+    }
+
+    #[ink::test]
+    fn test_dispute_blocks_escrow_until_arbitrated() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        contract
+            .set_escrow_arbitrator(Some(accounts.django))
+            .expect("Failed to set arbitrator");
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        let escrow_id = contract
+            .open_escrow(property_id, 500, None)
+            .expect("Failed to open escrow");
+        assert!(contract
+            .dispute_escrow(escrow_id, "Seller unresponsive".to_string())
+            .is_ok());
+        assert_eq!(
+            contract.dispute_escrow(escrow_id, "Again".to_string()),
+            Err(Error::EscrowDisputed)
+        );
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowDisputed)
+        );
+
+        // Only the arbitrator can resolve
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.resolve_escrow_dispute(escrow_id, 0, true),
+            Err(Error::NotArbitrator)
+        );
+
+        set_caller(accounts.django);
+        assert!(contract.resolve_escrow_dispute(escrow_id, 0, true).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(balance_of(accounts.alice), seller_balance + 500);
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.state, EscrowState::Released);
+    }
+
+    #[ink::test]
+    fn test_arbitrator_can_refund_and_block_title() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        contract
+            .set_escrow_arbitrator(Some(accounts.django))
+            .expect("Failed to set arbitrator");
+
+        set_caller(accounts.bob);
+        let buyer_balance = balance_of(accounts.bob);
+        transfer_in(500);
+        let escrow_id = contract
+            .open_escrow(property_id, 500, None)
+            .expect("Failed to open escrow");
+
+        set_caller(accounts.django);
+        assert_eq!(
+            contract.resolve_escrow_dispute(escrow_id, 10_000, false),
+            Err(Error::EscrowNotDisputed)
+        );
+
+        set_caller(accounts.alice);
+        contract
+            .dispute_escrow(escrow_id, "Financing fell through".to_string())
+            .expect("Failed to dispute");
+
+        set_caller(accounts.django);
+        assert!(contract
+            .resolve_escrow_dispute(escrow_id, 10_000, false)
+            .is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+        assert_eq!(balance_of(accounts.bob), buyer_balance);
+    }
+
+    #[ink::test]
+    fn test_set_escrow_arbitrator_admin_only() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert_eq!(contract.get_escrow_arbitrator(), None);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_escrow_arbitrator(Some(accounts.bob)),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_cancel_escrow_requires_both_parties() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        let bob_balance = balance_of(accounts.bob);
+
+        // Buyer asks to cancel; funds stay locked until the seller agrees
+        assert!(contract.cancel_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.cancel_escrow(escrow_id),
+            Err(Error::CancellationAlreadyRequested)
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Funded
+        );
+        assert_eq!(contract.total_escrowed(), 500);
+
+        set_caller(accounts.charlie);
+        assert_eq!(contract.cancel_escrow(escrow_id), Err(Error::Unauthorized));
+
+        set_caller(accounts.alice);
+        assert!(contract.cancel_escrow(escrow_id).is_ok());
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.state, EscrowState::Refunded);
+        assert_eq!(escrow.funded_amount, 0);
+        assert_eq!(contract.total_escrowed(), 0);
+        assert_eq!(balance_of(accounts.bob), bob_balance + 500);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    #[ink::test]
+    fn test_release_escrow_deducts_platform_fee() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let contract_account = set_contract_account();
+        assert!(contract.set_escrow_fee_bps(250).is_ok());
+        assert!(contract.set_treasury(accounts.django).is_ok());
+
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 800, None)
+            .expect("Failed to create escrow");
+        contract
+            .approve(property_id, Some(accounts.bob))
+            .expect("Failed to approve buyer");
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        transfer_in(800);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        // 2.5% of 800 stays in the contract as platform fees
+        assert_eq!(balance_of(accounts.alice), seller_balance + 780);
+        assert_eq!(contract.treasury_balance(), 20);
+        assert_eq!(contract.total_escrowed(), 0);
+        assert_eq!(balance_of(contract_account), 20);
+
+        assert_eq!(contract.withdraw_fees(), Err(Error::Unauthorized));
+
+        set_caller(accounts.alice);
+        assert_eq!(contract.withdraw_fees(), Ok(20));
+        assert_eq!(balance_of(accounts.django), 20);
+        assert_eq!(contract.treasury_balance(), 0);
+        assert_eq!(contract.withdraw_fees(), Err(Error::NoFeesToWithdraw));
+    }
+
+    #[ink::test]
+    fn test_escrow_fee_config_admin_only() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        assert_eq!(
+            contract.set_escrow_fee_bps(10_001),
+            Err(Error::InvalidEscrowFee)
+        );
+        assert_eq!(contract.get_treasury(), accounts.alice);
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.set_escrow_fee_bps(100), Err(Error::Unauthorized));
+        assert_eq!(
+            contract.set_treasury(accounts.bob),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(contract.get_escrow_fee_bps(), 0);
+    }
+
+    #[ink::test]
+    fn test_create_escrow_psp22_validates_before_pulling_tokens() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let token = accounts.frank;
+
+        assert_eq!(
+            contract.create_escrow_psp22(property_id, token, 500),
+            Err(Error::CannotBuyOwnProperty)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.create_escrow_psp22(property_id, token, 0),
+            Err(Error::InvalidEscrowDeposit)
+        );
+        assert_eq!(
+            contract.create_escrow_psp22(property_id, token, 500),
+            Err(Error::UnsupportedEscrowAsset)
+        );
+        assert_eq!(
+            contract.create_escrow_psp22(999, token, 500),
+            Err(Error::PropertyNotFound)
+        );
+        assert_eq!(contract.get_escrow(1), None);
+    }
+
+    #[ink::test]
+    fn test_native_escrow_records_asset() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().asset,
+            EscrowAsset::Native
+        );
+    }
+
+    #[ink::test]
+    fn test_escrow_state_distinguishes_refund_from_release() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Created
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Created
+        );
+        transfer_in(300);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Funded
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Refunded
+        );
+
+        // A refunded escrow is closed to every further transition
+        assert_eq!(contract.refund_escrow(escrow_id), Err(Error::EscrowClosed));
+        set_caller(accounts.bob);
+        assert_eq!(contract.release_escrow(escrow_id), Err(Error::EscrowClosed));
+        assert_eq!(
+            contract.dispute_escrow(escrow_id, String::from("late")),
+            Err(Error::EscrowClosed)
+        );
+    }
+
+    #[ink::test]
+    fn test_escrow_state_transitions() {
+        assert!(EscrowState::Created.can_transition_to(EscrowState::Funded));
+        assert!(EscrowState::Funded.can_transition_to(EscrowState::Released));
+        assert!(EscrowState::Disputed.can_transition_to(EscrowState::Refunded));
+        assert!(!EscrowState::Created.can_transition_to(EscrowState::Released));
+        assert!(!EscrowState::Disputed.can_transition_to(EscrowState::Expired));
+        assert!(!EscrowState::Released.can_transition_to(EscrowState::Refunded));
+        assert!(EscrowState::Expired.is_final());
+        assert!(!EscrowState::Disputed.is_final());
+    }
+
+    #[ink::test]
+    fn test_escrow_indexes_and_pagination() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        let e1 = contract
+            .create_escrow(first, accounts.bob, 100, None)
+            .expect("Failed to create escrow");
+        let e2 = contract
+            .create_escrow(first, accounts.charlie, 200, None)
+            .expect("Failed to create escrow");
+        let e3 = contract
+            .create_escrow(second, accounts.bob, 300, None)
+            .expect("Failed to create escrow");
+
+        let ids = |escrows: Vec<EscrowInfo>| -> Vec<u64> { escrows.iter().map(|e| e.id).collect() };
+
+        assert_eq!(
+            ids(contract.get_escrows_by_property(first, 0, 10)),
+            vec![e1, e2]
+        );
+        assert_eq!(
+            ids(contract.get_escrows_by_buyer(accounts.bob, 0, 10)),
+            vec![e1, e3]
+        );
+        assert_eq!(
+            ids(contract.get_escrows_by_seller(accounts.alice, 0, 10)),
+            vec![e1, e2, e3]
+        );
+
+        // Pagination
+        assert_eq!(
+            ids(contract.get_escrows_by_seller(accounts.alice, 1, 1)),
+            vec![e2]
+        );
+        assert!(contract
+            .get_escrows_by_seller(accounts.alice, 5, 10)
+            .is_empty());
+        assert!(contract
+            .get_escrows_by_buyer(accounts.django, 0, 10)
+            .is_empty());
+        assert_eq!(contract.get_escrow_count_by_property(first), 2);
+        assert_eq!(contract.get_escrow_count_by_buyer(accounts.bob), 2);
+        assert_eq!(contract.get_escrow_count_by_seller(accounts.alice), 3);
+        assert_eq!(contract.get_escrow_count_by_buyer(accounts.django), 0);
+    }
+
+    #[ink::test]
+    fn test_zero_amount_escrows_rejected() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let fractional_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(fractional_id, 100).is_ok());
+
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 0, None),
+            Err(Error::InvalidEscrowDeposit)
+        );
+        assert_eq!(
+            contract.create_share_escrow(fractional_id, accounts.bob, 10, 0, None),
+            Err(Error::InvalidEscrowDeposit)
+        );
+        set_caller(accounts.bob);
+        transfer_in(0);
+        assert_eq!(
+            contract.open_escrow(property_id, 0, None),
+            Err(Error::InvalidEscrowDeposit)
+        );
+        assert_eq!(
+            contract.open_share_escrow(fractional_id, accounts.alice, 10, 0, None),
+            Err(Error::InvalidEscrowDeposit)
+        );
+
+        // Nothing was indexed against the property or either party
+        assert_eq!(contract.escrow_count(), 0);
+        assert_eq!(contract.get_escrow_count_by_property(property_id), 0);
+        assert_eq!(contract.get_escrow_count_by_buyer(accounts.bob), 0);
+        assert_eq!(contract.get_escrow_count_by_seller(accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn test_release_escrow_moves_title_without_approval() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(
+            contract.get_owner_properties(accounts.bob),
+            vec![property_id]
+        );
+        assert!(contract.get_owner_properties(accounts.alice).is_empty());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 500);
+    }
+
+    #[ink::test]
+    fn test_release_escrow_fails_if_seller_no_longer_owns_property() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+
+        // The seller sells the title elsewhere before settlement
+        set_caller(accounts.alice);
+        assert!(contract
+            .transfer_property(property_id, accounts.charlie)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowSellerNotOwner)
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Funded
+        );
+        assert_eq!(contract.total_escrowed(), 500);
+    }
+
+    #[ink::test]
+    fn test_earnest_forfeited_when_buyer_abandons_accepted_escrow() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        transfer_in(50);
+        let escrow_id = contract
+            .open_escrow_with_earnest(property_id, 500, None)
+            .expect("Failed to make offer");
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+
+        set_caller(accounts.alice);
+        assert!(contract.accept_escrow(escrow_id).is_ok());
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        let buyer_balance = balance_of(accounts.bob);
+        assert!(contract.abandon_escrow(escrow_id).is_ok());
+
+        // The earnest goes to the seller, the rest of the deposits back to the buyer
+        assert_eq!(balance_of(accounts.alice), seller_balance + 50);
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 200);
+        assert_eq!(contract.total_escrowed(), 0);
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Refunded
+        );
+    }
+
+    #[ink::test]
+    fn test_earnest_forfeited_when_accepted_escrow_expires() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        transfer_in(50);
+        let escrow_id = contract
+            .open_escrow_with_earnest(property_id, 500, Some(100))
+            .expect("Failed to make offer");
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+
+        set_caller(accounts.alice);
+        assert!(contract.accept_escrow(escrow_id).is_ok());
+        let seller_balance = balance_of(accounts.alice);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+        set_caller(accounts.bob);
+        let buyer_balance = balance_of(accounts.bob);
+        assert!(contract.claim_expired_escrow(escrow_id).is_ok());
+
+        // Letting an accepted escrow lapse costs the earnest and counts as a default
+        assert_eq!(balance_of(accounts.alice), seller_balance + 50);
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 200);
+        assert_eq!(contract.get_buyer_defaults(accounts.bob), 1);
+        assert_eq!(contract.total_escrowed(), 0);
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Expired
+        );
+    }
+
+    #[ink::test]
+    fn test_earnest_refunded_when_unaccepted_escrow_expires() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        transfer_in(50);
+        let escrow_id = contract
+            .open_escrow_with_earnest(property_id, 500, Some(100))
+            .expect("Failed to make offer");
+        let seller_balance = balance_of(accounts.alice);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+        let buyer_balance = balance_of(accounts.bob);
+        assert!(contract.claim_expired_escrow(escrow_id).is_ok());
+
+        // The seller never accepted, so the whole deposit comes back with no default
+        assert_eq!(balance_of(accounts.alice), seller_balance);
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 50);
+        assert_eq!(contract.get_buyer_defaults(accounts.bob), 0);
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[ink::test]
+    fn test_earnest_counts_toward_amount_and_returned_before_acceptance() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        transfer_in(0);
+        assert_eq!(
+            contract.open_escrow_with_earnest(property_id, 500, None),
+            Err(Error::InvalidEscrowDeposit)
+        );
+
+        transfer_in(100);
+        let escrow_id = contract
+            .open_escrow_with_earnest(property_id, 500, None)
+            .expect("Failed to make offer");
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.earnest_amount, 100);
+        assert_eq!(escrow.funded_amount, 100);
+
+        // Withdrawing before the seller accepts returns the earnest
+        let buyer_balance = balance_of(accounts.bob);
+        assert!(contract.abandon_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 100);
+
+        // On a completed sale the earnest is part of the price
+        transfer_in(100);
+        let escrow_id = contract
+            .open_escrow_with_earnest(property_id, 300, None)
+            .expect("Failed to make offer");
+        set_caller(accounts.alice);
+        assert!(contract.accept_escrow(escrow_id).is_ok());
+        let seller_balance = balance_of(accounts.alice);
+        set_caller(accounts.bob);
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 300);
+    }
+
+    #[ink::test]
+    fn test_release_requires_all_escrow_conditions_satisfied() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        assert!(contract
+            .set_escrow_notary(escrow_id, Some(accounts.charlie))
+            .is_ok());
+        assert_eq!(
+            contract.add_escrow_condition(escrow_id, String::from("inspection")),
+            Ok(0)
+        );
+
+        // The notary can attach conditions too
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.add_escrow_condition(escrow_id, String::from("title search")),
+            Ok(1)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.add_escrow_condition(escrow_id, String::from("buyer")),
+            Err(Error::Unauthorized)
+        );
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowConditionsNotMet)
+        );
+
+        assert!(contract.satisfy_escrow_condition(escrow_id, 0).is_ok());
+        assert_eq!(
+            contract.satisfy_escrow_condition(escrow_id, 0),
+            Err(Error::EscrowConditionAlreadySatisfied)
+        );
+        assert_eq!(
+            contract.satisfy_escrow_condition(escrow_id, 5),
+            Err(Error::EscrowConditionNotFound)
+        );
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowConditionsNotMet)
+        );
+
+        set_caller(accounts.charlie);
+        assert!(contract.satisfy_escrow_condition(escrow_id, 1).is_ok());
+        let conditions = contract.get_escrow_conditions(escrow_id);
+        assert!(conditions.iter().all(|c| c.satisfied));
+        assert_eq!(conditions[1].satisfied_by, Some(accounts.charlie));
+        assert!(contract.notary_approve(escrow_id).is_ok());
+
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+    }
+
+    #[ink::test]
+    fn test_multisig_escrow_release_executes_at_threshold() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        assert_eq!(
+            contract.set_escrow_signers(escrow_id, vec![accounts.bob], 2),
+            Err(Error::InvalidSignerThreshold)
+        );
+        assert!(contract
+            .set_escrow_signers(
+                escrow_id,
+                vec![accounts.bob, accounts.charlie, accounts.django],
+                2
+            )
+            .is_ok());
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::InsufficientApprovals)
+        );
+
+        assert_eq!(contract.approve_release(escrow_id), Ok(false));
+        assert_eq!(
+            contract.approve_release(escrow_id),
+            Err(Error::AlreadyApproved)
+        );
+
+        set_caller(accounts.eve);
+        assert_eq!(
+            contract.approve_release(escrow_id),
+            Err(Error::Unauthorized)
+        );
+
+        // The second signature meets the threshold and settles the escrow
+        set_caller(accounts.charlie);
+        assert_eq!(contract.approve_release(escrow_id), Ok(true));
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Released
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn test_enable_escrow_yield_requires_both_parties() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        assert_eq!(
+            contract.enable_escrow_yield(escrow_id, 5_000),
+            Err(Error::YieldSourceNotSet)
+        );
+        assert!(contract.set_yield_source(Some(accounts.frank)).is_ok());
+
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.enable_escrow_yield(escrow_id, 5_000),
+            Err(Error::Unauthorized)
+        );
+
+        // The buyer proposes a 70/30 split; a mismatched reply becomes a counter-proposal
+        set_caller(accounts.bob);
+        assert!(contract.enable_escrow_yield(escrow_id, 7_000).is_ok());
+        set_caller(accounts.alice);
+        assert!(contract.enable_escrow_yield(escrow_id, 5_000).is_ok());
+        assert_eq!(
+            contract
+                .get_escrow(escrow_id)
+                .unwrap()
+                .yield_buyer_share_bps,
+            None
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract.enable_escrow_yield(escrow_id, 5_000).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.yield_buyer_share_bps, Some(5_000));
+        // Nothing is routed until the escrow is fully funded
+        assert_eq!(escrow.yield_principal, 0);
+
+        assert_eq!(
+            contract.enable_escrow_yield(escrow_id, 5_000),
+            Err(Error::InvalidEscrowTransition)
+        );
+    }
+
+    #[ink::test]
+    fn test_yield_loss_is_split_and_charged_pro_rata() {
+        // A 100 shortfall on a position whose interest the buyer takes 30% of
+        let loss = YieldLoss::split(100, 3_000);
+        assert_eq!(
+            loss,
+            YieldLoss {
+                buyer: 30,
+                seller: 70
+            }
+        );
+        assert_eq!(loss.total(), 100);
+
+        // Settlement: each party's payout carries its own share
+        assert_eq!(loss.charge(200, 900), (170, 830));
+        // A share larger than a party's payout falls on the other party
+        assert_eq!(loss.charge(10, 900), (0, 810));
+        assert_eq!(loss.charge(1_000, 0), (900, 0));
+        assert_eq!(loss.charge(0, 0), (0, 0));
+
+        assert_eq!(YieldLoss::split(0, 5_000).charge(10, 20), (10, 20));
+        assert_eq!(YieldLoss::split(7, 10_000).seller, 0);
+    }
+
+    #[ink::test]
+    fn test_cooling_off_delays_escrow_settlement() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract.set_escrow_cooling_off_period(1_000).is_ok());
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        assert_eq!(
+            contract.finalize_escrow(escrow_id),
+            Err(Error::EscrowNotApproved)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5_000);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        // Approval alone does not move funds or title
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.approved_at, Some(5_000));
+        assert_eq!(escrow.state, EscrowState::Funded);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::AlreadyApproved)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5_999);
+        assert_eq!(
+            contract.finalize_escrow(escrow_id),
+            Err(Error::CoolingOffActive)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(6_000);
+        set_caller(accounts.alice);
+        assert!(contract.finalize_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Released
+        );
+    }
+
+    #[ink::test]
+    fn test_refund_returns_exactly_the_funded_balance() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let contract_account = set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(300);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        let buyer_balance = balance_of(accounts.bob);
+
+        // Only the 300 actually deposited goes back, not the agreed 500
+        set_caller(accounts.alice);
+        assert!(contract.refund_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 300);
+        assert_eq!(balance_of(contract_account), 0);
+        assert_eq!(contract.total_escrowed(), 0);
+
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.state, EscrowState::Refunded);
+        assert_eq!(escrow.funded_amount, 0);
+        assert_eq!(contract.refund_escrow(escrow_id), Err(Error::EscrowClosed));
+    }
+
+    #[ink::test]
+    fn test_escrow_count_tracks_created_escrows() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert_eq!(contract.escrow_count(), 0);
+
+        let first = contract
+            .create_escrow(property_id, accounts.bob, 100, None)
+            .expect("Failed to create escrow");
+        set_caller(accounts.charlie);
+        let second = contract
+            .open_escrow(property_id, 200, None)
+            .expect("Failed to open escrow");
+
+        assert_eq!((first, second), (1, 2));
+        assert_eq!(contract.escrow_count(), 2);
+        assert_eq!(
+            contract.get_escrows_by_seller(accounts.alice, 0, 10).len(),
+            2
+        );
+    }
+
+    #[ink::test]
+    fn test_arbitrator_can_split_disputed_escrow() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract
+            .set_escrow_arbitrator(Some(accounts.django))
+            .is_ok());
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract
+            .dispute_escrow(escrow_id, String::from("partial damage"))
+            .is_ok());
+        let seller_balance = balance_of(accounts.alice);
+        let buyer_balance = balance_of(accounts.bob);
+
+        set_caller(accounts.django);
+        assert_eq!(
+            contract.resolve_escrow_dispute(escrow_id, 10_001, false),
+            Err(Error::InvalidBasisPoints)
+        );
+        // 70% back to the buyer, 30% to the seller, title stays put
+        assert!(contract
+            .resolve_escrow_dispute(escrow_id, 7_000, false)
+            .is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 350);
+        assert_eq!(balance_of(accounts.alice), seller_balance + 150);
+        assert_eq!(contract.total_escrowed(), 0);
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().state,
+            EscrowState::Refunded
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    #[ink::test]
+    fn test_arbitrated_share_block_escrow_is_not_a_sale() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract
+            .set_escrow_arbitrator(Some(accounts.django))
+            .is_ok());
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        let escrow_id = contract
+            .create_share_escrow(property_id, accounts.bob, 300, 500, None)
+            .expect("Failed to create share escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract
+            .dispute_escrow(escrow_id, String::from("late handover"))
+            .is_ok());
+
+        set_caller(accounts.django);
+        assert!(contract.resolve_escrow_dispute(escrow_id, 0, true).is_ok());
+
+        // The shares move, but the property itself was not sold
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 300);
+        assert_eq!(contract.get_share_balance(property_id, accounts.alice), 700);
+        assert!(contract.get_property_sales(property_id, 0, 10).is_empty());
+    }
+
+    #[ink::test]
+    fn test_whole_title_escrow_rejected_for_fractional_property() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 500, None),
+            Err(Error::PropertyFractionalized)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.open_escrow(property_id, 500, None),
+            Err(Error::PropertyFractionalized)
+        );
+        assert_eq!(
+            contract.create_escrow_psp22(property_id, accounts.frank, 500),
+            Err(Error::PropertyFractionalized)
+        );
+    }
+
+    #[ink::test]
+    fn test_top_up_and_withdraw_excess() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let contract_account = set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.withdraw_excess(escrow_id),
+            Err(Error::NoExcessToWithdraw)
+        );
+        transfer_in(300);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        // Top-ups may overshoot the agreed price
+        transfer_in(350);
+        assert!(contract.top_up_escrow(escrow_id).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.funded_amount, 650);
+        assert_eq!(escrow.state, EscrowState::Funded);
+
+        let buyer_balance = balance_of(accounts.bob);
+        assert_eq!(contract.withdraw_excess(escrow_id), Ok(150));
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 150);
+        assert_eq!(contract.get_escrow(escrow_id).unwrap().funded_amount, 500);
+        assert_eq!(contract.total_escrowed(), 500);
+        assert_eq!(balance_of(contract_account), 500);
+    }
+
+    #[ink::test]
+    fn test_release_returns_surplus_to_buyer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let contract_account = set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        transfer_in(600);
+        assert!(contract.top_up_escrow(escrow_id).is_ok());
+        let buyer_balance = balance_of(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        assert_eq!(balance_of(accounts.alice), seller_balance + 500);
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 100);
+        assert_eq!(balance_of(contract_account), 0);
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[ink::test]
+    fn test_escrow_released_event_indexes_both_parties() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        // The release event is emitted last, with buyer and seller among its topics
+        let events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let released = events.last().expect("EscrowReleased should be emitted");
+        let buyer: &[u8] = accounts.bob.as_ref();
+        let seller: &[u8] = accounts.alice.as_ref();
+        assert!(released
+            .topics
+            .iter()
+            .any(|topic| topic.as_slice() == buyer));
+        assert!(released
+            .topics
+            .iter()
+            .any(|topic| topic.as_slice() == seller));
+    }
+
+    #[ink::test]
+    fn test_notary_must_cosign_release() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        // The buyer attaches the notary
+        set_caller(accounts.bob);
+        assert!(contract
+            .set_escrow_notary(escrow_id, Some(accounts.django))
+            .is_ok());
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.set_escrow_notary(escrow_id, None),
+            Err(Error::EscrowAlreadyFunded)
+        );
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::NotaryApprovalRequired)
+        );
+        assert_eq!(contract.notary_approve(escrow_id), Err(Error::Unauthorized));
+
+        set_caller(accounts.django);
+        assert!(contract.notary_approve(escrow_id).is_ok());
+        assert_eq!(
+            contract.notary_approve(escrow_id),
+            Err(Error::AlreadyApproved)
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn test_release_escrows_settles_batch_atomically() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let e1 = contract
+            .create_escrow(first, accounts.bob, 200, None)
+            .expect("Failed to create escrow");
+        let e2 = contract
+            .create_escrow(second, accounts.bob, 300, None)
+            .expect("Failed to create escrow");
+        let seller_balance = balance_of(accounts.alice);
+
+        set_caller(accounts.bob);
+        transfer_in(200);
+        assert!(contract.fund_escrow(e1).is_ok());
+
+        // The second escrow is unfunded, so nothing is settled
+        assert_eq!(
+            contract.release_escrows(vec![e1, e2]),
+            Err(Error::EscrowNotFunded)
+        );
+        assert_eq!(contract.get_escrow(e1).unwrap().state, EscrowState::Funded);
+        assert_eq!(contract.get_property(first).unwrap().owner, accounts.alice);
+
+        transfer_in(300);
+        assert!(contract.fund_escrow(e2).is_ok());
+        assert_eq!(
+            contract.release_escrows(vec![e1, e1]),
+            Err(Error::InvalidEscrowTransition)
+        );
+        assert!(contract.release_escrows(vec![e1, e2]).is_ok());
+
+        assert_eq!(contract.get_property(first).unwrap().owner, accounts.bob);
+        assert_eq!(contract.get_property(second).unwrap().owner, accounts.bob);
+        assert_eq!(balance_of(accounts.alice), seller_balance + 500);
+        assert_eq!(contract.total_escrowed(), 0);
+    }
+
+    #[ink::test]
+    fn test_escrow_agent_acts_for_buyer_until_expiry() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 200, None)
+            .expect("Failed to create escrow");
+
+        // Only the parties may appoint agents, and not each other
+        set_caller(accounts.django);
+        assert_eq!(
+            contract.delegate_escrow_agent(escrow_id, accounts.charlie, 1_000),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.delegate_escrow_agent(escrow_id, accounts.alice, 1_000),
+            Err(Error::InvalidDelegation)
+        );
+        assert!(contract
+            .delegate_escrow_agent(escrow_id, accounts.charlie, 1_000)
+            .is_ok());
+        assert_eq!(
+            contract
+                .get_escrow_delegation(escrow_id, accounts.charlie)
+                .unwrap()
+                .principal,
+            accounts.bob
+        );
+
+        // The agent funds and releases on the buyer's behalf
+        set_caller(accounts.charlie);
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(contract.refund_escrow(escrow_id), Err(Error::Unauthorized));
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+
+        // Expired and revoked delegations no longer authorize anything
+        set_caller(accounts.alice);
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second_escrow = contract
+            .create_escrow(second, accounts.bob, 100, None)
+            .expect("Failed to create escrow");
+        set_caller(accounts.bob);
+        assert!(contract
+            .delegate_escrow_agent(second_escrow, accounts.charlie, 1_000)
+            .is_ok());
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        set_caller(accounts.charlie);
+        transfer_in(100);
+        assert_eq!(
+            contract.fund_escrow(second_escrow),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.django);
+        assert_eq!(
+            contract.revoke_escrow_agent(second_escrow, accounts.charlie),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.bob);
+        assert!(contract
+            .revoke_escrow_agent(second_escrow, accounts.charlie)
+            .is_ok());
+        assert_eq!(
+            contract.get_escrow_delegation(second_escrow, accounts.charlie),
+            None
+        );
+    }
+
+    #[ink::test]
+    fn test_seller_acceptance_window_lapses() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_escrow_acceptance_window(100).is_ok());
+        assert_eq!(contract.get_escrow_acceptance_window(), 100);
+
+        // Unfunded escrows have no acceptance deadline yet
+        set_caller(accounts.bob);
+        let unfunded = contract
+            .open_escrow(property_id, 500, None)
+            .expect("Failed to open escrow");
+        assert_eq!(
+            contract.get_escrow(unfunded).unwrap().acceptance_deadline,
+            None
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+        let buyer_balance = balance_of(accounts.bob);
+        transfer_in(100);
+        let escrow_id = contract
+            .open_escrow_with_earnest(property_id, 500, None)
+            .expect("Failed to make offer");
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().acceptance_deadline,
+            Some(110)
+        );
+
+        // Acceptance inside the window is recorded
+        set_caller(accounts.alice);
+        assert!(contract.accept_escrow(unfunded).is_ok());
+        assert_eq!(contract.get_escrow(unfunded).unwrap().accepted_at, Some(10));
+
+        // Past the window the seller can no longer accept and the buyer leaves with everything
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(111);
+        assert_eq!(
+            contract.accept_escrow(escrow_id),
+            Err(Error::EscrowAcceptanceExpired)
+        );
+        set_caller(accounts.bob);
+        assert!(contract.abandon_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), buyer_balance);
+        assert_eq!(contract.get_escrow(escrow_id).unwrap().accepted_at, None);
+    }
+
+    #[ink::test]
+    fn test_escrow_trait_interface() {
+        use propchain_traits::{Escrow, EscrowError, EscrowStatus};
+
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            Escrow::open_escrow(&mut contract, 999, 100, None),
+            Err(EscrowError::PropertyNotFound)
+        );
+        let escrow_id = Escrow::open_escrow(&mut contract, property_id, 100, None)
+            .expect("Failed to create escrow");
+        transfer_in(100);
+        assert!(Escrow::fund_escrow(&mut contract, escrow_id).is_ok());
+        assert!(Escrow::is_escrow_funded(&contract, escrow_id));
+
+        // The seller has not accepted, so release is refused with a mapped error
+        assert_eq!(
+            Escrow::release_escrow(&mut contract, escrow_id),
+            Err(EscrowError::NotAccepted)
+        );
+
+        let details = Escrow::get_escrow_details(&contract, escrow_id).unwrap();
+        assert_eq!(details.buyer, accounts.bob);
+        assert_eq!(details.seller, accounts.alice);
+        assert_eq!(details.funded_amount, 100);
+        assert_eq!(details.status, EscrowStatus::Funded);
+        assert_eq!(Escrow::get_escrow_details(&contract, 999), None);
+    }
+
+    #[ink::test]
+    fn test_settlement_records_sale_receipt() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 300, None)
+            .expect("Failed to create escrow");
+        assert_eq!(contract.sale_count(), 0);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(42);
+        set_caller(accounts.bob);
+        transfer_in(300);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        assert_eq!(contract.sale_count(), 1);
+        let sale = contract
+            .get_sale_record(1)
+            .expect("Sale should be recorded");
+        assert_eq!(sale.sale_type, SaleType::Escrow);
+        assert_eq!(sale.source_id, escrow_id);
+        assert_eq!(sale.property_id, property_id);
+        assert_eq!(sale.buyer, accounts.bob);
+        assert_eq!(sale.seller, accounts.alice);
+        assert_eq!(sale.price, 300);
+        assert_eq!(sale.asset, EscrowAsset::Native);
+        assert_eq!(sale.timestamp, 42);
+        assert_eq!(contract.get_property_sales(property_id, 0, 10), vec![sale]);
+        assert!(contract.get_property_sales(property_id, 1, 10).is_empty());
+    }
+
+    #[ink::test]
+    fn test_escrow_compliance_toggles() {
+        use propchain_traits::ComplianceOperation;
+
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.is_escrow_compliance_required(ComplianceOperation::CreateEscrow));
+        assert!(contract.is_escrow_compliance_required(ComplianceOperation::ReleaseEscrow));
+
+        assert!(contract
+            .set_escrow_compliance_required(ComplianceOperation::ReleaseEscrow, false)
+            .is_ok());
+        assert!(!contract.is_escrow_compliance_required(ComplianceOperation::ReleaseEscrow));
+        assert!(contract.is_escrow_compliance_required(ComplianceOperation::CreateEscrow));
+        assert!(contract
+            .set_escrow_compliance_required(ComplianceOperation::ReleaseEscrow, true)
+            .is_ok());
+        assert!(contract.is_escrow_compliance_required(ComplianceOperation::ReleaseEscrow));
+
+        // Only escrow steps can be toggled, and only by the admin
+        assert_eq!(
+            contract.set_escrow_compliance_required(ComplianceOperation::Purchase, false),
+            Err(Error::InvalidComplianceOperation)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_escrow_compliance_required(ComplianceOperation::CreateEscrow, false),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_accepted_escrow_assets() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let token = EscrowAsset::Psp22(accounts.frank);
+        assert_eq!(
+            contract.get_accepted_escrow_assets(),
+            vec![EscrowAsset::Native]
+        );
+
+        assert!(contract.set_escrow_asset_accepted(token, true).is_ok());
+        assert!(contract.set_escrow_asset_accepted(token, true).is_ok());
+        assert!(contract.is_escrow_asset_accepted(token));
+        assert_eq!(
+            contract.get_accepted_escrow_assets(),
+            vec![EscrowAsset::Native, token]
+        );
+
+        // Withdrawing the native asset blocks new native escrows
+        assert!(contract
+            .set_escrow_asset_accepted(EscrowAsset::Native, false)
+            .is_ok());
+        assert_eq!(contract.get_accepted_escrow_assets(), vec![token]);
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 500, None),
+            Err(Error::UnsupportedEscrowAsset)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.open_escrow(property_id, 500, None),
+            Err(Error::UnsupportedEscrowAsset)
+        );
+        assert_eq!(
+            contract.set_escrow_asset_accepted(EscrowAsset::Native, true),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_sweep_expired_escrows_pays_bounty() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract.set_escrow_fee_bps(1_000).is_ok());
+        assert!(contract.set_escrow_sweep_bounty(15).is_ok());
+        let mut escrow_ids = Vec::new();
+        for deadline in [None, Some(100), Some(100), None] {
+            let property_id = contract
+                .register_property(create_sample_metadata())
+                .expect("Failed to register property");
+            escrow_ids.push(
+                contract
+                    .create_escrow(property_id, accounts.bob, 100, deadline)
+                    .expect("Failed to create escrow"),
+            );
+        }
+
+        // A settled sale puts 10 in the fee pool
+        set_caller(accounts.bob);
+        for &escrow_id in &escrow_ids[..3] {
+            transfer_in(100);
+            assert!(contract.fund_escrow(escrow_id).is_ok());
+        }
+        assert!(contract.release_escrow(escrow_ids[0]).is_ok());
+        assert_eq!(contract.treasury_balance(), 10);
+
+        set_caller(accounts.django);
+        assert_eq!(contract.sweep_expired_escrows(10), Ok(0));
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+        let buyer_balance = balance_of(accounts.bob);
+        assert_eq!(contract.sweep_expired_escrows(10), Ok(2));
+        assert_eq!(
+            contract.get_escrow(escrow_ids[1]).unwrap().state,
+            EscrowState::Expired
+        );
+        assert_eq!(
+            contract.get_escrow(escrow_ids[3]).unwrap().state,
+            EscrowState::Created
+        );
+        assert_eq!(balance_of(accounts.bob), buyer_balance + 200);
+
+        // The bounty is capped by the fee pool
+        assert_eq!(balance_of(accounts.django), 10);
+        assert_eq!(contract.treasury_balance(), 0);
+        assert_eq!(contract.sweep_expired_escrows(10), Ok(0));
+
+        assert_eq!(
+            contract.set_escrow_sweep_bounty(1),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_sweep_bounty_only_for_refunded_escrows() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract.set_escrow_fee_bps(1_000).is_ok());
+        assert!(contract.set_escrow_sweep_bounty(15).is_ok());
+        let mut property_ids = Vec::new();
+        for _ in 0..3 {
+            property_ids.push(
+                contract
+                    .register_property(create_sample_metadata())
+                    .expect("Failed to register property"),
+            );
+        }
+        let sold = contract
+            .create_escrow(property_ids[0], accounts.bob, 1_000, None)
+            .expect("Failed to create escrow");
+        set_caller(accounts.bob);
+        transfer_in(1_000);
+        assert!(contract.fund_escrow(sold).is_ok());
+        assert!(contract.release_escrow(sold).is_ok());
+        assert_eq!(contract.treasury_balance(), 100);
+
+        // Zero-deposit escrows opened with a near deadline
+        set_caller(accounts.django);
+        transfer_in(0);
+        let unfunded = contract
+            .open_escrow(property_ids[1], 500, Some(100))
+            .expect("Failed to open escrow");
+        set_caller(accounts.charlie);
+        transfer_in(10);
+        let funded = contract
+            .open_escrow(property_ids[2], 500, Some(100))
+            .expect("Failed to open escrow");
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+
+        // The buyer cannot sweep their own escrow for a bounty
+        set_caller(accounts.django);
+        let django_balance = balance_of(accounts.django);
+        assert_eq!(contract.sweep_expired_escrows(10), Ok(1));
+        assert_eq!(
+            contract.get_escrow(unfunded).unwrap().state,
+            EscrowState::Created
+        );
+        assert_eq!(balance_of(accounts.django), django_balance + 10);
+        assert_eq!(contract.treasury_balance(), 90);
+
+        // Sweeping an unfunded escrow pays nothing
+        set_caller(accounts.frank);
+        let frank_balance = balance_of(accounts.frank);
+        assert_eq!(contract.sweep_expired_escrows(10), Ok(1));
+        assert_eq!(
+            contract.get_escrow(unfunded).unwrap().state,
+            EscrowState::Expired
+        );
+        assert_eq!(balance_of(accounts.frank), frank_balance);
+        assert_eq!(contract.treasury_balance(), 90);
+        assert_eq!(
+            contract.get_escrow(funded).unwrap().state,
+            EscrowState::Expired
+        );
+    }
+
+    #[ink::test]
+    fn test_escrow_minimum_relative_to_valuation() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // 5% of the 1_000_000 valuation
+        assert_eq!(
+            contract.set_escrow_min_valuation_bps(10_001),
+            Err(Error::InvalidBasisPoints)
+        );
+        assert!(contract.set_escrow_min_valuation_bps(500).is_ok());
+        assert_eq!(contract.get_escrow_min_valuation_bps(), 500);
+
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 49_999, None),
+            Err(Error::EscrowAmountBelowMinimum)
+        );
+        assert!(contract
+            .create_escrow(property_id, accounts.bob, 50_000, None)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.open_escrow(property_id, 1, None),
+            Err(Error::EscrowAmountBelowMinimum)
+        );
+        assert_eq!(
+            contract.set_escrow_min_valuation_bps(0),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_defaulting_buyer_is_barred() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract.set_buyer_default_policy(2, 500).is_ok());
+        assert_eq!(contract.get_buyer_default_policy(), (2, 500));
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // Two funded escrows left to expire bar the buyer automatically
+        for _ in 0..2 {
+            let escrow_id = contract
+                .create_escrow(property_id, accounts.bob, 100, Some(100))
+                .expect("Failed to create escrow");
+            set_caller(accounts.bob);
+            transfer_in(100);
+            assert!(contract.fund_escrow(escrow_id).is_ok());
+            set_caller(accounts.alice);
+        }
+        assert_eq!(contract.get_buyer_defaults(accounts.bob), 0);
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+        set_caller(accounts.bob);
+        assert!(contract.claim_expired_escrow(1).is_ok());
+        assert_eq!(contract.get_buyer_defaults(accounts.bob), 1);
+        assert!(contract.claim_expired_escrow(2).is_ok());
+        assert_eq!(contract.get_buyer_defaults(accounts.bob), 0);
+        assert_eq!(contract.get_buyer_barred_until(accounts.bob), Some(600));
+
+        assert_eq!(
+            contract.open_escrow(property_id, 100, None),
+            Err(Error::BuyerBarred)
+        );
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 100, None),
+            Err(Error::BuyerBarred)
+        );
+
+        // The cooldown ends on its own, and the admin can bar or lift bars manually
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(600);
+        assert_eq!(contract.get_buyer_barred_until(accounts.bob), None);
+        assert!(contract
+            .create_escrow(property_id, accounts.bob, 100, None)
+            .is_ok());
+        assert!(contract.bar_buyer(accounts.charlie, 1_000).is_ok());
+        assert_eq!(
+            contract.get_buyer_barred_until(accounts.charlie),
+            Some(1_000)
+        );
+        assert!(contract.bar_buyer(accounts.charlie, 0).is_ok());
+        assert_eq!(contract.get_buyer_barred_until(accounts.charlie), None);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.bar_buyer(accounts.charlie, 1_000),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_escrow_terms_must_be_acknowledged_by_both_parties() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 100, None)
+            .expect("Failed to create escrow");
+        let terms: ink::primitives::Hash = [7u8; 32].into();
+
+        assert!(contract.attach_escrow_terms(escrow_id, terms).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.terms_hash, Some(terms));
+        assert!(escrow.seller_acknowledged_terms);
+        assert!(!escrow.buyer_acknowledged_terms);
+
+        set_caller(accounts.bob);
+        transfer_in(100);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowTermsNotAcknowledged)
+        );
+        assert_eq!(
+            contract.acknowledge_escrow_terms(escrow_id, [8u8; 32].into()),
+            Err(Error::EscrowTermsMismatch)
+        );
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.acknowledge_escrow_terms(escrow_id, terms),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract.acknowledge_escrow_terms(escrow_id, terms).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(contract.get_sale_record(1).unwrap().terms_hash, Some(terms));
+    }
+
+    #[ink::test]
+    fn test_escrow_terms_can_change_until_release_is_approved() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert!(contract.set_escrow_cooling_off_period(100).is_ok());
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 100, None)
+            .expect("Failed to create escrow");
+        let terms: ink::primitives::Hash = [7u8; 32].into();
+        let amended: ink::primitives::Hash = [8u8; 32].into();
+
+        // Terms can be attached after funding
+        set_caller(accounts.bob);
+        transfer_in(100);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.attach_escrow_terms(escrow_id, terms).is_ok());
+        set_caller(accounts.alice);
+        assert!(contract.acknowledge_escrow_terms(escrow_id, terms).is_ok());
+
+        // Re-attaching the same hash keeps both acknowledgements
+        assert!(contract.attach_escrow_terms(escrow_id, terms).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert!(escrow.buyer_acknowledged_terms && escrow.seller_acknowledged_terms);
+
+        // A new hash clears the counterparty's acknowledgement
+        set_caller(accounts.bob);
+        assert!(contract.attach_escrow_terms(escrow_id, amended).is_ok());
+        let escrow = contract.get_escrow(escrow_id).unwrap();
+        assert_eq!(escrow.terms_hash, Some(amended));
+        assert!(escrow.buyer_acknowledged_terms);
+        assert!(!escrow.seller_acknowledged_terms);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::EscrowTermsNotAcknowledged)
+        );
+
+        // Once the release is approved the terms are locked
+        set_caller(accounts.alice);
+        assert!(contract
+            .acknowledge_escrow_terms(escrow_id, amended)
+            .is_ok());
+        set_caller(accounts.bob);
+        assert_eq!(contract.release_escrow(escrow_id), Ok(()));
+        assert!(contract
+            .get_escrow(escrow_id)
+            .unwrap()
+            .approved_at
+            .is_some());
+        assert_eq!(
+            contract.attach_escrow_terms(escrow_id, terms),
+            Err(Error::EscrowTermsLocked)
+        );
+    }
+
+    #[ink::test]
+    fn test_list_and_buy_property() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.list_property(property_id, 0, None),
+            Err(Error::InvalidListingPrice)
+        );
+        let listing_id = contract
+            .list_property(property_id, 400, None)
+            .expect("Failed to list property");
+        assert_eq!(
+            contract.list_property(property_id, 500, None),
+            Err(Error::ListingAlreadyExists)
+        );
+        assert_eq!(
+            contract.get_property_listing(property_id).unwrap().price,
+            400
+        );
+        assert_eq!(
+            contract.buy_listing(listing_id),
+            Err(Error::CannotBuyOwnProperty)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.list_property(property_id, 400, None),
+            Err(Error::Unauthorized)
+        );
+        transfer_in(300);
+        assert_eq!(
+            contract.buy_listing(listing_id),
+            Err(Error::IncorrectPayment)
+        );
+
+        let seller_balance = balance_of(accounts.alice);
+        transfer_in(400);
+        assert!(contract.buy_listing(listing_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(balance_of(accounts.alice), seller_balance + 400);
+
+        let listing = contract.get_listing(listing_id).unwrap();
+        assert_eq!(listing.status, ListingStatus::Sold);
+        assert_eq!(listing.buyer, Some(accounts.bob));
+        assert_eq!(contract.get_property_listing(property_id), None);
+
+        set_caller(accounts.charlie);
+        transfer_in(400);
+        assert_eq!(
+            contract.buy_listing(listing_id),
+            Err(Error::ListingNotActive)
+        );
+        assert_eq!(contract.buy_listing(99), Err(Error::ListingNotFound));
+    }
+
+    #[ink::test]
+    fn test_transfers_cancel_the_previous_owners_listing() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let stale_listing = contract
+            .list_property(property_id, 1_000, None)
+            .expect("Failed to list property");
+
+        assert!(contract
+            .batch_transfer_properties(vec![property_id], accounts.bob)
+            .is_ok());
+        assert_eq!(
+            contract.get_listing(stale_listing).unwrap().status,
+            ListingStatus::Cancelled
+        );
+        assert_eq!(contract.get_property_listing(property_id), None);
+        assert_eq!(contract.active_listing_count(), 0);
+
+        // The new owner is free to sell, and the title can return to the old seller
+        set_caller(accounts.bob);
+        let bob_listing = contract
+            .list_property(property_id, 2_000, None)
+            .expect("Failed to list property");
+        assert!(contract
+            .batch_transfer_properties_to_multiple(vec![(property_id, accounts.alice)])
+            .is_ok());
+        assert_eq!(
+            contract.get_listing(bob_listing).unwrap().status,
+            ListingStatus::Cancelled
+        );
+
+        set_caller(accounts.charlie);
+        transfer_in(1_000);
+        assert_eq!(
+            contract.buy_listing(stale_listing),
+            Err(Error::ListingNotActive)
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    #[ink::test]
+    fn test_update_listing_price_and_delist() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let listing_id = contract
+            .list_property(property_id, 400, None)
+            .expect("Failed to list property");
+
+        assert_eq!(
+            contract.update_listing_price(listing_id, 0),
+            Err(Error::InvalidListingPrice)
+        );
+        assert!(contract.update_listing_price(listing_id, 250).is_ok());
+        assert_eq!(contract.get_listing(listing_id).unwrap().price, 250);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.update_listing_price(listing_id, 1),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(
+            contract.delist_property(listing_id),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.delist_property(listing_id).is_ok());
+        assert_eq!(
+            contract.get_listing(listing_id).unwrap().status,
+            ListingStatus::Cancelled
+        );
+        assert_eq!(contract.get_property_listing(property_id), None);
+        assert_eq!(
+            contract.delist_property(listing_id),
+            Err(Error::ListingNotActive)
+        );
+        assert_eq!(
+            contract.update_listing_price(listing_id, 300),
+            Err(Error::ListingNotActive)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(250);
+        assert_eq!(
+            contract.buy_listing(listing_id),
+            Err(Error::ListingNotActive)
+        );
+
+        // The property can be listed again once delisted
+        set_caller(accounts.alice);
+        assert!(contract.list_property(property_id, 300, None).is_ok());
+    }
+
+    #[ink::test]
+    fn test_english_auction_flow() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.start_auction(property_id, 100, 0),
+            Err(Error::InvalidAuctionDuration)
+        );
+        let auction_id = contract
+            .start_auction(property_id, 100, 1_000)
+            .expect("Failed to start auction");
+        assert_eq!(
+            contract.list_property(property_id, 500, None),
+            Err(Error::AuctionAlreadyExists)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(50);
+        assert_eq!(contract.bid(auction_id), Err(Error::BidTooLow));
+        let bob_balance = balance_of(accounts.bob);
+        transfer_in(150);
+        assert!(contract.bid(auction_id).is_ok());
+
+        // Outbidding makes the previous highest bid withdrawable
+        set_caller(accounts.charlie);
+        transfer_in(150);
+        assert_eq!(contract.bid(auction_id), Err(Error::BidTooLow));
+        transfer_in(200);
+        assert!(contract.bid(auction_id).is_ok());
+        assert_eq!(contract.get_withdrawable_bid(auction_id, accounts.bob), 150);
+        set_caller(accounts.bob);
+        assert_eq!(contract.withdraw_bid(auction_id), Ok(150));
+        assert_eq!(contract.withdraw_bid(auction_id), Err(Error::BidNotFound));
+        assert_eq!(balance_of(accounts.bob), bob_balance);
+        set_caller(accounts.charlie);
+        let auction = contract.get_auction(auction_id).unwrap();
+        assert_eq!(auction.highest_bid, 200);
+        assert_eq!(auction.highest_bidder, Some(accounts.charlie));
+
+        assert_eq!(
+            contract.finalize_auction(auction_id),
+            Err(Error::AuctionNotEnded)
+        );
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        transfer_in(300);
+        assert_eq!(contract.bid(auction_id), Err(Error::AuctionEnded));
+
+        let seller_balance = balance_of(accounts.alice);
+        set_caller(accounts.django);
+        assert!(contract.finalize_auction(auction_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.charlie
+        );
+        assert_eq!(balance_of(accounts.alice), seller_balance + 200);
+        assert_eq!(
+            contract.get_auction(auction_id).unwrap().status,
+            AuctionStatus::Finalized
+        );
+        assert_eq!(contract.get_property_auction(property_id), None);
+        assert_eq!(
+            contract.finalize_auction(auction_id),
+            Err(Error::AuctionNotActive)
+        );
+    }
+
+    #[ink::test]
+    fn test_transfer_cancels_running_auctions() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let english = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let sealed = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let auction_id = contract
+            .start_auction(english, 100, 1_000)
+            .expect("Failed to start auction");
+        let sealed_id = contract
+            .start_sealed_auction(sealed, 100, 1_000, 1_000)
+            .expect("Failed to start auction");
+
+        set_caller(accounts.bob);
+        transfer_in(150);
+        assert!(contract.bid(auction_id).is_ok());
+        let salt: ink::primitives::Hash = [1u8; 32].into();
+        let commitment = contract.compute_bid_commitment(sealed_id, accounts.bob, 200, salt);
+        transfer_in(300);
+        assert!(contract.commit_sealed_bid(sealed_id, commitment).is_ok());
+
+        set_caller(accounts.alice);
+        assert!(contract
+            .transfer_property(english, accounts.charlie)
+            .is_ok());
+        assert!(contract.transfer_property(sealed, accounts.charlie).is_ok());
+        assert_eq!(
+            contract.get_auction(auction_id).unwrap().status,
+            AuctionStatus::Cancelled
+        );
+        assert_eq!(
+            contract.get_sealed_auction(sealed_id).unwrap().status,
+            AuctionStatus::Cancelled
+        );
+        assert_eq!(contract.get_property_auction(english), None);
+
+        // Nothing more can happen on the auctions, and the bidder gets everything back
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        assert_eq!(
+            contract.finalize_auction(auction_id),
+            Err(Error::AuctionNotActive)
+        );
+        assert_eq!(
+            contract.finalize_sealed_auction(sealed_id),
+            Err(Error::AuctionNotActive)
+        );
+        set_caller(accounts.bob);
+        let bob_balance = balance_of(accounts.bob);
+        assert_eq!(contract.withdraw_bid(auction_id), Ok(150));
+        assert_eq!(contract.withdraw_sealed_deposit(sealed_id), Ok(300));
+        assert_eq!(balance_of(accounts.bob), bob_balance + 450);
+        assert_eq!(
+            contract.get_property(english).unwrap().owner,
+            accounts.charlie
+        );
+    }
+
+    #[ink::test]
+    fn test_dutch_auction_price_decays_until_bought() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.start_dutch_auction(property_id, 100, 200, 1_000),
+            Err(Error::InvalidAuctionPrice)
+        );
+        let auction_id = contract
+            .start_dutch_auction(property_id, 600, 200, 1_000)
+            .expect("Failed to start auction");
+        assert_eq!(
+            contract.start_auction(property_id, 100, 1_000),
+            Err(Error::AuctionAlreadyExists)
+        );
+        assert_eq!(contract.get_dutch_auction_price(auction_id), Some(600));
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(500);
+        assert_eq!(contract.get_dutch_auction_price(auction_id), Some(400));
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        assert_eq!(contract.get_dutch_auction_price(auction_id), Some(200));
+
+        // The first buyer at the current price wins and gets any surplus back
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(750);
+        set_caller(accounts.bob);
+        transfer_in(200);
+        assert_eq!(
+            contract.buy_dutch_auction(auction_id),
+            Err(Error::IncorrectPayment)
+        );
+        let bob_balance = balance_of(accounts.bob);
+        let seller_balance = balance_of(accounts.alice);
+        transfer_in(400);
+        assert!(contract.buy_dutch_auction(auction_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), bob_balance - 300);
+        assert_eq!(balance_of(accounts.alice), seller_balance + 300);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+
+        let auction = contract.get_dutch_auction(auction_id).unwrap();
+        assert_eq!(auction.status, AuctionStatus::Finalized);
+        assert_eq!(auction.sold_price, 300);
+        assert_eq!(contract.get_dutch_auction_price(auction_id), None);
+
+        // Unsold auctions can be cancelled by the seller
+        let second = contract
+            .start_dutch_auction(property_id, 600, 200, 1_000)
+            .expect("Failed to start auction");
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.cancel_dutch_auction(second),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.bob);
+        assert!(contract.cancel_dutch_auction(second).is_ok());
+        assert!(contract.list_property(property_id, 500, None).is_ok());
+    }
+
+    #[ink::test]
+    fn test_sealed_auction_commit_reveal_and_withdraw() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let auction_id = contract
+            .start_sealed_auction(property_id, 100, 1_000, 1_000)
+            .expect("Failed to start auction");
+        assert_eq!(
+            contract.list_property(property_id, 500, None),
+            Err(Error::AuctionAlreadyExists)
+        );
+
+        let bob_salt: ink::primitives::Hash = [1u8; 32].into();
+        let charlie_salt: ink::primitives::Hash = [2u8; 32].into();
+        set_caller(accounts.bob);
+        transfer_in(500);
+        let bob_commitment =
+            contract.compute_bid_commitment(auction_id, accounts.bob, 300, bob_salt);
+        assert!(contract
+            .commit_sealed_bid(auction_id, bob_commitment)
+            .is_ok());
+        assert_eq!(
+            contract.commit_sealed_bid(auction_id, bob_commitment),
+            Err(Error::BidAlreadyCommitted)
+        );
+        set_caller(accounts.charlie);
+        transfer_in(400);
+        let charlie_commitment =
+            contract.compute_bid_commitment(auction_id, accounts.charlie, 250, charlie_salt);
+        assert!(contract
+            .commit_sealed_bid(auction_id, charlie_commitment)
+            .is_ok());
+
+        // Reveals only open once commitments close, and must match the commitment
+        assert_eq!(
+            contract.reveal_sealed_bid(auction_id, 250, charlie_salt),
+            Err(Error::RevealNotOpen)
+        );
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_500);
+        transfer_in(0);
+        assert_eq!(
+            contract.commit_sealed_bid(auction_id, charlie_commitment),
+            Err(Error::AuctionEnded)
+        );
+        assert_eq!(
+            contract.reveal_sealed_bid(auction_id, 300, charlie_salt),
+            Err(Error::InvalidBidReveal)
+        );
+        assert!(contract
+            .reveal_sealed_bid(auction_id, 250, charlie_salt)
+            .is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .reveal_sealed_bid(auction_id, 300, bob_salt)
+            .is_ok());
+        assert_eq!(
+            contract.finalize_sealed_auction(auction_id),
+            Err(Error::AuctionNotEnded)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        let seller_balance = balance_of(accounts.alice);
+        assert!(contract.finalize_sealed_auction(auction_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 300);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        let auction = contract.get_sealed_auction(auction_id).unwrap();
+        assert_eq!(auction.status, AuctionStatus::Finalized);
+        assert_eq!(auction.highest_bidder, Some(accounts.bob));
+
+        // The winner withdraws the excess deposit, the loser the whole deposit
+        let bob_balance = balance_of(accounts.bob);
+        assert_eq!(contract.withdraw_sealed_deposit(auction_id), Ok(200));
+        assert_eq!(balance_of(accounts.bob), bob_balance + 200);
+        set_caller(accounts.charlie);
+        assert_eq!(contract.withdraw_sealed_deposit(auction_id), Ok(400));
+        assert_eq!(
+            contract.withdraw_sealed_deposit(auction_id),
+            Err(Error::BidNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn test_sealed_bid_commitment_cannot_be_copied() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let auction_id = contract
+            .start_sealed_auction(property_id, 100, 1_000, 1_000)
+            .expect("Failed to start auction");
+        let salt: ink::primitives::Hash = [1u8; 32].into();
+        let commitment = contract.compute_bid_commitment(auction_id, accounts.bob, 300, salt);
+        assert_ne!(
+            commitment,
+            contract.compute_bid_commitment(auction_id, accounts.charlie, 300, salt)
+        );
+        assert_ne!(
+            commitment,
+            contract.compute_bid_commitment(auction_id + 1, accounts.bob, 300, salt)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.commit_sealed_bid(auction_id, commitment).is_ok());
+        // Charlie copies Bob's commitment from the chain
+        set_caller(accounts.charlie);
+        transfer_in(500);
+        assert!(contract.commit_sealed_bid(auction_id, commitment).is_ok());
+
+        // Once Bob reveals, Charlie cannot reuse the revealed amount and salt
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_500);
+        transfer_in(0);
+        set_caller(accounts.bob);
+        assert!(contract.reveal_sealed_bid(auction_id, 300, salt).is_ok());
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.reveal_sealed_bid(auction_id, 300, salt),
+            Err(Error::InvalidBidReveal)
+        );
+    }
+
+    #[ink::test]
+    fn test_property_offers_accept_reject_and_withdraw() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        transfer_in(100);
+        assert_eq!(
+            contract.make_offer(property_id, 100, None),
+            Err(Error::CannotBuyOwnProperty)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(300);
+        assert_eq!(
+            contract.make_offer(property_id, 200, None),
+            Err(Error::IncorrectPayment)
+        );
+        let bob_offer = contract
+            .make_offer(property_id, 300, None)
+            .expect("Failed to make offer");
+        set_caller(accounts.charlie);
+        transfer_in(200);
+        let charlie_offer = contract
+            .make_offer(property_id, 200, None)
+            .expect("Failed to make offer");
+        transfer_in(250);
+        let second_charlie_offer = contract
+            .make_offer(property_id, 250, None)
+            .expect("Failed to make offer");
+        assert_eq!(contract.get_property_offers(property_id).len(), 3);
+
+        // Only the owner resolves offers; rejection refunds the deposit
+        assert_eq!(
+            contract.accept_offer(charlie_offer),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        let charlie_balance = balance_of(accounts.charlie);
+        assert!(contract.reject_offer(charlie_offer).is_ok());
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + 200);
+        assert_eq!(
+            contract.get_offer(charlie_offer).unwrap().status,
+            OfferStatus::Rejected
+        );
+        assert_eq!(
+            contract.reject_offer(charlie_offer),
+            Err(Error::OfferNotPending)
+        );
+
+        // Acceptance pays the owner and moves the title in one step
+        let seller_balance = balance_of(accounts.alice);
+        assert!(contract.accept_offer(bob_offer).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 300);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+
+        // Remaining offers stay pending and can be withdrawn by the offeror
+        set_caller(accounts.charlie);
+        assert!(contract.withdraw_offer(second_charlie_offer).is_ok());
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + 200 + 250);
+        assert!(contract.get_property_offers(property_id).is_empty());
+    }
+
+    #[ink::test]
+    fn test_counter_offer_accepted_with_top_up() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        transfer_in(300);
+        let offer_id = contract
+            .make_offer(property_id, 300, None)
+            .expect("Failed to make offer");
+        assert_eq!(
+            contract.accept_counter_offer(offer_id),
+            Err(Error::CounterOfferNotFound)
+        );
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.counter_offer(offer_id, 300),
+            Err(Error::InvalidCounterOffer)
+        );
+        assert!(contract.counter_offer(offer_id, 500).is_ok());
+        assert!(contract.counter_offer(offer_id, 450).is_ok());
+        assert_eq!(
+            contract.get_offer(offer_id).unwrap().counter.unwrap().price,
+            450
+        );
+
+        // The offeror tops the deposit up to the latest counter price
+        set_caller(accounts.bob);
+        transfer_in(200);
+        assert_eq!(
+            contract.accept_counter_offer(offer_id),
+            Err(Error::IncorrectPayment)
+        );
+        let seller_balance = balance_of(accounts.alice);
+        transfer_in(150);
+        assert!(contract.accept_counter_offer(offer_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 450);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        let offer = contract.get_offer(offer_id).unwrap();
+        assert_eq!(offer.status, OfferStatus::Accepted);
+        assert_eq!(offer.amount, 450);
+    }
+
+    #[ink::test]
+    fn test_expired_listings_and_offers_are_pruned() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        assert_eq!(
+            contract.list_property(property_id, 500, Some(1_000)),
+            Err(Error::InvalidExpiry)
+        );
+        let listing_id = contract
+            .list_property(property_id, 500, Some(2_000))
+            .expect("Failed to list property");
+
+        set_caller(accounts.bob);
+        transfer_in(300);
+        let offer_id = contract
+            .make_offer(property_id, 300, Some(2_000))
+            .expect("Failed to make offer");
+        transfer_in(200);
+        let open_offer = contract
+            .make_offer(property_id, 200, None)
+            .expect("Failed to make offer");
+
+        // Nothing is pruned before expiry
+        set_caller(accounts.charlie);
+        assert_eq!(contract.prune_expired_sales(property_id), Ok(0));
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        transfer_in(500);
+        assert_eq!(contract.buy_listing(listing_id), Err(Error::ListingExpired));
+        set_caller(accounts.alice);
+        assert_eq!(contract.accept_offer(offer_id), Err(Error::OfferExpired));
+
+        // Anyone can prune; the lapsed offer's deposit goes back to the offeror
+        set_caller(accounts.charlie);
+        let bob_balance = balance_of(accounts.bob);
+        assert_eq!(contract.prune_expired_sales(property_id), Ok(2));
+        assert_eq!(balance_of(accounts.bob), bob_balance + 300);
+        assert_eq!(
+            contract.get_listing(listing_id).unwrap().status,
+            ListingStatus::Expired
+        );
+        assert_eq!(
+            contract.get_offer(offer_id).unwrap().status,
+            OfferStatus::Expired
+        );
+        assert_eq!(
+            contract.get_offer(open_offer).unwrap().status,
+            OfferStatus::Pending
+        );
+        assert_eq!(contract.prune_expired_sales(property_id), Ok(0));
+
+        set_caller(accounts.alice);
+        assert!(contract.list_property(property_id, 500, None).is_ok());
+    }
+
+    #[ink::test]
+    fn test_private_listing_only_sells_to_named_buyer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.list_property_privately(property_id, 400, accounts.alice, None),
+            Err(Error::CannotBuyOwnProperty)
+        );
+        let listing_id = contract
+            .list_property_privately(property_id, 400, accounts.bob, None)
+            .expect("Failed to list property");
+        assert_eq!(
+            contract.get_listing(listing_id).unwrap().private_buyer,
+            Some(accounts.bob)
+        );
+
+        set_caller(accounts.charlie);
+        transfer_in(400);
+        assert_eq!(contract.buy_listing(listing_id), Err(Error::Unauthorized));
+
+        set_caller(accounts.bob);
+        transfer_in(400);
+        assert!(contract.buy_listing(listing_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(
+            contract.get_listing(listing_id).unwrap().status,
+            ListingStatus::Sold
+        );
+    }
+
+    #[ink::test]
+    fn test_bundle_listing_transfers_all_titles() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.list_bundle(vec![first], 500),
+            Err(Error::InvalidBundle)
+        );
+        assert_eq!(
+            contract.list_bundle(vec![first, first], 500),
+            Err(Error::InvalidBundle)
+        );
+        let bundle_id = contract
+            .list_bundle(vec![first, second], 500)
+            .expect("Failed to list bundle");
+        assert_eq!(
+            contract.list_property(first, 300, None),
+            Err(Error::ListingAlreadyExists)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(400);
+        assert_eq!(contract.buy_bundle(bundle_id), Err(Error::IncorrectPayment));
+        let seller_balance = balance_of(accounts.alice);
+        transfer_in(500);
+        assert!(contract.buy_bundle(bundle_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 500);
+        assert_eq!(contract.get_property(first).unwrap().owner, accounts.bob);
+        assert_eq!(contract.get_property(second).unwrap().owner, accounts.bob);
+        assert_eq!(
+            contract.get_bundle(bundle_id).unwrap().status,
+            ListingStatus::Sold
+        );
+
+        // The new owner can list the properties again
+        assert!(contract.list_property(first, 300, None).is_ok());
+    }
+
+    #[ink::test]
+    fn test_bundle_purchase_fails_if_any_title_moved() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let bundle_id = contract
+            .list_bundle(vec![first, second], 500)
+            .expect("Failed to list bundle");
+        assert!(contract.transfer_property(second, accounts.charlie).is_ok());
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert_eq!(contract.buy_bundle(bundle_id), Err(Error::ListingNotActive));
+        assert_eq!(contract.get_property(first).unwrap().owner, accounts.alice);
+
+        set_caller(accounts.alice);
+        assert!(contract.cancel_bundle(bundle_id).is_ok());
+        assert!(contract.list_property(first, 300, None).is_ok());
+    }
+
+    #[ink::test]
+    fn test_royalty_is_split_from_resales() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        assert_eq!(
+            contract.register_property_with_royalty(create_sample_metadata(), None, 1_001),
+            Err(Error::InvalidBasisPoints)
+        );
+        let property_id = contract
+            .register_property_with_royalty(create_sample_metadata(), Some(accounts.django), 500)
+            .expect("Failed to register property");
+        assert_eq!(contract.get_property_royalty(property_id).unwrap().bps, 500);
+
+        // A listing sale pays 5% to the royalty receiver
+        let listing_id = contract
+            .list_property(property_id, 400, None)
+            .expect("Failed to list property");
+        set_caller(accounts.bob);
+        transfer_in(400);
+        let seller_balance = balance_of(accounts.alice);
+        assert!(contract.buy_listing(listing_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 380);
+        assert_eq!(balance_of(accounts.django), 20);
+
+        // Escrow settlements pay the royalty too
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.charlie, 200, None)
+            .expect("Failed to create escrow");
+        contract
+            .approve(property_id, Some(accounts.charlie))
+            .expect("Failed to approve buyer");
+        set_caller(accounts.charlie);
+        transfer_in(200);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(balance_of(accounts.django), 30);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.charlie
+        );
+    }
+
+    #[ink::test]
+    fn test_marketplace_fees_accrue_to_treasury() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert!(contract
+            .set_marketplace_fee_bps(SaleType::Listing, 250)
+            .is_ok());
+        assert!(contract
+            .set_marketplace_fee_bps(SaleType::Escrow, 100)
+            .is_ok());
+        assert_eq!(contract.get_escrow_fee_bps(), 100);
+        assert_eq!(contract.get_marketplace_fee_bps(SaleType::Auction), 0);
+        assert_eq!(
+            contract.set_marketplace_fee_bps(SaleType::Offer, 10_001),
+            Err(Error::InvalidEscrowFee)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_marketplace_fee_bps(SaleType::Offer, 100),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        let listing_id = contract
+            .list_property(property_id, 400, None)
+            .expect("Failed to list property");
+        set_caller(accounts.bob);
+        transfer_in(400);
+        let seller_balance = balance_of(accounts.alice);
+        assert!(contract.buy_listing(listing_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 390);
+        assert_eq!(contract.treasury_balance(), 10);
+
+        // Accrued fees are withdrawn through the existing treasury flow
+        set_caller(accounts.alice);
+        let treasury = contract.get_treasury();
+        let treasury_balance = balance_of(treasury);
+        assert_eq!(contract.withdraw_fees(), Ok(10));
+        assert_eq!(balance_of(treasury), treasury_balance + 10);
+    }
+
+    #[ink::test]
+    fn test_price_history_records_marketplace_sales() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+        let listing_id = contract
+            .list_property(property_id, 400, None)
+            .expect("Failed to list property");
+        set_caller(accounts.bob);
+        transfer_in(400);
+        assert!(contract.buy_listing(listing_id).is_ok());
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(20);
+        set_caller(accounts.charlie);
+        transfer_in(450);
+        let offer_id = contract
+            .make_offer(property_id, 450, None)
+            .expect("Failed to make offer");
+        set_caller(accounts.bob);
+        assert!(contract.accept_offer(offer_id).is_ok());
+
+        assert_eq!(
+            contract.get_price_history(property_id),
+            vec![(10, 400), (20, 450)]
+        );
+        let sales = contract.get_property_sales(property_id, 0, 10);
+        assert_eq!(sales[0].sale_type, SaleType::Listing);
+        assert_eq!(sales[0].source_id, listing_id);
+        assert_eq!(sales[0].seller, accounts.alice);
+        assert_eq!(sales[1].sale_type, SaleType::Offer);
+        assert_eq!(sales[1].buyer, accounts.charlie);
+        assert_eq!(sales[1].seller, accounts.bob);
+    }
+
+    #[ink::test]
+    fn test_marketplace_statistics() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert_eq!(contract.average_sale_price(10), 0);
+
+        let first_listing = contract
+            .list_property(first, 400, None)
+            .expect("Failed to list property");
+        let second_listing = contract
+            .list_property(second, 200, None)
+            .expect("Failed to list property");
+        assert_eq!(contract.active_listing_count(), 2);
+
+        set_caller(accounts.bob);
+        transfer_in(400);
+        assert!(contract.buy_listing(first_listing).is_ok());
+        assert_eq!(contract.active_listing_count(), 1);
+        transfer_in(200);
+        assert!(contract.buy_listing(second_listing).is_ok());
+        assert_eq!(contract.active_listing_count(), 0);
+
+        assert_eq!(contract.total_sales_volume(), 600);
+        assert_eq!(contract.get_seller_sales_count(accounts.alice), 2);
+        assert_eq!(contract.get_seller_sales_count(accounts.bob), 0);
+        assert_eq!(contract.average_sale_price(10), 300);
+        assert_eq!(contract.average_sale_price(1), 200);
+
+        // Delisting and transfers keep the active count in step
+        assert!(contract.list_property(first, 500, None).is_ok());
+        let second_relisting = contract
+            .list_property(second, 500, None)
+            .expect("Failed to list property");
+        assert_eq!(contract.active_listing_count(), 2);
+        assert!(contract.transfer_property(first, accounts.charlie).is_ok());
+        assert!(contract.delist_property(second_relisting).is_ok());
+        assert_eq!(contract.active_listing_count(), 0);
+    }
+
+    #[ink::test]
+    fn test_financed_purchase_records_lien_until_repaid() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let listing_id = contract
+            .list_property(property_id, 300, None)
+            .expect("Failed to list property");
+
+        set_caller(accounts.bob);
+        transfer_in(10);
+        assert_eq!(
+            contract.buy_listing_financed(listing_id),
+            Err(Error::FinancingNotAvailable)
+        );
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.set_listing_financing(listing_id, Some(300)),
+            Err(Error::InvalidListingPrice)
+        );
+        assert!(contract
+            .set_listing_financing(listing_id, Some(100))
+            .is_ok());
+
+        set_caller(accounts.bob);
+        transfer_in(50);
+        assert_eq!(
+            contract.buy_listing_financed(listing_id),
+            Err(Error::IncorrectPayment)
+        );
+        let seller_balance = balance_of(accounts.alice);
+        transfer_in(100);
+        assert!(contract.buy_listing_financed(listing_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), seller_balance + 100);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        let lien = contract.get_property_lien(property_id).unwrap();
+        assert_eq!(lien.creditor, accounts.alice);
+        assert_eq!(lien.outstanding, 200);
+
+        // The lien blocks transfers and new sales until it is cleared
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.charlie),
+            Err(Error::PropertyEncumbered)
+        );
+        assert_eq!(
+            contract.list_property(property_id, 800, None),
+            Err(Error::PropertyEncumbered)
+        );
+
+        transfer_in(201);
+        assert_eq!(
+            contract.repay_lien(property_id),
+            Err(Error::IncorrectPayment)
+        );
+        transfer_in(150);
+        assert_eq!(contract.repay_lien(property_id), Ok(50));
+        assert_eq!(balance_of(accounts.alice), seller_balance + 250);
+
+        // The creditor can forgive the rest
+        assert_eq!(contract.release_lien(property_id), Err(Error::Unauthorized));
+        set_caller(accounts.alice);
+        assert!(contract.release_lien(property_id).is_ok());
+        assert_eq!(contract.get_property_lien(property_id), None);
+
+        set_caller(accounts.bob);
+        assert!(contract
+            .transfer_property(property_id, accounts.charlie)
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_only_whitelisted_operator_contracts_can_transfer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // Django plays an external marketplace contract
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            contract.approve(property_id, Some(accounts.django)),
+            Err(Error::OperatorNotApproved)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_marketplace_operator(accounts.django, true),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_marketplace_operator(accounts.django, true)
+            .is_ok());
+        assert!(contract.is_marketplace_operator(accounts.django));
+        assert!(contract.approve(property_id, Some(accounts.django)).is_ok());
+
+        // Delisting the operator stops transfers under existing approvals
+        assert!(contract
+            .set_marketplace_operator(accounts.django, false)
+            .is_ok());
+        set_caller(accounts.django);
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.bob),
+            Err(Error::OperatorNotApproved)
+        );
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_marketplace_operator(accounts.django, true)
+            .is_ok());
+        set_caller(accounts.django);
+        assert!(contract
+            .transfer_property(property_id, accounts.bob)
+            .is_ok());
+
+        // Ordinary accounts can still be approved without the whitelist
+        set_caller(accounts.bob);
+        assert!(contract
+            .approve(property_id, Some(accounts.charlie))
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_sale_types_can_require_verified_properties() {
+        use crate::propchain_contracts::BadgeType;
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_sale_verification_requirement(
+                SaleType::Listing,
+                Some(BadgeType::DocumentVerification)
+            ),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_sale_verification_requirement(
+                SaleType::Listing,
+                Some(BadgeType::DocumentVerification)
+            )
+            .is_ok());
+        assert_eq!(
+            contract.list_property(property_id, 500, None),
+            Err(Error::PropertyNotVerified)
+        );
+        // Other sale types are unaffected
+        assert!(contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .is_ok());
+
+        // An expired badge does not count as verified
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::DocumentVerification,
+                Some(1_000),
+                "ipfs://badge".to_string()
+            )
+            .is_ok());
+        assert_eq!(
+            contract.list_property(property_id, 500, None),
+            Err(Error::PropertyNotVerified)
+        );
+        assert!(contract
+            .set_sale_verification_requirement(
+                SaleType::Listing,
+                Some(BadgeType::OwnerVerification)
+            )
+            .is_ok());
+        assert!(contract
+            .issue_badge(
+                property_id,
+                BadgeType::OwnerVerification,
+                None,
+                "ipfs://badge".to_string()
+            )
+            .is_ok());
+        assert!(contract.list_property(property_id, 500, None).is_ok());
+    }
+
+    #[ink::test]
+    fn test_auction_bid_increment_and_anti_sniping_extension() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let auction_id = contract
+            .start_auction(property_id, 100, 1_000)
+            .expect("Failed to start auction");
+        assert!(contract
+            .set_auction_bidding_rules(auction_id, 50, 200)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        transfer_in(100);
+        assert!(contract.bid(auction_id).is_ok());
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.set_auction_bidding_rules(auction_id, 0, 0),
+            Err(Error::AuctionHasBids)
+        );
+
+        // Later bids must clear the increment
+        set_caller(accounts.charlie);
+        transfer_in(149);
+        assert_eq!(contract.bid(auction_id), Err(Error::BidTooLow));
+
+        // A bid in the final window pushes the end back
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(900);
+        transfer_in(150);
+        assert!(contract.bid(auction_id).is_ok());
+        assert_eq!(contract.get_auction(auction_id).unwrap().ends_at, 1_100);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        assert_eq!(
+            contract.finalize_auction(auction_id),
+            Err(Error::AuctionNotEnded)
+        );
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_100);
+        assert!(contract.finalize_auction(auction_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.charlie
+        );
+    }
+
+    #[ink::test]
+    fn test_listing_asset_must_be_accepted_and_paid_by_allowance() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let listing_id = contract
+            .list_property(property_id, 500, None)
+            .expect("Failed to list property");
+        let token = EscrowAsset::Psp22(accounts.frank);
+
+        assert_eq!(
+            contract.set_listing_asset(listing_id, token),
+            Err(Error::UnsupportedEscrowAsset)
+        );
+        assert!(contract.set_escrow_asset_accepted(token, true).is_ok());
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_listing_asset(listing_id, token),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.set_listing_asset(listing_id, token).is_ok());
+        assert_eq!(contract.get_listing(listing_id).unwrap().asset, token);
+
+        // Token-priced listings cannot be paid with native value or financed
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert_eq!(
+            contract.buy_listing(listing_id),
+            Err(Error::IncorrectPayment)
+        );
+        assert_eq!(
+            contract.buy_listing_financed(listing_id),
+            Err(Error::UnsupportedEscrowAsset)
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    #[ink::test]
+    fn test_auction_below_reserve_fails_and_refunds_bidder() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.start_auction_with_reserve(property_id, 500, 400, 1_000),
+            Err(Error::InvalidAuctionPrice)
+        );
+        let auction_id = contract
+            .start_auction_with_reserve(property_id, 100, 400, 1_000)
+            .expect("Failed to start auction");
+
+        set_caller(accounts.bob);
+        let bob_balance = balance_of(accounts.bob);
+        transfer_in(200);
+        assert!(contract.bid(auction_id).is_ok());
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        assert!(contract.finalize_auction(auction_id).is_ok());
+        assert_eq!(
+            contract.get_auction(auction_id).unwrap().status,
+            AuctionStatus::ReserveNotMet
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+        assert_eq!(contract.get_property_auction(property_id), None);
+
+        assert_eq!(contract.withdraw_bid(auction_id), Ok(200));
+        assert_eq!(balance_of(accounts.bob), bob_balance);
+    }
+
+    #[ink::test]
+    fn test_fractional_shares_transfer_and_block_whole_title() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.enable_fractional(property_id, 1_000),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert_eq!(
+            contract.get_share_balance(property_id, accounts.alice),
+            1_000
+        );
+        assert_eq!(
+            contract.enable_fractional(property_id, 10),
+            Err(Error::PropertyFractionalized)
+        );
+
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 400)
+            .is_ok());
+        assert_eq!(contract.get_share_balance(property_id, accounts.alice), 600);
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 400);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.charlie, 401),
+            Err(Error::InsufficientShares)
+        );
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.bob),
+            Err(Error::PropertyFractionalized)
+        );
+        assert_eq!(
+            contract.list_property(property_id, 500, None),
+            Err(Error::PropertyFractionalized)
+        );
+    }
+
+    #[ink::test]
+    fn test_share_token_deploy_requires_code_hash() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        assert_eq!(
+            contract.deploy_share_token(property_id),
+            Err(Error::PropertyNotFound)
+        );
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert_eq!(
+            contract.deploy_share_token(property_id),
+            Err(Error::ShareTokenCodeHashNotSet)
+        );
+        assert_eq!(
+            contract.wrap_shares(property_id, 100),
+            Err(Error::ShareTokenNotDeployed)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_share_token_code_hash(Some(ink::primitives::Hash::from([0x01; 32]))),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(
+            contract.deploy_share_token(property_id),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(contract.get_share_token(property_id), None);
+    }
+
+    #[ink::test]
+    fn test_share_compliance_exemption_is_admin_only() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert_eq!(contract.get_share_compliance_exemption(), None);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_share_compliance_exemption(Some(10)),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.set_share_compliance_exemption(Some(10)).is_ok());
+        assert_eq!(contract.get_share_compliance_exemption(), Some(10));
+
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 5)
+            .is_ok());
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 5);
+    }
+
+    #[ink::test]
+    fn test_share_token_recipients_pass_the_compliance_check() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+
+        // Without a compliance registry every recipient passes, as for in-registry transfers
+        assert!(contract.can_receive_shares(property_id, accounts.bob, 500));
+
+        // A sanctioned recipient is refused on both paths
+        assert!(contract.add_to_sanctions_list(accounts.bob).is_ok());
+        assert!(!contract.can_receive_shares(property_id, accounts.bob, 500));
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.bob, 500),
+            Err(Error::AccountSanctioned)
+        );
+
+        // Transfers below the exemption threshold skip KYC, but not the sanctions list
+        assert!(contract.set_share_compliance_exemption(Some(100)).is_ok());
+        assert!(contract.can_receive_shares(property_id, accounts.charlie, 99));
+        assert!(!contract.can_receive_shares(property_id, accounts.bob, 99));
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.bob, 99),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 0);
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 99)
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_income_distribution_is_pro_rata_and_pull_based() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        transfer_in(100);
+        assert_eq!(
+            contract.distribute_income(property_id),
+            Err(Error::PropertyNotFound)
+        );
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 250)
+            .is_ok());
+
+        set_caller(accounts.charlie);
+        transfer_in(1_000);
+        assert!(contract.distribute_income(property_id).is_ok());
+        assert_eq!(
+            contract.get_claimable_income(property_id, accounts.alice),
+            750
+        );
+        assert_eq!(
+            contract.get_claimable_income(property_id, accounts.bob),
+            250
+        );
+
+        // Shares moved after a distribution do not carry the earlier income with them
+        set_caller(accounts.bob);
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 250)
+            .is_ok());
+        assert_eq!(
+            contract.get_claimable_income(property_id, accounts.bob),
+            250
+        );
+        assert_eq!(
+            contract.get_claimable_income(property_id, accounts.charlie),
+            0
+        );
+
+        let bob_balance = balance_of(accounts.bob);
+        assert_eq!(contract.claim_income(property_id), Ok(250));
+        assert_eq!(balance_of(accounts.bob), bob_balance + 250);
+        assert_eq!(
+            contract.claim_income(property_id),
+            Err(Error::NoIncomeToClaim)
+        );
+    }
+
+    #[ink::test]
+    fn test_majority_buyout_consolidates_title_and_pays_minority() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 100).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 95)
+            .is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 5)
+            .is_ok());
+
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.start_buyout(property_id, 10),
+            Err(Error::BelowBuyoutThreshold)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(40);
+        assert_eq!(
+            contract.start_buyout(property_id, 10),
+            Err(Error::IncorrectPayment)
+        );
+        transfer_in(50);
+        assert!(contract.start_buyout(property_id, 10).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert!(!contract.is_fractional(property_id));
+        assert_eq!(
+            contract.get_buyout(property_id).unwrap().outstanding_shares,
+            5
+        );
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.alice, 1),
+            Err(Error::PropertyNotFound)
+        );
+
+        set_caller(accounts.charlie);
+        let charlie_balance = balance_of(accounts.charlie);
+        assert_eq!(contract.claim_buyout(property_id), Ok(50));
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + 50);
+        assert_eq!(contract.get_share_balance(property_id, accounts.charlie), 0);
+        assert_eq!(contract.get_buyout(property_id), None);
+        assert_eq!(
+            contract.claim_buyout(property_id),
+            Err(Error::BuyoutNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn test_defractionalize_restores_whole_title() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 100).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 1)
+            .is_ok());
+        assert_eq!(
+            contract.defractionalize(property_id),
+            Err(Error::InsufficientShares)
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract
+            .transfer_shares(property_id, accounts.alice, 1)
+            .is_ok());
+
+        set_caller(accounts.alice);
+        assert!(contract.defractionalize(property_id).is_ok());
+        assert!(!contract.is_fractional(property_id));
+        assert_eq!(contract.get_share_balance(property_id, accounts.alice), 0);
+        assert!(contract
+            .transfer_property(property_id, accounts.bob)
+            .is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn test_shareholder_vote_unlocks_sale_and_pays_holders() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        set_balance(accounts.django, 10_000_000);
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 100).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 40)
+            .is_ok());
+        assert_eq!(
+            contract.update_metadata(property_id, create_sample_metadata()),
+            Err(Error::RenovationNotApproved)
+        );
+
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.create_proposal(property_id, ProposalAction::Sell { price: 1_000 }),
+            Err(Error::InsufficientShares)
+        );
+
+        set_caller(accounts.bob);
+        let proposal_id = contract
+            .create_proposal(property_id, ProposalAction::Sell { price: 1_000 })
+            .expect("Failed to create proposal");
+        assert!(contract.vote(proposal_id, true).is_ok());
+        assert_eq!(contract.vote(proposal_id, true), Err(Error::AlreadyVoted));
+        assert_eq!(
+            contract.execute_proposal(proposal_id),
+            Err(Error::ProposalNotPassed)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.vote(proposal_id, true).is_ok());
+        assert!(contract.is_proposal_passed(proposal_id));
+        assert!(contract.execute_proposal(proposal_id).is_ok());
+        let listing = contract
+            .get_property_listing(property_id)
+            .expect("Sale should be listed");
+        assert_eq!(listing.seller, accounts.alice);
+        assert_eq!(
+            contract.update_listing_price(listing.id, 2_000),
+            Err(Error::PropertyFractionalized)
+        );
+
+        set_caller(accounts.django);
+        transfer_in(1_000);
+        assert!(contract.buy_listing(listing.id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.django
+        );
+        assert!(!contract.is_fractional(property_id));
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.claim_buyout(property_id), Ok(400));
+        set_caller(accounts.alice);
+        assert_eq!(contract.claim_buyout(property_id), Ok(600));
+        assert_eq!(contract.get_buyout(property_id), None);
+    }
+
+    #[ink::test]
+    fn test_vested_shares_unlock_linearly_after_cliff() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert_eq!(
+            contract.transfer_shares_vested(property_id, accounts.bob, 400, 2_000, 1_000),
+            Err(Error::InvalidVestingSchedule)
+        );
+        assert!(contract
+            .transfer_shares_vested(property_id, accounts.bob, 400, 1_000, 4_000)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.get_locked_shares(property_id, accounts.bob), 400);
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.charlie, 1),
+            Err(Error::SharesLocked)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        assert_eq!(
+            contract.get_transferable_shares(property_id, accounts.bob),
+            100
+        );
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 100)
+            .is_ok());
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.charlie, 1),
+            Err(Error::SharesLocked)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(4_000);
+        assert_eq!(contract.get_locked_shares(property_id, accounts.bob), 0);
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 300)
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_min_share_holding_rejects_dust_balances() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_min_share_holding(property_id, 100),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.set_min_share_holding(property_id, 100).is_ok());
+
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.bob, 99),
+            Err(Error::ShareBalanceBelowMinimum)
+        );
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.bob, 950),
+            Err(Error::ShareBalanceBelowMinimum)
+        );
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 100)
+            .is_ok());
+
+        // Topping up an existing holding and exiting entirely are both allowed
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 1)
+            .is_ok());
+        set_caller(accounts.bob);
+        assert!(contract
+            .transfer_shares(property_id, accounts.alice, 101)
+            .is_ok());
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 0);
+    }
+
+    #[ink::test]
+    fn test_share_allowance_transfer_from() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .approve_shares(property_id, accounts.bob, 300)
+            .is_ok());
+        assert_eq!(
+            contract.get_share_allowance(property_id, accounts.alice, accounts.bob),
+            300
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.transfer_shares_from(property_id, accounts.alice, accounts.charlie, 301),
+            Err(Error::InsufficientShareAllowance)
+        );
+        assert!(contract
+            .transfer_shares_from(property_id, accounts.alice, accounts.charlie, 200)
+            .is_ok());
+        assert_eq!(
+            contract.get_share_balance(property_id, accounts.charlie),
+            200
+        );
+        assert_eq!(
+            contract.get_share_allowance(property_id, accounts.alice, accounts.bob),
+            100
+        );
+    }
+
+    #[ink::test]
+    fn test_shareholder_registry_pagination() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 300)
+            .is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 200)
+            .is_ok());
+        assert_eq!(contract.get_shareholder_count(property_id), 3);
+        assert_eq!(
+            contract.get_shareholders(property_id, 0, 2),
+            vec![(accounts.alice, 500), (accounts.bob, 300)]
+        );
+        assert_eq!(
+            contract.get_shareholders(property_id, 2, 10),
+            vec![(accounts.charlie, 200)]
+        );
+
+        // A holder that sells out leaves the set and the last holder takes its place
+        set_caller(accounts.bob);
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 300)
+            .is_ok());
+        assert_eq!(contract.get_shareholder_count(property_id), 2);
+        assert_eq!(
+            contract.get_shareholders(property_id, 0, 10),
+            vec![(accounts.alice, 500), (accounts.charlie, 500)]
+        );
+    }
+
+    #[ink::test]
+    fn test_share_block_escrow_settles_shares_and_funds_together() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert_eq!(
+            contract.create_share_escrow(property_id, accounts.bob, 300, 500, None),
+            Err(Error::PropertyNotFractionalized)
+        );
+        assert_eq!(
+            contract.create_share_escrow(property_id + 1, accounts.bob, 300, 500, None),
+            Err(Error::PropertyNotFound)
+        );
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert_eq!(
+            contract.create_share_escrow(property_id, accounts.bob, 1_001, 500, None),
+            Err(Error::SharesLocked)
+        );
+        let escrow_id = contract
+            .create_share_escrow(property_id, accounts.bob, 300, 500, None)
+            .expect("Failed to create share escrow");
+        assert_eq!(contract.get_escrow_shares(escrow_id), Some(300));
+        assert_eq!(
+            contract.get_reserved_shares(property_id, accounts.alice),
+            300
+        );
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.charlie, 701),
+            Err(Error::SharesLocked)
+        );
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        let alice_balance = balance_of(accounts.alice);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 300);
+        assert_eq!(contract.get_share_balance(property_id, accounts.alice), 700);
+        assert_eq!(contract.get_reserved_shares(property_id, accounts.alice), 0);
+        assert_eq!(balance_of(accounts.alice), alice_balance + 500);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    #[ink::test]
+    fn test_share_snapshot_freezes_balances() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 400)
+            .is_ok());
+
+        let first = contract.snapshot(property_id).expect("Failed to snapshot");
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 100)
+            .is_ok());
+        let second = contract.snapshot(property_id).expect("Failed to snapshot");
+        set_caller(accounts.bob);
+        assert_eq!(contract.snapshot(property_id), Err(Error::Unauthorized));
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 500)
+            .is_ok());
+
+        assert_eq!(
+            contract.get_share_balance_at(property_id, accounts.bob, first),
+            Ok(400)
+        );
+        assert_eq!(
+            contract.get_share_balance_at(property_id, accounts.bob, second),
+            Ok(500)
+        );
+        assert_eq!(
+            contract.get_share_balance_at(property_id, accounts.alice, second),
+            Ok(500)
+        );
+        assert_eq!(
+            contract.get_share_balance_at(property_id, accounts.charlie, first),
+            Ok(0)
+        );
+        assert_eq!(
+            contract.get_share_balance(property_id, accounts.charlie),
+            500
+        );
+        assert_eq!(
+            contract.get_share_balance_at(property_id + 1, accounts.bob, first),
+            Err(Error::SnapshotNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn test_votes_use_balances_from_proposal_snapshot() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 400)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        let proposal_id = contract
+            .create_proposal(property_id, ProposalAction::Sell { price: 1_000 })
+            .expect("Failed to create proposal");
+        assert!(contract.vote(proposal_id, true).is_ok());
+        // Shares passed on after the snapshot carry no extra voting power
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 400)
+            .is_ok());
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.vote(proposal_id, true),
+            Err(Error::InsufficientShares)
+        );
+        assert_eq!(contract.get_proposal(proposal_id).unwrap().votes_for, 400);
+    }
+
+    #[ink::test]
+    fn test_issued_shares_offered_to_holders_before_public() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.bob, 400)
+            .is_ok());
+        let issuance_id = contract
+            .issue_shares(property_id, 100, 10, 1_000)
+            .expect("Failed to issue shares");
+        assert_eq!(
+            contract.issue_shares(property_id, 100, 10, 1_000),
+            Err(Error::IssuanceInProgress)
+        );
+        assert_eq!(
+            contract.get_preemption_right(issuance_id, accounts.bob),
+            Ok(40)
+        );
+
+        // Outsiders and over-subscribing holders wait for the public offer
+        set_caller(accounts.charlie);
+        transfer_in(100);
+        assert_eq!(
+            contract.buy_issued_shares(issuance_id, 10),
+            Err(Error::ExceedsPreemptionRight)
+        );
+        set_caller(accounts.bob);
+        transfer_in(410);
+        assert_eq!(
+            contract.buy_issued_shares(issuance_id, 41),
+            Err(Error::ExceedsPreemptionRight)
+        );
+        let alice_balance = balance_of(accounts.alice);
+        transfer_in(400);
+        assert!(contract.buy_issued_shares(issuance_id, 40).is_ok());
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 440);
+        assert_eq!(
+            contract.get_preemption_right(issuance_id, accounts.bob),
+            Ok(0)
+        );
+        assert_eq!(balance_of(accounts.alice), alice_balance + 400);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        set_caller(accounts.charlie);
+        transfer_in(600);
+        assert!(contract.buy_issued_shares(issuance_id, 60).is_ok());
+        assert_eq!(
+            contract.get_share_balance(property_id, accounts.charlie),
+            60
+        );
+        assert_eq!(
+            contract
+                .get_fractional_info(property_id)
+                .unwrap()
+                .total_shares,
+            1_100
+        );
+        // Selling out closes the offer
+        assert!(!contract.get_share_issuance(issuance_id).unwrap().open);
+    }
+
+    #[ink::test]
+    fn test_pledged_shares_locked_until_released_or_seized() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        let pledge_id = contract
+            .pledge_shares(property_id, accounts.bob, 600, 1_000)
+            .expect("Failed to pledge shares");
+        assert_eq!(
+            contract.get_transferable_shares(property_id, accounts.alice),
+            400
+        );
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.charlie, 401),
+            Err(Error::SharesLocked)
+        );
+        assert_eq!(
+            contract.pledge_shares(property_id, accounts.bob, 401, 1_000),
+            Err(Error::SharesLocked)
+        );
+
+        // Only the lender acts on the pledge, and only after maturity
+        assert_eq!(contract.release_pledge(pledge_id), Err(Error::Unauthorized));
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.record_pledge_default(pledge_id),
+            Err(Error::PledgeNotMatured)
+        );
+        assert_eq!(
+            contract.seize_pledged_shares(pledge_id),
+            Err(Error::PledgeNotDefaulted)
+        );
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+        assert!(contract.record_pledge_default(pledge_id).is_ok());
+        assert!(contract.seize_pledged_shares(pledge_id).is_ok());
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 600);
+        assert_eq!(contract.get_share_balance(property_id, accounts.alice), 400);
+        assert_eq!(contract.get_pledged_shares(property_id, accounts.alice), 0);
+        assert_eq!(contract.get_share_pledge(pledge_id), None);
+
+        set_caller(accounts.alice);
+        let pledge_id = contract
+            .pledge_shares(property_id, accounts.charlie, 400, 2_000)
+            .expect("Failed to pledge shares");
+        set_caller(accounts.charlie);
+        assert!(contract.release_pledge(pledge_id).is_ok());
+        set_caller(accounts.alice);
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 400)
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_lease_accept_and_end_after_term() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 100, 1_000, 12_000, 0)
+            .expect("Failed to create lease");
+        assert_eq!(
+            contract.create_lease(property_id, accounts.charlie, 100, 1_000, 12_000, 0),
+            Err(Error::PropertyAlreadyLeased)
+        );
+        assert_eq!(
+            contract.accept_lease(lease_id, None),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        let lease = contract.get_lease(lease_id).unwrap();
+        assert_eq!(lease.status, LeaseStatus::Active);
+        assert_eq!(lease.started_at, Some(0));
+        assert_eq!(contract.end_lease(lease_id), Err(Error::LeaseTermNotOver));
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(12_000);
+        assert!(contract.end_lease(lease_id).is_ok());
+        assert_eq!(
+            contract.get_lease(lease_id).unwrap().status,
+            LeaseStatus::Ended
+        );
+        assert_eq!(contract.get_active_lease(property_id), None);
+        assert_eq!(contract.get_property_lease_count(property_id), 1);
+        assert_eq!(contract.get_tenant_lease_count(accounts.bob), 1);
+    }
+
+    #[ink::test]
+    fn test_pay_rent_charges_late_fee_after_due_date() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        set_balance(accounts.bob, 10_000_000);
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 3_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+
+        // First period is due at acceptance and paid on time
+        let owner_balance = balance_of(accounts.alice);
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance + 1_000);
+        assert_eq!(contract.get_lease(lease_id).unwrap().paid_through, 1_000);
+
+        // Once the second period has ended unpaid it is in arrears and the next payment
+        // carries the 5% fee
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_500);
+        assert_eq!(contract.get_rent_arrears(lease_id), 1_000);
+        assert_eq!(contract.get_rent_due(lease_id), Ok(1_050));
+        transfer_in(1_000);
+        assert_eq!(contract.pay_rent(lease_id), Err(Error::IncorrectPayment));
+        transfer_in(1_050);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(contract.get_rent_arrears(lease_id), 0);
+        assert_eq!(contract.get_lease(lease_id).unwrap().late_fees_paid, 50);
+
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(contract.get_rent_due(lease_id), Err(Error::NoRentDue));
+    }
+
+    #[ink::test]
+    fn test_first_rent_paid_after_acceptance_is_not_late() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        set_balance(accounts.bob, 10_000_000);
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 3_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+
+        // Paying in the next block is within the first period, so no fee or missed period
+        ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+        assert!(ink::env::block_timestamp::<ink::env::DefaultEnvironment>() > 0);
+        assert_eq!(contract.get_missed_rent_periods(lease_id), 0);
+        assert_eq!(contract.get_rent_due(lease_id), Ok(1_000));
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(contract.get_lease(lease_id).unwrap().late_fees_paid, 0);
+    }
+
+    #[ink::test]
+    fn test_security_deposit_deduction_disputed_and_resolved() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 100, 1_000, 2_000, 500)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        transfer_in(400);
+        assert_eq!(
+            contract.accept_lease(lease_id, None),
+            Err(Error::IncorrectPayment)
+        );
+        transfer_in(500);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        assert_eq!(contract.get_lease_deposit(lease_id).unwrap().amount, 500);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        assert!(contract.end_lease(lease_id).is_ok());
+        // The owner gets the claim period before the tenant can take the deposit back
+        assert_eq!(
+            contract.release_deposit(lease_id),
+            Err(Error::DepositClaimPeriodActive)
+        );
+
+        set_caller(accounts.alice);
+        let evidence = ink::primitives::Hash::from([0x11; 32]);
+        assert_eq!(
+            contract.claim_deposit_deduction(lease_id, 600, evidence),
+            Err(Error::InvalidDepositDeduction)
+        );
+        assert!(contract
+            .claim_deposit_deduction(lease_id, 300, evidence)
+            .is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.dispute_deposit(lease_id).is_ok());
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.release_deposit(lease_id),
+            Err(Error::InvalidDepositTransition)
+        );
+
+        let tenant_balance = balance_of(accounts.bob);
+        let owner_balance = balance_of(accounts.alice);
+        assert!(contract.resolve_deposit_dispute(lease_id, 100).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance + 100);
+        assert_eq!(balance_of(accounts.bob), tenant_balance + 400);
+        assert_eq!(
+            contract.get_lease_deposit(lease_id).unwrap().status,
+            DepositStatus::Settled
+        );
+    }
+
+    #[ink::test]
+    fn test_tenant_notice_shortens_term_and_charges_penalty_from_deposit() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 100, 1_000, 10_000, 300)
+            .expect("Failed to create lease");
+        assert!(contract
+            .set_lease_termination_terms(lease_id, 2_000, 500)
+            .is_ok());
+        set_caller(accounts.bob);
+        transfer_in(300);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+
+        // The deposit covers 300 of the penalty, the tenant pays the other 200
+        let owner_balance = balance_of(accounts.alice);
+        transfer_in(100);
+        assert_eq!(
+            contract.give_lease_notice(lease_id),
+            Err(Error::IncorrectPayment)
+        );
+        transfer_in(200);
+        assert!(contract.give_lease_notice(lease_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance + 500);
+        assert_eq!(contract.get_lease_deposit(lease_id).unwrap().amount, 0);
+        let lease = contract.get_lease(lease_id).unwrap();
+        assert_eq!(lease.term_end, 2_000);
+        assert_eq!(lease.notice_given_by, Some(accounts.bob));
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.give_lease_notice(lease_id),
+            Err(Error::NoticeAlreadyGiven)
+        );
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        assert!(contract.end_lease(lease_id).is_ok());
+    }
+
+    #[ink::test]
+    fn test_sublease_needs_owner_approval_and_passes_rent_to_tenant() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        set_balance(accounts.charlie, 10_000_000);
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        assert_eq!(
+            contract.propose_sublease(lease_id, accounts.charlie, 1_200, 1_000, 12_000, 0),
+            Err(Error::InvalidLeaseTerms)
+        );
+        let sublease_id = contract
+            .propose_sublease(lease_id, accounts.charlie, 1_200, 1_000, 8_000, 0)
+            .expect("Failed to propose sublease");
+
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.accept_lease(sublease_id, None),
+            Err(Error::SubleaseNotApproved)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.approve_sublease(sublease_id),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.approve_sublease(sublease_id).is_ok());
+        set_caller(accounts.charlie);
+        assert!(contract.accept_lease(sublease_id, None).is_ok());
+
+        let tenant_balance = balance_of(accounts.bob);
+        transfer_in(1_200);
+        assert!(contract.pay_rent(sublease_id).is_ok());
+        assert_eq!(balance_of(accounts.bob), tenant_balance + 1_200);
+        assert_eq!(
+            contract.get_lease(lease_id).unwrap().sublease_rent_received,
+            1_200
+        );
+        assert_eq!(contract.get_sublease(lease_id), Some(sublease_id));
+        assert_eq!(contract.get_active_lease(property_id), Some(lease_id));
+    }
+
+    #[ink::test]
+    fn test_rent_to_own_credit_lets_tenant_take_title() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        set_balance(accounts.bob, 10_000_000);
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        assert!(contract.set_purchase_option(lease_id, 3_000, 5_000).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(contract.get_lease(lease_id).unwrap().purchase_credit, 500);
+        assert_eq!(
+            contract.exercise_purchase_option(lease_id),
+            Err(Error::PurchaseOptionNotExercisable)
+        );
+
+        // At the end of the term the tenant pays whatever the credit does not cover
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10_000);
+        let owner_balance = balance_of(accounts.alice);
+        transfer_in(2_500);
+        assert!(contract.exercise_purchase_option(lease_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance + 2_500);
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        assert_eq!(
+            contract.get_lease(lease_id).unwrap().status,
+            LeaseStatus::Ended
+        );
+    }
+
+    #[ink::test]
+    fn test_lease_renewal_extends_existing_lease() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 2_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(
+            contract.accept_lease_renewal(lease_id),
+            Err(Error::RenewalNotFound)
+        );
+
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.propose_lease_renewal(lease_id, 1_100, 2_000),
+            Err(Error::InvalidLeaseTerms)
+        );
+        assert!(contract
+            .propose_lease_renewal(lease_id, 1_100, 4_000)
+            .is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease_renewal(lease_id).is_ok());
+
+        let lease = contract.get_lease(lease_id).unwrap();
+        assert_eq!(lease.rent, 1_100);
+        assert_eq!(lease.term_end, 4_000);
+        assert_eq!(lease.renewals, 1);
+        // Payment history carries over to the renewed term
+        assert_eq!(lease.paid_through, 1_000);
+        assert_eq!(contract.get_lease_renewal(lease_id), None);
+        assert_eq!(contract.get_property_lease_count(property_id), 1);
+    }
+
+    #[ink::test]
+    fn test_tenant_compliance_toggle() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.is_tenant_compliance_required());
+        assert!(contract.set_tenant_compliance_required(false).is_ok());
+        assert!(!contract.is_tenant_compliance_required());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_tenant_compliance_required(true),
+            Err(Error::Unauthorized)
+        );
+        assert!(!contract.is_tenant_compliance_required());
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn test_tenant_screened_in_property_jurisdiction() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let mut metadata = create_sample_metadata();
+        metadata.jurisdiction = Some(Jurisdiction::US);
+        let us_property = contract.register_property(metadata.clone()).unwrap();
+        metadata.jurisdiction = Some(Jurisdiction::UK);
+        let uk_property = contract.register_property(metadata).unwrap();
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+
+        let attestation = ComplianceAttestation {
+            account: accounts.bob,
+            jurisdiction: Some(Jurisdiction::US),
+            tier: 1,
+            nonce: 1,
+            expires_at: 5_000,
+        };
+        let (signer, signature) = sign_attestation_ecdsa(&attestation);
+        assert!(contract.set_compliance_signer(Some(signer)).is_ok());
+        assert!(contract
+            .submit_compliance_attestation(attestation, signature)
+            .is_ok());
+        assert!(contract
+            .set_compliance_registry(Some(accounts.charlie))
+            .is_ok());
+
+        // The attestation clears the tenant in the US without asking the registry
+        let lease_id = contract
+            .create_lease(us_property, accounts.bob, 100, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+
+        // It says nothing about the UK, so that lease falls through to the registry, which
+        // the off-chain environment cannot call
+        set_caller(accounts.alice);
+        let lease_id = contract
+            .create_lease(uk_property, accounts.bob, 100, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        let _ = contract.accept_lease(lease_id, None);
+    }
+
+    #[ink::test]
+    fn test_rent_on_fractional_property_goes_to_shareholders() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        assert!(contract.enable_fractional(property_id, 100).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 40)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        let owner_balance = balance_of(accounts.alice);
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance);
+        assert_eq!(
+            contract.get_claimable_income(property_id, accounts.alice),
+            600
+        );
+        assert_eq!(
+            contract.get_claimable_income(property_id, accounts.charlie),
+            400
+        );
+    }
+
+    #[ink::test]
+    fn test_lease_assignment_moves_tenancy_with_deposit() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        set_balance(accounts.bob, 10_000_000);
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 500)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        assert!(contract
+            .propose_lease_assignment(lease_id, accounts.charlie)
+            .is_ok());
+
+        set_caller(accounts.charlie);
+        assert_eq!(
+            contract.accept_lease_assignment(lease_id),
+            Err(Error::AssignmentNotApproved)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.approve_lease_assignment(lease_id).is_ok());
+        set_caller(accounts.charlie);
+        assert!(contract.accept_lease_assignment(lease_id).is_ok());
+
+        let lease = contract.get_lease(lease_id).unwrap();
+        assert_eq!(lease.tenant, accounts.charlie);
+        assert_eq!(lease.previous_tenants, vec![accounts.bob]);
+        assert_eq!(contract.get_lease_deposit(lease_id).unwrap().amount, 500);
+        assert_eq!(contract.get_tenant_lease_count(accounts.charlie), 1);
+        assert_eq!(contract.get_lease_assignment(lease_id), None);
+
+        // Only the new tenant can pay rent from now on
+        set_caller(accounts.bob);
+        transfer_in(1_000);
+        assert_eq!(contract.pay_rent(lease_id), Err(Error::Unauthorized));
+    }
+
+    #[ink::test]
+    fn test_maintenance_requests_filed_and_resolved() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        let description = ink::primitives::Hash::from([0x21; 32]);
+        assert_eq!(
+            contract.file_maintenance_request(lease_id, description),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        let request_id = contract
+            .file_maintenance_request(lease_id, description)
+            .expect("Failed to file maintenance request");
+
+        let resolution = ink::primitives::Hash::from([0x22; 32]);
+        assert_eq!(
+            contract.resolve_maintenance_request(request_id, resolution),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract
+            .resolve_maintenance_request(request_id, resolution)
+            .is_ok());
+        assert_eq!(
+            contract.resolve_maintenance_request(request_id, resolution),
+            Err(Error::MaintenanceAlreadyResolved)
+        );
+        let request = contract.get_maintenance_request(request_id).unwrap();
+        assert_eq!(request.description_hash, description);
+        assert_eq!(request.resolution_hash, Some(resolution));
+        assert_eq!(
+            contract.get_lease_maintenance_requests(lease_id),
+            vec![request_id]
+        );
+    }
+
+    #[ink::test]
+    fn test_terminate_for_default_after_missed_periods() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+
+        // The periods ending at 1_000 and 2_000 have passed unpaid by 2_500
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_500);
+        assert_eq!(contract.get_missed_rent_periods(lease_id), 2);
+        assert!(!contract.is_eviction_eligible(lease_id));
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.terminate_for_default(lease_id),
+            Err(Error::NotEvictionEligible)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(3_000);
+        assert_eq!(contract.get_missed_rent_periods(lease_id), 3);
+        assert!(contract.is_eviction_eligible(lease_id));
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.terminate_for_default(lease_id),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract.terminate_for_default(lease_id).is_ok());
+        assert_eq!(
+            contract.get_lease(lease_id).unwrap().status,
+            LeaseStatus::Ended
+        );
+        assert!(!contract.is_eviction_eligible(lease_id));
+    }
+
+    #[ink::test]
+    fn test_paginated_lease_queries_by_property_and_tenant() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let first_property = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second_property = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+
+        // Three leases on the first property, one after another, all to bob
+        let mut lease_ids = Vec::new();
+        for _ in 0..3 {
+            let lease_id = contract
+                .create_lease(first_property, accounts.bob, 100, 1_000, 10_000, 0)
+                .expect("Failed to create lease");
+            assert!(contract.end_lease(lease_id).is_ok());
+            lease_ids.push(lease_id);
+        }
+        let active_id = contract
+            .create_lease(second_property, accounts.bob, 100, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+
+        assert_eq!(contract.get_property_lease_count(first_property), 3);
+        let page = contract.get_property_leases(first_property, 1, 5);
+        assert_eq!(
+            page.iter().map(|lease| lease.id).collect::<Vec<_>>(),
+            lease_ids[1..].to_vec()
+        );
+        assert!(page.iter().all(|lease| lease.status == LeaseStatus::Ended));
+
+        assert_eq!(contract.get_tenant_lease_count(accounts.bob), 4);
+        let page = contract.get_tenant_leases(accounts.bob, 3, 10);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].id, active_id);
+        assert_eq!(page[0].status, LeaseStatus::Pending);
+        assert!(contract.get_tenant_leases(accounts.bob, 4, 10).is_empty());
+    }
+
+    #[ink::test]
+    fn test_disputed_rent_held_until_arbitrator_releases_it() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 1_000, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        set_caller(accounts.bob);
+        assert!(contract.accept_lease(lease_id, None).is_ok());
+        let evidence = ink::primitives::Hash::from([0x31; 32]);
+        assert_eq!(
+            contract.dispute_habitability(lease_id, evidence),
+            Err(Error::ArbitratorNotSet)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.set_rent_arbitrator(Some(accounts.eve)).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.dispute_habitability(lease_id, evidence).is_ok());
+
+        // Rent is held, yet the tenant stays current
+        let owner_balance = balance_of(accounts.alice);
+        transfer_in(1_000);
+        assert!(contract.pay_rent(lease_id).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance);
+        assert_eq!(contract.get_rent_dispute(lease_id).unwrap().held, 1_000);
+        assert_eq!(contract.get_rent_arrears(lease_id), 0);
+
+        assert_eq!(
+            contract.release_rent_escrow(lease_id, 600),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.eve);
+        assert_eq!(
+            contract.release_rent_escrow(lease_id, 1_001),
+            Err(Error::ExceedsHeldRent)
+        );
+        let tenant_balance = balance_of(accounts.bob);
+        assert!(contract.release_rent_escrow(lease_id, 600).is_ok());
+        assert_eq!(balance_of(accounts.alice), owner_balance + 600);
+        assert_eq!(balance_of(accounts.bob), tenant_balance + 400);
+        assert_eq!(contract.get_rent_dispute(lease_id), None);
+    }
+
+    #[ink::test]
+    fn test_lease_acceptance_requires_matching_document_hash() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let lease_id = contract
+            .create_lease(property_id, accounts.bob, 100, 1_000, 10_000, 0)
+            .expect("Failed to create lease");
+        let document = ink::primitives::Hash::from([0x41; 32]);
+        assert!(contract.set_lease_document(lease_id, document).is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_lease_document(lease_id, document),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(
+            contract.accept_lease(lease_id, None),
+            Err(Error::DocumentHashMismatch)
+        );
+        assert_eq!(
+            contract.accept_lease(lease_id, Some(ink::primitives::Hash::from([0x42; 32]))),
+            Err(Error::DocumentHashMismatch)
+        );
+        assert!(contract.accept_lease(lease_id, Some(document)).is_ok());
+        assert_eq!(
+            contract.get_lease(lease_id).unwrap().document_hash,
+            Some(document)
+        );
+    }
+
+    #[ink::test]
+    fn test_compliance_fail_open_toggle() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(!contract.is_compliance_fail_open());
+        assert!(contract.set_compliance_fail_open(true).is_ok());
+        assert!(contract.is_compliance_fail_open());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_compliance_fail_open(false),
+            Err(Error::Unauthorized)
+        );
+        assert!(contract.is_compliance_fail_open());
+    }
+
+    #[ink::test]
+    fn test_compliance_cache_admin_controls() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert_eq!(contract.get_compliance_cache_ttl(), 0);
+        assert!(contract.set_compliance_cache_ttl(60_000).is_ok());
+        assert_eq!(contract.get_compliance_cache_ttl(), 60_000);
+        assert!(!contract.is_compliance_cached(accounts.bob));
+        assert!(contract.flush_compliance_cache().is_ok());
+        assert!(contract.invalidate_compliance_cache(accounts.bob).is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_compliance_cache_ttl(0),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(contract.flush_compliance_cache(), Err(Error::Unauthorized));
+        assert_eq!(
+            contract.invalidate_compliance_cache(accounts.bob),
+            Err(Error::Unauthorized)
+        );
+
+        // The registry itself may evict an account it has revoked
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_compliance_registry(Some(accounts.charlie))
+            .is_ok());
+        set_caller(accounts.charlie);
+        assert!(contract.invalidate_compliance_cache(accounts.bob).is_ok());
+    }
+
+    #[ink::test]
+    fn test_property_records_jurisdiction() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let mut metadata = create_sample_metadata();
+        metadata.jurisdiction = Some(Jurisdiction::UK);
+        let property_id = contract.register_property(metadata).unwrap();
+        assert_eq!(
+            contract
+                .get_property(property_id)
+                .unwrap()
+                .metadata
+                .jurisdiction,
+            Some(Jurisdiction::UK)
+        );
+
+        // Without a registry, restricted property still moves freely
+        assert!(contract
+            .transfer_property(property_id, accounts.bob)
+            .is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+    }
+
+    #[ink::test]
+    fn test_kyc_tier_thresholds() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert_eq!(contract.get_required_kyc_tier(u128::MAX), 1);
+
+        assert_eq!(
+            contract.set_kyc_tier_thresholds(vec![(1_000_000, 0)]),
+            Err(Error::InvalidKycTier)
+        );
+        assert!(contract
+            .set_kyc_tier_thresholds(vec![(1_000_000, 3), (100_000, 2)])
+            .is_ok());
+        assert_eq!(contract.get_required_kyc_tier(99_999), 1);
+        assert_eq!(contract.get_required_kyc_tier(100_000), 2);
+        assert_eq!(contract.get_required_kyc_tier(5_000_000), 3);
+        assert_eq!(contract.get_kyc_tier_thresholds().len(), 2);
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_kyc_tier_thresholds(Vec::new()),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_sanctions_blocklist() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert!(contract.add_to_sanctions_list(accounts.bob).is_ok());
+        assert!(contract.is_sanctioned(accounts.bob));
+        assert_eq!(contract.check_account_compliance(accounts.bob), Ok(false));
+
+        // Blocked even though no compliance registry is configured
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.bob),
+            Err(Error::AccountSanctioned)
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.register_property(create_sample_metadata()),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(
+            contract.remove_from_sanctions_list(accounts.bob),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_compliance_officer(Some(accounts.charlie))
+            .is_ok());
+        set_caller(accounts.charlie);
+        assert!(contract.remove_from_sanctions_list(accounts.bob).is_ok());
+        assert!(!contract.is_sanctioned(accounts.bob));
+
+        assert!(contract.add_to_sanctions_list(accounts.alice).is_ok());
+        set_caller(accounts.alice);
+        assert_eq!(
+            contract.list_property(property_id, 1_000, None),
+            Err(Error::AccountSanctioned)
+        );
+    }
+
+    #[ink::test]
+    fn test_batch_transfers_screen_recipients() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let first = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let second = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.add_to_sanctions_list(accounts.charlie).is_ok());
+
+        assert_eq!(
+            contract.batch_transfer_properties(vec![first, second], accounts.charlie),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(
+            contract.batch_transfer_properties_to_multiple(vec![
+                (first, accounts.bob),
+                (second, accounts.charlie)
+            ]),
+            Err(Error::AccountSanctioned)
+        );
+        // Nothing moved
+        assert_eq!(contract.get_property(first).unwrap().owner, accounts.alice);
+        assert_eq!(contract.get_property(second).unwrap().owner, accounts.alice);
+    }
+
+    #[ink::test]
+    fn test_compliance_exemption_whitelist() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(!contract.is_compliance_exempt(accounts.django));
+        assert!(contract
+            .set_compliance_exempt(accounts.django, true)
+            .is_ok());
+        assert!(contract.is_compliance_exempt(accounts.django));
+        assert_eq!(contract.check_account_compliance(accounts.django), Ok(true));
+
+        // Sanctions still win over an exemption
+        assert!(contract.add_to_sanctions_list(accounts.django).is_ok());
+        assert_eq!(
+            contract.check_account_compliance(accounts.django),
+            Ok(false)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_compliance_exempt(accounts.django, false),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_compliance_exempt(accounts.django, false)
+            .is_ok());
+        assert!(!contract.is_compliance_exempt(accounts.django));
+    }
+
+    #[ink::test]
+    fn test_check_compliance_batch() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.add_to_sanctions_list(accounts.charlie).is_ok());
+        // Without a registry only the sanctions list decides, under either policy
+        for policy in [CompliancePolicy::All, CompliancePolicy::Any] {
+            assert!(contract.set_compliance_policy(policy).is_ok());
+            assert_eq!(
+                contract.check_compliance_batch(vec![
+                    accounts.bob,
+                    accounts.charlie,
+                    accounts.django
+                ]),
+                Ok(vec![true, false, true])
+            );
+            assert_eq!(contract.check_account_compliance(accounts.bob), Ok(true));
+        }
+        assert_eq!(contract.check_compliance_batch(Vec::new()), Ok(Vec::new()));
+        assert_eq!(
+            contract.check_compliance_batch(vec![accounts.bob; 101]),
+            Err(Error::ComplianceBatchTooLarge)
+        );
+    }
+
+    #[ink::test]
+    fn test_escrow_release_rescreens_buyer_at_settlement() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+
+        // The buyer passed at creation but is blocked before closing
+        set_caller(accounts.alice);
+        assert!(contract.add_to_sanctions_list(accounts.bob).is_ok());
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.release_escrow(escrow_id),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+
+        // Nor can an arbitrator force the title through to them
+        assert!(contract
+            .dispute_escrow(escrow_id, String::from("closing blocked"))
+            .is_ok());
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_escrow_arbitrator(Some(accounts.django))
+            .is_ok());
+        set_caller(accounts.django);
+        assert_eq!(
+            contract.resolve_escrow_dispute(escrow_id, 0, true),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.alice
+        );
+    }
+
+    #[ink::test]
+    fn test_compliance_checked_only_when_registry_consulted() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+
+        // Without a registry there is nothing to record: registration emits only its own event
+        let before = ink::env::test::recorded_events().count();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .unwrap();
+        assert_eq!(ink::env::test::recorded_events().count(), before + 1);
+
+        // An exempt account is waved through without consulting anyone
+        assert!(contract.set_compliance_exempt(accounts.bob, true).is_ok());
+        let before = ink::env::test::recorded_events().count();
+        assert!(contract
+            .transfer_property(property_id, accounts.bob)
+            .is_ok());
+        assert_eq!(ink::env::test::recorded_events().count(), before + 1);
+
+        // A sanctioned recipient is refused before any registry is asked
+        set_caller(accounts.alice);
+        assert!(contract.add_to_sanctions_list(accounts.charlie).is_ok());
+        set_caller(accounts.bob);
+        let before = ink::env::test::recorded_events().count();
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.charlie),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(ink::env::test::recorded_events().count(), before);
+    }
+
+    #[ink::test]
+    fn test_compliance_staleness_policy() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(!contract.compliance_stale(accounts.bob));
+        assert_eq!(contract.get_compliance_confirmed_at(accounts.bob), None);
+
+        assert!(contract.set_compliance_max_age(86_400_000).is_ok());
+        assert_eq!(contract.get_compliance_max_age(), 86_400_000);
+        // Never confirmed by a registry
+        assert!(contract.compliance_stale(accounts.bob));
+
+        set_caller(accounts.bob);
+        assert_eq!(contract.set_compliance_max_age(0), Err(Error::Unauthorized));
+        set_caller(accounts.alice);
+        assert!(contract.set_compliance_max_age(0).is_ok());
+        assert!(!contract.compliance_stale(accounts.bob));
+    }
+
+    #[ink::test]
+    fn test_stale_account_views_match_enforcement() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.set_compliance_fail_open(true).is_ok());
+        assert!(contract.set_compliance_max_age(1_000).is_ok());
+        assert!(contract.compliance_stale(accounts.bob));
+
+        // Without a registry nothing can lapse, so every path lets the stale account through
+        assert_eq!(contract.check_account_compliance(accounts.bob), Ok(true));
+        assert_eq!(
+            contract.check_compliance_batch(vec![accounts.bob]),
+            Ok(vec![true])
+        );
+        assert!(contract
+            .transfer_property(property_id, accounts.bob)
+            .is_ok());
+
+        // A blocklisted stale account is refused everywhere
+        assert!(contract.add_to_sanctions_list(accounts.charlie).is_ok());
+        assert_eq!(
+            contract.check_account_compliance(accounts.charlie),
+            Ok(false)
+        );
+        assert_eq!(
+            contract.check_compliance_batch(vec![accounts.bob, accounts.charlie]),
+            Ok(vec![true, false])
+        );
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.transfer_property(property_id, accounts.charlie),
+            Err(Error::AccountSanctioned)
+        );
+    }
+
+    #[ink::test]
+    fn test_multiple_compliance_registries() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        assert!(contract.get_compliance_registries().is_empty());
+        assert_eq!(contract.get_compliance_policy(), CompliancePolicy::All);
+
+        assert!(contract
+            .set_compliance_registry(Some(accounts.charlie))
+            .is_ok());
+        assert!(contract.add_compliance_registry(accounts.django).is_ok());
+        assert!(contract.add_compliance_registry(accounts.django).is_ok());
+        assert_eq!(
+            contract.get_compliance_registries(),
+            vec![accounts.charlie, accounts.django]
+        );
+        assert!(contract
+            .set_compliance_policy(CompliancePolicy::Any)
+            .is_ok());
+        assert_eq!(contract.get_compliance_policy(), CompliancePolicy::Any);
+
+        // Any registry may evict an account it has revoked
+        set_caller(accounts.django);
+        assert!(contract.invalidate_compliance_cache(accounts.bob).is_ok());
+        assert_eq!(
+            contract.add_compliance_registry(accounts.eve),
+            Err(Error::Unauthorized)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.remove_compliance_registry(accounts.django).is_ok());
+        assert_eq!(contract.get_compliance_registries(), vec![accounts.charlie]);
+        for seed in 0..5u8 {
+            assert!(contract
+                .add_compliance_registry(AccountId::from([seed; 32]))
+                .is_ok());
+        }
+        assert_eq!(
+            contract.add_compliance_registry(accounts.eve),
+            Err(Error::TooManyComplianceRegistries)
+        );
+    }
+
+    #[ink::test]
+    fn test_per_operation_compliance_toggles() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        for operation in [
+            ComplianceOperation::RegisterProperty,
+            ComplianceOperation::TransferProperty,
+            ComplianceOperation::ListForSale,
+            ComplianceOperation::Lease,
+            ComplianceOperation::CreateEscrow,
+        ] {
+            assert!(contract.is_compliance_required(operation));
+        }
+
+        assert!(contract
+            .set_compliance_required(ComplianceOperation::RegisterProperty, false)
+            .is_ok());
+        assert!(!contract.is_compliance_required(ComplianceOperation::RegisterProperty));
+        assert!(contract
+            .set_compliance_required(ComplianceOperation::Lease, false)
+            .is_ok());
+        assert!(!contract.is_tenant_compliance_required());
+        assert_eq!(
+            contract.set_compliance_required(ComplianceOperation::BridgeTransfer, true),
+            Err(Error::InvalidComplianceOperation)
+        );
+
+        // Skipped checks emit no screening event but still honour the blocklist
+        let before = ink::env::test::recorded_events().count();
+        assert!(contract.register_property(create_sample_metadata()).is_ok());
+        assert_eq!(ink::env::test::recorded_events().count(), before + 1);
+        assert!(contract.add_to_sanctions_list(accounts.bob).is_ok());
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.register_property(create_sample_metadata()),
+            Err(Error::AccountSanctioned)
+        );
+        assert_eq!(
+            contract.set_compliance_required(ComplianceOperation::RegisterProperty, true),
+            Err(Error::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_compliance_attestation_rejections() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+
+        let attestation = ComplianceAttestation {
+            account: accounts.bob,
+            jurisdiction: None,
+            tier: 1,
+            nonce: 1,
+            expires_at: 5_000,
+        };
+        let signature = AttestationSignature::Sr25519([7u8; 64]);
+        assert_eq!(
+            contract.submit_compliance_attestation(attestation.clone(), signature),
+            Err(Error::ComplianceSignerNotSet)
+        );
+
+        set_caller(accounts.bob);
+        assert_eq!(
+            contract.set_compliance_signer(Some(ComplianceSigner::Sr25519([1u8; 32]))),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.alice);
+        assert!(contract
+            .set_compliance_signer(Some(ComplianceSigner::Sr25519([1u8; 32])))
+            .is_ok());
+        assert_eq!(
+            contract.get_compliance_signer(),
+            Some(ComplianceSigner::Sr25519([1u8; 32]))
+        );
+
+        let expired = ComplianceAttestation {
+            expires_at: 1_000,
+            ..attestation.clone()
+        };
+        assert_eq!(
+            contract.submit_compliance_attestation(expired, signature),
+            Err(Error::AttestationExpired)
+        );
+        let tierless = ComplianceAttestation {
+            tier: 0,
+            ..attestation.clone()
+        };
+        assert_eq!(
+            contract.submit_compliance_attestation(tierless, signature),
+            Err(Error::InvalidAttestation)
+        );
+        assert_eq!(
+            contract.submit_compliance_attestation(attestation.clone(), signature),
+            Err(Error::InvalidAttestationSignature)
+        );
+        assert_eq!(
+            contract.submit_compliance_attestation(
+                attestation.clone(),
+                AttestationSignature::Ecdsa([7u8; 65])
+            ),
+            Err(Error::InvalidAttestationSignature)
+        );
+        assert_eq!(contract.get_attestation_nonce(accounts.bob), 0);
+        assert!(!contract.is_compliance_cached(accounts.bob));
+
+        // The attestation must vouch for the recipient
+        assert_eq!(
+            contract.transfer_property_with_attestation(
+                property_id,
+                accounts.charlie,
+                attestation,
+                signature
+            ),
+            Err(Error::InvalidAttestation)
+        );
+        assert_eq!(
+            contract.get_property(property_id).map(|p| p.owner),
+            Some(accounts.alice)
+        );
+    }
+
+    #[ink::test]
+    fn test_attested_buyer_settles_escrow() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+
+        let attestation = ComplianceAttestation {
+            account: accounts.bob,
+            jurisdiction: None,
+            tier: 1,
+            nonce: 1,
+            expires_at: 5_000,
+        };
+        let (signer, signature) = sign_attestation_ecdsa(&attestation);
+        assert!(contract.set_compliance_signer(Some(signer)).is_ok());
+        assert!(contract
+            .submit_compliance_attestation(attestation.clone(), signature)
+            .is_ok());
+        assert_eq!(contract.get_attestation_nonce(accounts.bob), 1);
+        assert_eq!(
+            contract.get_compliance_attestation(accounts.bob),
+            Some((5_000, None, 1))
+        );
+        assert_eq!(
+            contract.submit_compliance_attestation(attestation, signature),
+            Err(Error::AttestationNonceUsed)
+        );
+
+        // Attestations are not part of the compliance cache
+        assert!(contract.flush_compliance_cache().is_ok());
+        assert!(contract.get_compliance_attestation(accounts.bob).is_some());
+
+        let escrow_id = contract
+            .create_escrow(property_id, accounts.bob, 500, None)
+            .expect("Failed to create escrow");
+        set_caller(accounts.bob);
+        transfer_in(500);
+        assert!(contract.fund_escrow(escrow_id).is_ok());
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_property(property_id).unwrap().owner,
+            accounts.bob
+        );
+        // Settlement re-screening leaves the attestation in place
+        assert!(contract.get_compliance_attestation(accounts.bob).is_some());
+
+        // Rotating the signer drops attestations made under the old key
+        set_caller(accounts.alice);
+        assert!(contract.set_compliance_signer(None).is_ok());
+        assert_eq!(contract.get_compliance_attestation(accounts.bob), None);
+    }
+
+    #[ink::test]
+    fn test_buyer_opened_share_escrow_reserves_on_acceptance() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        assert!(contract.enable_fractional(property_id, 1_000).is_ok());
+        assert!(contract
+            .transfer_shares(property_id, accounts.charlie, 400)
+            .is_ok());
+
+        set_caller(accounts.bob);
+        transfer_in(0);
+        assert_eq!(
+            contract.open_share_escrow(property_id, accounts.charlie, 401, 500, None),
+            Err(Error::InsufficientShares)
+        );
+        transfer_in(500);
+        let escrow_id = contract
+            .open_share_escrow(property_id, accounts.charlie, 200, 500, None)
+            .expect("Failed to open share escrow");
+        assert_eq!(contract.get_escrow_shares(escrow_id), Some(200));
+        // Nothing is reserved until the holder accepts
+        assert_eq!(
+            contract.get_reserved_shares(property_id, accounts.charlie),
+            0
+        );
+
+        set_caller(accounts.charlie);
+        assert!(contract.accept_escrow(escrow_id).is_ok());
+        assert_eq!(
+            contract.get_reserved_shares(property_id, accounts.charlie),
+            200
+        );
+        assert_eq!(
+            contract.transfer_shares(property_id, accounts.alice, 201),
+            Err(Error::SharesLocked)
+        );
+
+        set_caller(accounts.bob);
+        let charlie_balance = balance_of(accounts.charlie);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+        assert_eq!(contract.get_share_balance(property_id, accounts.bob), 200);
+        assert_eq!(
+            contract.get_share_balance(property_id, accounts.charlie),
+            200
+        );
+        assert_eq!(balance_of(accounts.charlie), charlie_balance + 500);
+    }
+
+    #[ink::test]
+    fn test_escrow_bound_to_active_listing_price() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let listing_id = contract
+            .list_property(property_id, 1_000, None)
+            .expect("Failed to list property");
+
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.bob, 999, None),
+            Err(Error::EscrowBelowListingPrice)
+        );
+        set_caller(accounts.bob);
+        transfer_in(0);
+        assert_eq!(
+            contract.open_escrow(property_id, 999, None),
+            Err(Error::EscrowBelowListingPrice)
+        );
+        assert_eq!(
+            contract.create_escrow_psp22(property_id, accounts.frank, 1_000),
+            Err(Error::EscrowBelowListingPrice)
+        );
+
+        transfer_in(1_000);
+        let escrow_id = contract
+            .open_escrow(property_id, 1_000, None)
+            .expect("Failed to open escrow");
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().listing_id,
+            Some(listing_id)
+        );
+
+        set_caller(accounts.alice);
+        assert!(contract.accept_escrow(escrow_id).is_ok());
+        set_caller(accounts.bob);
+        assert!(contract.release_escrow(escrow_id).is_ok());
+
+        let listing = contract.get_listing(listing_id).unwrap();
+        assert_eq!(listing.status, ListingStatus::Sold);
+        assert_eq!(listing.buyer, Some(accounts.bob));
+        assert_eq!(contract.get_property_listing(property_id), None);
+        assert_eq!(contract.active_listing_count(), 0);
+    }
+
+    #[ink::test]
+    fn test_escrow_on_private_listing_limited_to_named_buyer() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        set_contract_account();
+        let property_id = contract
+            .register_property(create_sample_metadata())
+            .expect("Failed to register property");
+        let listing_id = contract
+            .list_property_privately(property_id, 1_000, accounts.bob, None)
+            .expect("Failed to list property");
+
+        assert_eq!(
+            contract.create_escrow(property_id, accounts.charlie, 1_000, None),
+            Err(Error::Unauthorized)
+        );
+        set_caller(accounts.charlie);
+        transfer_in(1_000);
+        assert_eq!(
+            contract.open_escrow(property_id, 1_000, None),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(
+            contract.get_listing(listing_id).unwrap().status,
+            ListingStatus::Active
+        );
+
+        set_caller(accounts.bob);
+        let escrow_id = contract
+            .open_escrow(property_id, 1_000, None)
+            .expect("Failed to open escrow");
+        assert_eq!(
+            contract.get_escrow(escrow_id).unwrap().listing_id,
+            Some(listing_id)
+        );
+    }
+
+    #[ink::test]
+    fn test_tampered_attestation_rejected() {
+        let accounts = default_accounts();
+        set_caller(accounts.alice);
+        let mut contract = PropertyRegistry::new();
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+
+        let attestation = ComplianceAttestation {
+            account: accounts.bob,
+            jurisdiction: None,
+            tier: 1,
+            nonce: 1,
+            expires_at: 5_000,
+        };
+        let tampered = [
+            ComplianceAttestation {
+                account: accounts.charlie,
+                ..attestation.clone()
+            },
+            ComplianceAttestation {
+                tier: 3,
+                ..attestation.clone()
+            },
+            ComplianceAttestation {
+                expires_at: 9_000,
+                ..attestation.clone()
+            },
+        ];
+        for sign in [sign_attestation_ecdsa, sign_attestation_sr25519] {
+            let (signer, signature) = sign(&attestation);
+            assert!(contract.set_compliance_signer(Some(signer)).is_ok());
+            for forged in tampered.iter().cloned() {
+                assert_eq!(
+                    contract.submit_compliance_attestation(forged, signature),
+                    Err(Error::InvalidAttestationSignature)
+                );
+            }
+            assert_eq!(contract.get_attestation_nonce(accounts.charlie), 0);
+            assert_eq!(contract.get_compliance_attestation(accounts.bob), None);
+        }
+
+        // The untouched payload verifies under the sr25519 key
+        let (_, signature) = sign_attestation_sr25519(&attestation);
+        assert!(contract
+            .submit_compliance_attestation(attestation, signature)
+            .is_ok());
+        assert_eq!(
+            contract.get_compliance_attestation(accounts.bob),
+            Some((5_000, None, 1))
+        );
+    }
 }
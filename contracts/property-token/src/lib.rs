@@ -2326,6 +2326,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let result = contract.register_property_with_token(metadata.clone());
@@ -2346,6 +2347,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let _token_id = contract
@@ -2370,6 +2372,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -2396,6 +2399,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -2440,6 +2444,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -2473,6 +2478,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -2523,6 +2529,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -2558,6 +2565,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -2614,6 +2622,7 @@ mod property_token {
                     legal_description: format!("Description {}", i),
                     valuation: 100_000 + (i as u128 * 1000),
                     documents_url: format!("ipfs://prop{}", i),
+                    jurisdiction: None,
                 };
 
                 let token_id = contract
@@ -2636,6 +2645,7 @@ mod property_token {
                 legal_description: String::from("Sample property"),
                 valuation: 500000,
                 documents_url: String::from("ipfs://sample-docs"),
+                jurisdiction: None,
             };
 
             let token_id = contract
@@ -0,0 +1,218 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![allow(unexpected_cfgs)]
+
+use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
+use ink::storage::Mapping;
+use propchain_traits::{PSP22Error, ShareRegistry, PSP22};
+
+pub use self::propchain_share_token::{ShareToken, ShareTokenRef};
+
+/// PSP22 share token for a single fractionalized property.
+/// Instantiated by the property registry, which receives the whole supply and releases
+/// tokens to shareholders as they wrap their in-registry shares. Every other recipient is
+/// screened by the registry, so tokens follow the same compliance rules as the shares.
+#[ink::contract]
+mod propchain_share_token {
+    use super::*;
+
+    /// Event emitted when tokens move between accounts
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: u128,
+    }
+
+    /// Event emitted when an allowance is set
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: u128,
+    }
+
+    #[ink(storage)]
+    pub struct ShareToken {
+        /// Registry property the shares represent
+        property_id: u64,
+        /// Registry contract that instantiated the token
+        registry: AccountId,
+        /// Fixed supply, equal to the property's total shares
+        total_supply: u128,
+        /// Token balances
+        balances: Mapping<AccountId, u128>,
+        /// Allowances by (owner, spender)
+        allowances: Mapping<(AccountId, AccountId), u128>,
+    }
+
+    impl ShareToken {
+        /// Creates the token and mints the whole supply to the instantiating registry
+        #[ink(constructor)]
+        pub fn new(property_id: u64, total_supply: u128) -> Self {
+            let registry = Self::env().caller();
+            let mut balances = Mapping::default();
+            balances.insert(registry, &total_supply);
+            Self::env().emit_event(Transfer {
+                from: None,
+                to: Some(registry),
+                value: total_supply,
+            });
+            Self {
+                property_id,
+                registry,
+                total_supply,
+                balances,
+                allowances: Mapping::default(),
+            }
+        }
+
+        /// Gets the registry property the shares represent
+        #[ink(message)]
+        pub fn property_id(&self) -> u64 {
+            self.property_id
+        }
+
+        /// Gets the registry contract that issued the token
+        #[ink(message)]
+        pub fn registry(&self) -> AccountId {
+            self.registry
+        }
+
+        /// Asks the registry whether `to` may receive the tokens
+        /// Transfers to or from the registry are screened by the registry itself.
+        fn ensure_recipient_allowed(
+            &self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> Result<(), PSP22Error> {
+            if from == self.registry || to == self.registry {
+                return Ok(());
+            }
+            use ink::env::call::FromAccountId;
+            let registry: ink::contract_ref!(ShareRegistry) =
+                FromAccountId::from_account_id(self.registry);
+            if !registry.can_receive_shares(self.property_id, to, value) {
+                return Err(PSP22Error::Custom(String::from("RecipientNotCompliant")));
+            }
+            Ok(())
+        }
+
+        fn transfer_from_to(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> Result<(), PSP22Error> {
+            let from_balance = self.balances.get(from).unwrap_or(0);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.ensure_recipient_allowed(from, to, value)?;
+            self.balances.insert(from, &(from_balance - value));
+            let to_balance = self.balances.get(to).unwrap_or(0);
+            self.balances.insert(to, &to_balance.saturating_add(value));
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+    }
+
+    impl PSP22 for ShareToken {
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128 {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let from = self.env().caller();
+            self.transfer_from_to(from, to, value)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let allowance = self.allowance(from, caller);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.transfer_from_to(from, to, value)?;
+            self.allowances.insert((from, caller), &(allowance - value));
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            self.allowances.insert((owner, spender), &value);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn test_supply_minted_to_registry_and_transfer_from() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = ShareToken::new(7, 1_000);
+            assert_eq!(token.property_id(), 7);
+            assert_eq!(token.registry(), accounts.alice);
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+
+            assert_eq!(token.transfer(accounts.bob, 300, Vec::new()), Ok(()));
+            assert_eq!(token.balance_of(accounts.bob), 300);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(token.approve(accounts.charlie, 100), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                token.transfer_from(accounts.bob, accounts.charlie, 101, Vec::new()),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+            // Returning tokens to the registry needs no compliance query
+            assert_eq!(
+                token.transfer_from(accounts.bob, accounts.alice, 100, Vec::new()),
+                Ok(())
+            );
+            assert_eq!(token.balance_of(accounts.bob), 200);
+            assert_eq!(token.allowance(accounts.bob, accounts.charlie), 0);
+        }
+    }
+}
@@ -60,6 +60,8 @@ pub struct PropertyMetadata {
     pub legal_description: String,
     pub valuation: u128,
     pub documents_url: String,
+    /// Jurisdiction whose compliance approval a holder needs (None for unrestricted)
+    pub jurisdiction: Option<Jurisdiction>,
 }
 
 /// Property information structure
@@ -310,19 +312,95 @@ pub trait OracleRegistry {
     fn detect_anomalies(&self, property_id: u64, new_valuation: u128) -> bool;
 }
 
+/// Error types for escrow operations exposed to other contracts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum EscrowError {
+    EscrowNotFound,
+    PropertyNotFound,
+    Unauthorized,
+    ContractPaused,
+    InvalidDeposit,
+    NotFunded,
+    AlreadyFunded,
+    NotAccepted,
+    ConditionsNotMet,
+    AlreadyReleased,
+    Closed,
+    Disputed,
+    NotExpired,
+    ComplianceFailed,
+    TransferFailed,
+    Other,
+}
+
+/// Lifecycle status of an escrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum EscrowStatus {
+    Created,
+    Funded,
+    Released,
+    Refunded,
+    Disputed,
+    Expired,
+}
+
+/// Escrow summary returned to other contracts
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct EscrowDetails {
+    pub id: u64,
+    pub property_id: u64,
+    pub buyer: AccountId,
+    pub seller: AccountId,
+    pub amount: u128,
+    pub funded_amount: u128,
+    pub deadline: Option<u64>,
+    pub status: EscrowStatus,
+}
+
 /// Escrow trait for secure property transfers
+/// Marketplace contracts hold escrows through this interface; the calling contract acts as
+/// the buyer.
+#[ink::trait_definition]
 pub trait Escrow {
-    /// Error type for escrow operations
-    type Error;
+    /// Open an escrow as the buyer against a property, optionally depositing funds with the call
+    #[ink(message, payable)]
+    fn open_escrow(
+        &mut self,
+        property_id: u64,
+        amount: u128,
+        deadline: Option<u64>,
+    ) -> Result<u64, EscrowError>;
 
-    /// Create a new escrow
-    fn create_escrow(&mut self, property_id: u64, amount: u128) -> Result<u64, Self::Error>;
+    /// Deposit funds towards the escrow amount
+    #[ink(message, payable)]
+    fn fund_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError>;
 
     /// Release escrow funds
-    fn release_escrow(&mut self, escrow_id: u64) -> Result<(), Self::Error>;
+    #[ink(message)]
+    fn release_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError>;
 
     /// Refund escrow funds
-    fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), Self::Error>;
+    #[ink(message)]
+    fn refund_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError>;
+
+    /// Raise a dispute, freezing the escrow until it is resolved
+    #[ink(message)]
+    fn dispute_escrow(&mut self, escrow_id: u64, reason: String) -> Result<(), EscrowError>;
+
+    /// Reclaim the deposit of an escrow whose deadline has passed
+    #[ink(message)]
+    fn claim_expired_escrow(&mut self, escrow_id: u64) -> Result<(), EscrowError>;
+
+    /// Get an escrow's terms and status
+    #[ink(message)]
+    fn get_escrow_details(&self, escrow_id: u64) -> Option<EscrowDetails>;
+
+    /// Check whether an escrow holds its full amount
+    #[ink(message)]
+    fn is_escrow_funded(&self, escrow_id: u64) -> bool;
 }
 
 #[cfg(not(feature = "std"))]
@@ -710,6 +788,22 @@ pub enum ComplianceOperation {
     ListForSale,
     Purchase,
     BridgeTransfer,
+    Lease,
+}
+
+/// Supported compliance jurisdictions
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Jurisdiction {
+    US,
+    EU,
+    UK,
+    Singapore,
+    UAE,
+    Other,
 }
 
 /// Trait for compliance registry (used by PropertyRegistry for automated checks)
@@ -718,4 +812,127 @@ pub trait ComplianceChecker {
     /// Returns true if the account meets current compliance requirements
     #[ink(message)]
     fn is_compliant(&self, account: ink::primitives::AccountId) -> bool;
+
+    /// Returns true if the account is compliant and was verified in the given jurisdiction
+    #[ink(message)]
+    fn is_compliant_in(
+        &self,
+        account: ink::primitives::AccountId,
+        jurisdiction: Jurisdiction,
+    ) -> bool;
+
+    /// Returns the account's KYC tier (1-5), or 0 if it is not compliant
+    /// When a jurisdiction is given, the account must also have been verified there.
+    #[ink(message)]
+    fn compliance_tier(
+        &self,
+        account: ink::primitives::AccountId,
+        jurisdiction: Option<Jurisdiction>,
+    ) -> u8;
+
+    /// Returns `is_compliant` for each account, in order, in a single call
+    #[ink(message)]
+    fn are_compliant(&self, accounts: Vec<ink::primitives::AccountId>) -> Vec<bool>;
+}
+
+// =============================================================================
+// PSP22 Fungible Tokens
+// =============================================================================
+
+/// Errors returned by PSP22 token contracts
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22Error {
+    Custom(String),
+    InsufficientBalance,
+    InsufficientAllowance,
+    ZeroRecipientAddress,
+    ZeroSenderAddress,
+    SafeTransferCheckFailed(String),
+}
+
+/// Minimal PSP22 interface used to hold token-denominated escrows
+/// Message selectors follow the PSP22 standard so any compliant token can be called.
+#[allow(clippy::upper_case_acronyms)]
+#[ink::trait_definition]
+pub trait PSP22 {
+    /// Returns the total token supply
+    #[ink(message)]
+    fn total_supply(&self) -> u128;
+
+    /// Returns the token balance of `owner`
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId) -> u128;
+
+    /// Returns the amount `spender` may still withdraw from `owner`
+    #[ink(message)]
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> u128;
+
+    /// Transfers `value` tokens from the caller to `to`
+    #[ink(message)]
+    fn transfer(
+        &mut self,
+        to: AccountId,
+        value: u128,
+        data: ink::prelude::vec::Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers `value` tokens on behalf of `from` to `to` using the caller's allowance
+    #[ink(message)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: u128,
+        data: ink::prelude::vec::Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Allows `spender` to withdraw up to `value` tokens from the caller
+    #[ink(message)]
+    fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error>;
+}
+
+/// Registry that issues PSP22 share tokens and screens the accounts receiving them
+#[ink::trait_definition]
+pub trait ShareRegistry {
+    /// Returns true if `to` may receive `amount` shares of the property
+    /// Applies the same screening as a share transfer inside the registry.
+    #[ink(message)]
+    fn can_receive_shares(
+        &self,
+        property_id: u64,
+        to: ink::primitives::AccountId,
+        amount: u128,
+    ) -> bool;
+}
+
+// =============================================================================
+// Yield Sources
+// =============================================================================
+
+/// Errors returned by yield source contracts
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum YieldSourceError {
+    PositionNotFound,
+    InsufficientLiquidity,
+    Unauthorized,
+}
+
+/// Staking or lending contract that idle escrow funds can be deposited into
+/// Positions are keyed by the depositor and a caller-chosen position ID.
+#[ink::trait_definition]
+pub trait YieldSource {
+    /// Adds the transferred value to the caller's position
+    #[ink(message, payable)]
+    fn deposit(&mut self, position_id: u64) -> Result<(), YieldSourceError>;
+
+    /// Returns the principal plus accrued interest of a position
+    #[ink(message)]
+    fn position_value(&self, owner: AccountId, position_id: u64) -> u128;
+
+    /// Closes the caller's position, transferring principal plus interest back to the caller
+    /// Returns the amount transferred.
+    #[ink(message)]
+    fn withdraw_all(&mut self, position_id: u64) -> Result<u128, YieldSourceError>;
 }
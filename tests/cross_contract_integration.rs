@@ -34,6 +34,7 @@ mod integration_tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = registry
@@ -79,6 +80,7 @@ mod integration_tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = registry
@@ -109,6 +111,7 @@ mod integration_tests {
                 legal_description: format!("Description {}", i),
                 valuation: 100_000 + (i as u128 * 10_000),
                 documents_url: format!("ipfs://prop{}", i),
+                jurisdiction: None,
             };
 
             let property_id = registry
@@ -142,6 +145,7 @@ mod integration_tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = registry
@@ -11,6 +11,7 @@ mod fractional_tests {
             legal_description: String::from("Fractional Property"),
             valuation: 1_000_000,
             documents_url: String::from("ipfs://docs"),
+            jurisdiction: None,
         }
     }
 
@@ -19,6 +19,7 @@ mod integration_tests {
             legal_description: String::from("Registry test property"),
             valuation: 400000,
             documents_url: String::from("ipfs://registry-docs"),
+            jurisdiction: None,
         };
         
         let property_id = registry.register_property(metadata.clone()).unwrap();
@@ -40,6 +41,7 @@ mod integration_tests {
             legal_description: String::from("Token test property"),
             valuation: 500000,
             documents_url: String::from("ipfs://token-docs"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -77,6 +79,7 @@ mod integration_tests {
             legal_description: String::from("From registry"),
             valuation: 300000,
             documents_url: String::from("ipfs://traditional"),
+            jurisdiction: None,
         };
         
         let registry_property_id = registry.register_property(registry_metadata).unwrap();
@@ -88,6 +91,7 @@ mod integration_tests {
             legal_description: String::from("From token contract"),
             valuation: 600000,
             documents_url: String::from("ipfs://enhanced"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(token_metadata).unwrap();
@@ -114,6 +118,7 @@ mod integration_tests {
             legal_description: String::from("Originally in old registry"),
             valuation: 350000,
             documents_url: String::from("ipfs://old-system"),
+            jurisdiction: None,
         };
         
         let old_property_id = old_registry.register_property(old_metadata.clone()).unwrap();
@@ -127,6 +132,7 @@ mod integration_tests {
             legal_description: old_metadata.legal_description,
             valuation: old_metadata.valuation,
             documents_url: old_metadata.documents_url,
+            jurisdiction: None,
         };
         
         let new_token_id = new_token_contract.register_property_with_token(new_metadata).unwrap();
@@ -158,6 +164,7 @@ mod integration_tests {
             legal_description: String::from("For escrow testing"),
             valuation: 450000,
             documents_url: String::from("ipfs://escrow-test"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -200,6 +207,7 @@ mod integration_tests {
                 legal_description: String::from("Batch created property"),
                 valuation,
                 documents_url: String::from("ipfs://batch"),
+                jurisdiction: None,
             };
             
             let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -234,6 +242,7 @@ mod integration_tests {
             legal_description: String::from("Ownership tracking test"),
             valuation: 520000,
             documents_url: String::from("ipfs://tracking"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -267,6 +276,7 @@ mod integration_tests {
             legal_description: String::from("Security features test"),
             valuation: 480000,
             documents_url: String::from("ipfs://security"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -304,6 +314,7 @@ mod integration_tests {
             legal_description: String::from("Backward compatibility test"),
             valuation: 420000,
             documents_url: String::from("ipfs://compatibility"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -336,6 +347,7 @@ mod integration_tests {
             legal_description: String::from("Property for bridge testing"),
             valuation: 400000,
             documents_url: String::from("ipfs://bridge-test"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -401,6 +413,7 @@ mod integration_tests {
             legal_description: String::from("Property with long legal description for gas estimation testing"),
             valuation: 600000,
             documents_url: String::from("ipfs://gas-test"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -428,6 +441,7 @@ mod integration_tests {
             legal_description: String::from("Property for recovery testing"),
             valuation: 350000,
             documents_url: String::from("ipfs://recovery-test"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -560,6 +574,7 @@ mod integration_tests {
             legal_description: String::from("Property for transaction verification testing"),
             valuation: 450000,
             documents_url: String::from("ipfs://verification-test"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -617,6 +632,7 @@ mod integration_tests {
             legal_description: String::from("Property with comprehensive metadata for cross-chain preservation testing"),
             valuation: 750000,
             documents_url: String::from("ipfs://comprehensive-metadata"),
+            jurisdiction: None,
         };
         
         let original_token_id = token_contract.register_property_with_token(original_metadata.clone()).unwrap();
@@ -664,6 +680,7 @@ mod integration_tests {
             legal_description: String::from("Property for error handling testing"),
             valuation: 300000,
             documents_url: String::from("ipfs://error-test"),
+            jurisdiction: None,
         };
         
         let token_id = token_contract.register_property_with_token(metadata).unwrap();
@@ -740,6 +757,7 @@ mod integration_tests {
                 legal_description: String::from("Bridge history test property"),
                 valuation,
                 documents_url: String::from("ipfs://history-test"),
+                jurisdiction: None,
             };
             
             let token_id = token_contract.register_property_with_token(metadata).unwrap();
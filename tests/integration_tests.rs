@@ -24,6 +24,7 @@ async fn e2e_register_property() -> E2EResult<()> {
         legal_description: "Test property".to_string(),
         valuation: 500000,
         documents_url: "https://ipfs.io/test".to_string(),
+        jurisdiction: None,
     };
 
     // When
@@ -57,6 +58,7 @@ async fn e2e_get_property() -> E2EResult<()> {
         legal_description: "Test property".to_string(),
         valuation: 500000,
         documents_url: "https://ipfs.io/test".to_string(),
+        jurisdiction: None,
     };
 
     let register_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
@@ -99,6 +101,7 @@ async fn e2e_cross_contract_escrow_workflow() -> E2EResult<()> {
         legal_description: "Commercial property for escrow".to_string(),
         valuation: 750000,
         documents_url: "https://ipfs.io/escrow-test".to_string(),
+        jurisdiction: None,
     };
 
     // Register property
@@ -160,6 +163,7 @@ async fn e2e_network_failure_scenarios() -> E2EResult<()> {
         legal_description: "Property for failure test".to_string(),
         valuation: 600000,
         documents_url: "https://ipfs.io/failure-test".to_string(),
+        jurisdiction: None,
     };
 
     // Register property
@@ -221,6 +225,7 @@ async fn e2e_performance_under_load() -> E2EResult<()> {
             legal_description: format!("Test property {}", i),
             valuation: 100000 + i as u128 * 1000,
             documents_url: format!("https://ipfs.io/test{}", i),
+            jurisdiction: None,
         };
 
         let register_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
@@ -265,6 +270,7 @@ async fn e2e_transfer_property() -> E2EResult<()> {
         legal_description: "Test property".to_string(),
         valuation: 500000,
         documents_url: "https://ipfs.io/test".to_string(),
+        jurisdiction: None,
     };
 
     let register_msg = build_message::<PropertyRegistry>(contract_acc_id.clone())
@@ -310,6 +316,7 @@ async fn e2e_end_to_end_property_workflow() -> E2EResult<()> {
             legal_description: "Residential property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test1".to_string(),
+            jurisdiction: None,
         },
         PropertyMetadata {
             location: "456 Oak Ave".to_string(),
@@ -317,6 +324,7 @@ async fn e2e_end_to_end_property_workflow() -> E2EResult<()> {
             legal_description: "Commercial property".to_string(),
             valuation: 750000,
             documents_url: "https://ipfs.io/test2".to_string(),
+            jurisdiction: None,
         },
     ];
 
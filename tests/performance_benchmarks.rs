@@ -35,6 +35,7 @@ mod benchmarks {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let start = ink::env::test::get_block_timestamp::<DefaultEnvironment>();
@@ -65,6 +66,7 @@ mod benchmarks {
                 legal_description: format!("Description {}", i),
                 valuation: 100_000 + (i as u128 * 10_000),
                 documents_url: format!("ipfs://prop{}", i),
+                jurisdiction: None,
             };
 
             registry
@@ -99,6 +101,7 @@ mod benchmarks {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = registry
@@ -134,6 +137,7 @@ mod benchmarks {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = registry
@@ -168,6 +172,7 @@ mod benchmarks {
                 legal_description: format!("Description {}", i),
                 valuation: 100_000,
                 documents_url: format!("ipfs://prop{}", i),
+                jurisdiction: None,
             };
 
             registry
@@ -204,6 +209,7 @@ mod benchmarks {
                 legal_description: format!("Description {}", i),
                 valuation: 100_000,
                 documents_url: format!("ipfs://prop{}", i),
+                jurisdiction: None,
             };
 
             let property_id = registry
@@ -227,6 +233,7 @@ mod benchmarks {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = registry
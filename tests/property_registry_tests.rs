@@ -32,6 +32,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let result = contract.register_property(metadata);
@@ -53,6 +54,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = contract.register_property(metadata).unwrap();
@@ -75,6 +77,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = contract.register_property(metadata).unwrap();
@@ -100,6 +103,7 @@ mod tests {
             legal_description: "Test property".to_string(),
             valuation: 500000,
             documents_url: "https://ipfs.io/test".to_string(),
+            jurisdiction: None,
         };
 
         let property_id = contract.register_property(metadata).unwrap();
@@ -27,6 +27,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let result = contract.register_property_with_token(metadata.clone());
@@ -54,6 +55,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -82,6 +84,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -115,6 +118,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -143,6 +147,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -168,6 +173,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         // Register two properties
@@ -197,6 +203,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -240,6 +247,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
@@ -266,6 +274,7 @@ mod property_token_tests {
             legal_description: String::from("Sample property"),
             valuation: 500000,
             documents_url: String::from("ipfs://sample-docs"),
+            jurisdiction: None,
         };
         
         let token_id = contract.register_property_with_token(metadata).unwrap();
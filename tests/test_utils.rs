@@ -50,6 +50,7 @@ impl PropertyMetadataFixtures {
             legal_description: "Test property".to_string(),
             valuation: 100_000,
             documents_url: "ipfs://test".to_string(),
+            jurisdiction: None,
         }
     }
 
@@ -61,6 +62,7 @@ impl PropertyMetadataFixtures {
             legal_description: "Lot 123, Block 4, Subdivision XYZ".to_string(),
             valuation: 500_000,
             documents_url: "https://ipfs.io/ipfs/QmTest".to_string(),
+            jurisdiction: None,
         }
     }
 
@@ -72,6 +74,7 @@ impl PropertyMetadataFixtures {
             legal_description: "Large commercial property with extensive legal description".to_string(),
             valuation: 5_000_000,
             documents_url: "https://ipfs.io/ipfs/QmLarge".to_string(),
+            jurisdiction: None,
         }
     }
 
@@ -89,6 +92,7 @@ impl PropertyMetadataFixtures {
             legal_description,
             valuation,
             documents_url,
+            jurisdiction: None,
         }
     }
 
@@ -102,6 +106,7 @@ impl PropertyMetadataFixtures {
                 legal_description: "X".to_string(),
                 valuation: 1,
                 documents_url: "ipfs://min".to_string(),
+                jurisdiction: None,
             },
             // Maximum reasonable values
             PropertyMetadata {
@@ -110,6 +115,7 @@ impl PropertyMetadataFixtures {
                 legal_description: "X".repeat(5000),
                 valuation: u128::MAX,
                 documents_url: "ipfs://max".to_string(),
+                jurisdiction: None,
             },
             // Special characters
             PropertyMetadata {
@@ -118,6 +124,7 @@ impl PropertyMetadataFixtures {
                 legal_description: "Test with émojis 🏠 and unicode".to_string(),
                 valuation: 100_000,
                 documents_url: "ipfs://special".to_string(),
+                jurisdiction: None,
             },
         ]
     }
@@ -213,6 +220,7 @@ pub mod generators {
             legal_description: format!("Legal description for seed {}", seed),
             valuation: 100_000 + (seed as u128 * 1000),
             documents_url: format!("ipfs://seed-{}", seed),
+            jurisdiction: None,
         }
     }
 